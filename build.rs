@@ -3,6 +3,10 @@ use std::env;
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 
+    // The n32 ABI of mips64 has no Rust target yet, so custom targets have to
+    // set `target_abi = "abin32"` themselves.
+    println!("cargo:rustc-check-cfg=cfg(target_abi, values(\"abin32\"))");
+
     // Automatically detect if thumb-mode is an available feature by looking at
     // the prefix of the target. Currently, the thumb-mode target feature is
     // only set automatically in nightly builds, so we must do the manual
@@ -701,3 +701,668 @@ syscall_enum! {
     }
     LAST: rseq_slice_yield;
 }
+
+syscall_signatures! {
+    impl Sysno {
+        io_setup(nr_reqs: "unsigned" = UInt, ctx: "aio_context_t *" = Ptr) -> Int;
+        io_destroy(ctx: "aio_context_t" = UInt) -> Int;
+        io_submit(ctx_id: "aio_context_t" = UInt, nr: "long" = Int, iocbpp: "struct iocb **" = Ptr) -> Int;
+        io_cancel(ctx_id: "aio_context_t" = UInt, iocb: "struct iocb *" = Ptr, result: "struct io_event *" = Ptr) -> Int;
+        io_getevents(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        setxattr(path: "const char *" = Str, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        lsetxattr(path: "const char *" = Str, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        fsetxattr(fd: "int" = Fd, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        getxattr(path: "const char *" = Str, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        lgetxattr(path: "const char *" = Str, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        fgetxattr(fd: "int" = Fd, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        listxattr(path: "const char *" = Str, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        llistxattr(path: "const char *" = Str, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        flistxattr(fd: "int" = Fd, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        removexattr(path: "const char *" = Str, name: "const char *" = Str) -> Int;
+        lremovexattr(path: "const char *" = Str, name: "const char *" = Str) -> Int;
+        fremovexattr(fd: "int" = Fd, name: "const char *" = Str) -> Int;
+        getcwd(buf: "char *" = Ptr, size: "unsigned long" = UInt) -> Int;
+        eventfd2(count: "unsigned int" = UInt, flags: "int" = Int) -> Fd;
+        epoll_create1(flags: "int" = Int) -> Fd;
+        epoll_ctl(epfd: "int" = Fd, op: "int" = Int, fd: "int" = Fd, event: "struct epoll_event *" = Ptr) -> Int;
+        epoll_pwait(epfd: "int" = Fd, events: "struct epoll_event *" = Ptr, maxevents: "int" = Int, timeout: "int" = Int, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        dup(fildes: "unsigned int" = Fd) -> Fd;
+        dup3(oldfd: "unsigned int" = Fd, newfd: "unsigned int" = Fd, flags: "int" = Int) -> Fd;
+        fcntl(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, arg: "unsigned long" = UInt) -> Int;
+        inotify_init1(flags: "int" = Int) -> Fd;
+        inotify_add_watch(fd: "int" = Fd, path: "const char *" = Str, mask: "u32" = UInt) -> Int;
+        inotify_rm_watch(fd: "int" = Fd, wd: "__s32" = Int) -> Int;
+        ioctl(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, arg: "unsigned long" = UInt) -> Int;
+        ioprio_set(which: "int" = Int, who: "int" = Int, ioprio: "int" = Int) -> Int;
+        ioprio_get(which: "int" = Int, who: "int" = Int) -> Int;
+        flock(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt) -> Int;
+        mknodat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt, dev: "unsigned" = UInt) -> Int;
+        mkdirat(dfd: "int" = Fd, pathname: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        unlinkat(dfd: "int" = Fd, pathname: "const char *" = Str, flag: "int" = Int) -> Int;
+        symlinkat(oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str) -> Int;
+        linkat(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str, flags: "int" = Int) -> Int;
+        renameat(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str) -> Int;
+        umount2(name: "char *" = Str, flags: "int" = Int) -> Int;
+        mount(dev_name: "char *" = Str, dir_name: "char *" = Str, type: "char *" = Str, flags: "unsigned long" = UInt, data: "void *" = Ptr) -> Int;
+        pivot_root(new_root: "const char *" = Str, put_old: "const char *" = Str) -> Int;
+        statfs(path: "const char *" = Str, buf: "struct statfs *" = Ptr) -> Int;
+        fstatfs(fd: "unsigned int" = Fd, buf: "struct statfs *" = Ptr) -> Int;
+        truncate(path: "const char *" = Str, length: "long" = Int) -> Int;
+        ftruncate(fd: "unsigned int" = Fd, length: "off_t" = Int) -> Int;
+        fallocate(fd: "int" = Fd, mode: "int" = Int, offset: "loff_t" = Int, len: "loff_t" = Int) -> Int;
+        faccessat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "int" = Int) -> Int;
+        chdir(filename: "const char *" = Str) -> Int;
+        fchdir(fd: "unsigned int" = Fd) -> Int;
+        chroot(filename: "const char *" = Str) -> Int;
+        fchmod(fd: "unsigned int" = Fd, mode: "umode_t" = UInt) -> Int;
+        fchmodat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        fchownat(dfd: "int" = Fd, filename: "const char *" = Str, user: "uid_t" = UInt, group: "gid_t" = UInt, flag: "int" = Int) -> Int;
+        fchown(fd: "unsigned int" = Fd, user: "uid_t" = UInt, group: "gid_t" = UInt) -> Int;
+        openat(dfd: "int" = Fd, filename: "const char *" = Str, flags: "int" = Int, mode: "umode_t" = UInt) -> Fd;
+        close(fd: "unsigned int" = Fd) -> Int;
+        vhangup() -> Int;
+        pipe2(fildes: "int *" = Ptr, flags: "int" = Int) -> Int;
+        quotactl(cmd: "unsigned int" = UInt, special: "const char *" = Str, id: "qid_t" = UInt, addr: "void *" = Ptr) -> Int;
+        getdents64(fd: "unsigned int" = Fd, dirent: "struct linux_dirent64 *" = Ptr, count: "unsigned int" = UInt) -> Int;
+        lseek(fd: "unsigned int" = Fd, offset: "off_t" = Int, whence: "unsigned int" = UInt) -> Int;
+        read(fd: "unsigned int" = Fd, buf: "char *" = Ptr, count: "size_t" = UInt) -> Int;
+        write(fd: "unsigned int" = Fd, buf: "const char *" = Ptr, count: "size_t" = UInt) -> Int;
+        readv(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt) -> Int;
+        writev(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt) -> Int;
+        pread64(fd: "unsigned int" = Fd, buf: "char *" = Ptr, count: "size_t" = UInt, pos: "loff_t" = Int) -> Int;
+        pwrite64(fd: "unsigned int" = Fd, buf: "const char *" = Ptr, count: "size_t" = UInt, pos: "loff_t" = Int) -> Int;
+        preadv(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt) -> Int;
+        pwritev(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt) -> Int;
+        sendfile(out_fd: "int" = Fd, in_fd: "int" = Fd, offset: "off_t *" = Ptr, count: "size_t" = UInt) -> Int;
+        pselect6(n: "int" = Int, inp: "fd_set *" = Ptr, outp: "fd_set *" = Ptr, exp: "fd_set *" = Ptr, tsp: "struct __kernel_timespec *" = Ptr, sig: "void *" = Ptr) -> Int;
+        ppoll(ufds: "struct pollfd *" = Ptr, nfds: "unsigned int" = UInt, tsp: "struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        signalfd4(ufd: "int" = Fd, user_mask: "sigset_t *" = Ptr, sizemask: "size_t" = UInt, flags: "int" = Int) -> Fd;
+        vmsplice(fd: "int" = Fd, vec: "const struct iovec *" = Ptr, nr_segs: "unsigned long" = UInt, flags: "unsigned int" = UInt) -> Int;
+        splice(fd_in: "int" = Fd, off_in: "loff_t *" = Ptr, fd_out: "int" = Fd, off_out: "loff_t *" = Ptr, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        tee(fdin: "int" = Fd, fdout: "int" = Fd, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        readlinkat(dfd: "int" = Fd, path: "const char *" = Str, buf: "char *" = Ptr, bufsiz: "int" = Int) -> Int;
+        fstatat(dfd: "int" = Fd, filename: "const char *" = Str, statbuf: "struct stat *" = Ptr, flag: "int" = Int) -> Int;
+        fstat(fd: "unsigned int" = Fd, statbuf: "struct stat *" = Ptr) -> Int;
+        sync() -> Int;
+        fsync(fd: "unsigned int" = Fd) -> Int;
+        fdatasync(fd: "unsigned int" = Fd) -> Int;
+        sync_file_range(fd: "int" = Fd, offset: "loff_t" = Int, nbytes: "loff_t" = Int, flags: "unsigned int" = UInt) -> Int;
+        timerfd_create(clockid: "int" = Int, flags: "int" = Int) -> Fd;
+        timerfd_settime(ufd: "int" = Fd, flags: "int" = Int, utmr: "const struct __kernel_itimerspec *" = Ptr, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_gettime(ufd: "int" = Fd, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        utimensat(dfd: "int" = Fd, filename: "const char *" = Str, utimes: "struct __kernel_timespec *" = Ptr, flags: "int" = Int) -> Int;
+        acct(name: "const char *" = Str) -> Int;
+        capget(header: "cap_user_header_t" = Ptr, dataptr: "cap_user_data_t" = Ptr) -> Int;
+        capset(header: "cap_user_header_t" = Ptr, data: "const cap_user_data_t" = Ptr) -> Int;
+        personality(personality: "unsigned int" = UInt) -> Int;
+        exit(error_code: "int" = Int) -> Int;
+        exit_group(error_code: "int" = Int) -> Int;
+        waitid(which: "int" = Int, pid: "pid_t" = Int, infop: "struct siginfo *" = Ptr, options: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        set_tid_address(tidptr: "int *" = Ptr) -> Int;
+        unshare(unshare_flags: "unsigned long" = UInt) -> Int;
+        futex(uaddr: "u32 *" = Ptr, op: "int" = Int, val: "u32" = UInt, utime: "const struct __kernel_timespec *" = Ptr, uaddr2: "u32 *" = Ptr, val3: "u32" = UInt) -> Int;
+        set_robust_list(head: "struct robust_list_head *" = Ptr, len: "size_t" = UInt) -> Int;
+        get_robust_list(pid: "int" = Int, head_ptr: "struct robust_list_head **" = Ptr, len_ptr: "size_t *" = Ptr) -> Int;
+        nanosleep(rqtp: "struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        getitimer(which: "int" = Int, value: "struct __kernel_old_itimerval *" = Ptr) -> Int;
+        setitimer(which: "int" = Int, value: "struct __kernel_old_itimerval *" = Ptr, ovalue: "struct __kernel_old_itimerval *" = Ptr) -> Int;
+        kexec_load(entry: "unsigned long" = UInt, nr_segments: "unsigned long" = UInt, segments: "struct kexec_segment *" = Ptr, flags: "unsigned long" = UInt) -> Int;
+        init_module(umod: "void *" = Ptr, len: "unsigned long" = UInt, uargs: "const char *" = Str) -> Int;
+        delete_module(name_user: "const char *" = Str, flags: "unsigned int" = UInt) -> Int;
+        timer_create(which_clock: "clockid_t" = Int, timer_event_spec: "struct sigevent *" = Ptr, created_timer_id: "timer_t *" = Ptr) -> Int;
+        timer_gettime(timer_id: "timer_t" = Int, setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_getoverrun(timer_id: "timer_t" = Int) -> Int;
+        timer_settime(timer_id: "timer_t" = Int, flags: "int" = Int, new_setting: "const struct __kernel_itimerspec *" = Ptr, old_setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_delete(timer_id: "timer_t" = Int) -> Int;
+        clock_settime(which_clock: "clockid_t" = Int, tp: "const struct __kernel_timespec *" = Ptr) -> Int;
+        clock_gettime(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_getres(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_nanosleep(which_clock: "clockid_t" = Int, flags: "int" = Int, rqtp: "const struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        syslog(type: "int" = Int, buf: "char *" = Ptr, len: "int" = Int) -> Int;
+        ptrace(request: "long" = Int, pid: "long" = Int, addr: "unsigned long" = UInt, data: "unsigned long" = UInt) -> Int;
+        sched_setparam(pid: "pid_t" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_setscheduler(pid: "pid_t" = Int, policy: "int" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_getscheduler(pid: "pid_t" = Int) -> Int;
+        sched_getparam(pid: "pid_t" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_setaffinity(pid: "pid_t" = Int, len: "unsigned int" = UInt, user_mask_ptr: "unsigned long *" = Ptr) -> Int;
+        sched_getaffinity(pid: "pid_t" = Int, len: "unsigned int" = UInt, user_mask_ptr: "unsigned long *" = Ptr) -> Int;
+        sched_yield() -> Int;
+        sched_get_priority_max(policy: "int" = Int) -> Int;
+        sched_get_priority_min(policy: "int" = Int) -> Int;
+        sched_rr_get_interval(pid: "pid_t" = Int, interval: "struct __kernel_timespec *" = Ptr) -> Int;
+        restart_syscall() -> Int;
+        kill(pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        tkill(pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        tgkill(tgid: "pid_t" = Int, pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        sigaltstack(uss: "const struct sigaltstack *" = Ptr, uoss: "struct sigaltstack *" = Ptr) -> Int;
+        rt_sigsuspend(unewset: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigaction(sig: "int" = Int, act: "const struct sigaction *" = Ptr, oact: "struct sigaction *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigprocmask(how: "int" = Int, set: "sigset_t *" = Ptr, oset: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigpending(set: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigtimedwait(uthese: "const sigset_t *" = Ptr, uinfo: "siginfo_t *" = Ptr, uts: "const struct __kernel_timespec *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigqueueinfo(pid: "pid_t" = Int, sig: "int" = Int, uinfo: "siginfo_t *" = Ptr) -> Int;
+        rt_sigreturn() -> Int;
+        setpriority(which: "int" = Int, who: "int" = Int, niceval: "int" = Int) -> Int;
+        getpriority(which: "int" = Int, who: "int" = Int) -> Int;
+        reboot(magic1: "int" = Int, magic2: "int" = Int, cmd: "unsigned int" = UInt, arg: "void *" = Ptr) -> Int;
+        setregid(rgid: "gid_t" = UInt, egid: "gid_t" = UInt) -> Int;
+        setgid(gid: "gid_t" = UInt) -> Int;
+        setreuid(ruid: "uid_t" = UInt, euid: "uid_t" = UInt) -> Int;
+        setuid(uid: "uid_t" = UInt) -> Int;
+        setresuid(ruid: "uid_t" = UInt, euid: "uid_t" = UInt, suid: "uid_t" = UInt) -> Int;
+        getresuid(ruid: "uid_t *" = Ptr, euid: "uid_t *" = Ptr, suid: "uid_t *" = Ptr) -> Int;
+        setresgid(rgid: "gid_t" = UInt, egid: "gid_t" = UInt, sgid: "gid_t" = UInt) -> Int;
+        getresgid(rgid: "gid_t *" = Ptr, egid: "gid_t *" = Ptr, sgid: "gid_t *" = Ptr) -> Int;
+        setfsuid(uid: "uid_t" = UInt) -> Int;
+        setfsgid(gid: "gid_t" = UInt) -> Int;
+        times(tbuf: "struct tms *" = Ptr) -> Int;
+        setpgid(pid: "pid_t" = Int, pgid: "pid_t" = Int) -> Int;
+        getpgid(pid: "pid_t" = Int) -> Int;
+        getsid(pid: "pid_t" = Int) -> Int;
+        setsid() -> Int;
+        getgroups(gidsetsize: "int" = Int, grouplist: "gid_t *" = Ptr) -> Int;
+        setgroups(gidsetsize: "int" = Int, grouplist: "gid_t *" = Ptr) -> Int;
+        uname(name: "struct new_utsname *" = Ptr) -> Int;
+        sethostname(name: "char *" = Str, len: "int" = Int) -> Int;
+        setdomainname(name: "char *" = Str, len: "int" = Int) -> Int;
+        getrlimit(resource: "unsigned int" = UInt, rlim: "struct rlimit *" = Ptr) -> Int;
+        setrlimit(resource: "unsigned int" = UInt, rlim: "struct rlimit *" = Ptr) -> Int;
+        getrusage(who: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        umask(mask: "int" = Int) -> Int;
+        prctl(option: "int" = Int, arg2: "unsigned long" = UInt, arg3: "unsigned long" = UInt, arg4: "unsigned long" = UInt, arg5: "unsigned long" = UInt) -> Int;
+        getcpu(cpu: "unsigned *" = Ptr, node: "unsigned *" = Ptr, cache: "struct getcpu_cache *" = Ptr) -> Int;
+        gettimeofday(tv: "struct __kernel_old_timeval *" = Ptr, tz: "struct timezone *" = Ptr) -> Int;
+        settimeofday(tv: "struct __kernel_old_timeval *" = Ptr, tz: "struct timezone *" = Ptr) -> Int;
+        adjtimex(txc_p: "struct __kernel_timex *" = Ptr) -> Int;
+        getpid() -> Int;
+        getppid() -> Int;
+        getuid() -> Int;
+        geteuid() -> Int;
+        getgid() -> Int;
+        getegid() -> Int;
+        gettid() -> Int;
+        sysinfo(info: "struct sysinfo *" = Ptr) -> Int;
+        mq_open(name: "const char *" = Str, oflag: "int" = Int, mode: "umode_t" = UInt, attr: "struct mq_attr *" = Ptr) -> Fd;
+        mq_unlink(name: "const char *" = Str) -> Int;
+        mq_timedsend(mqdes: "mqd_t" = Fd, msg_ptr: "const char *" = Ptr, msg_len: "size_t" = UInt, msg_prio: "unsigned int" = UInt, abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedreceive(mqdes: "mqd_t" = Fd, msg_ptr: "char *" = Ptr, msg_len: "size_t" = UInt, msg_prio: "unsigned int *" = Ptr, abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_notify(mqdes: "mqd_t" = Fd, notification: "const struct sigevent *" = Ptr) -> Int;
+        mq_getsetattr(mqdes: "mqd_t" = Fd, mqstat: "const struct mq_attr *" = Ptr, omqstat: "struct mq_attr *" = Ptr) -> Int;
+        msgget(key: "key_t" = Int, msgflg: "int" = Int) -> Int;
+        msgctl(msqid: "int" = Int, cmd: "int" = Int, buf: "struct msqid_ds *" = Ptr) -> Int;
+        msgrcv(msqid: "int" = Int, msgp: "struct msgbuf *" = Ptr, msgsz: "size_t" = UInt, msgtyp: "long" = Int, msgflg: "int" = Int) -> Int;
+        msgsnd(msqid: "int" = Int, msgp: "struct msgbuf *" = Ptr, msgsz: "size_t" = UInt, msgflg: "int" = Int) -> Int;
+        semget(key: "key_t" = Int, nsems: "int" = Int, semflg: "int" = Int) -> Int;
+        semctl(semid: "int" = Int, semnum: "int" = Int, cmd: "int" = Int, arg: "unsigned long" = UInt) -> Int;
+        semtimedop(semid: "int" = Int, sops: "struct sembuf *" = Ptr, nsops: "unsigned" = UInt, timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        semop(semid: "int" = Int, sops: "struct sembuf *" = Ptr, nsops: "unsigned" = UInt) -> Int;
+        shmget(key: "key_t" = Int, size: "size_t" = UInt, flag: "int" = Int) -> Int;
+        shmctl(shmid: "int" = Int, cmd: "int" = Int, buf: "struct shmid_ds *" = Ptr) -> Int;
+        shmat(shmid: "int" = Int, shmaddr: "char *" = Ptr, shmflg: "int" = Int) -> Ptr;
+        shmdt(shmaddr: "char *" = Ptr) -> Int;
+        socket(family: "int" = Int, type: "int" = Int, protocol: "int" = Int) -> Fd;
+        socketpair(family: "int" = Int, type: "int" = Int, protocol: "int" = Int, usockvec: "int *" = Ptr) -> Int;
+        bind(fd: "int" = Fd, umyaddr: "struct sockaddr *" = Ptr, addrlen: "int" = Int) -> Int;
+        listen(fd: "int" = Fd, backlog: "int" = Int) -> Int;
+        accept(fd: "int" = Fd, upeer_sockaddr: "struct sockaddr *" = Ptr, upeer_addrlen: "int *" = Ptr) -> Fd;
+        connect(fd: "int" = Fd, uservaddr: "struct sockaddr *" = Ptr, addrlen: "int" = Int) -> Int;
+        getsockname(fd: "int" = Fd, usockaddr: "struct sockaddr *" = Ptr, usockaddr_len: "int *" = Ptr) -> Int;
+        getpeername(fd: "int" = Fd, usockaddr: "struct sockaddr *" = Ptr, usockaddr_len: "int *" = Ptr) -> Int;
+        sendto(fd: "int" = Fd, buff: "void *" = Ptr, len: "size_t" = UInt, flags: "unsigned" = UInt, addr: "struct sockaddr *" = Ptr, addr_len: "int" = Int) -> Int;
+        recvfrom(fd: "int" = Fd, ubuf: "void *" = Ptr, size: "size_t" = UInt, flags: "unsigned" = UInt, addr: "struct sockaddr *" = Ptr, addr_len: "int *" = Ptr) -> Int;
+        setsockopt(fd: "int" = Fd, level: "int" = Int, optname: "int" = Int, optval: "char *" = Ptr, optlen: "int" = Int) -> Int;
+        getsockopt(fd: "int" = Fd, level: "int" = Int, optname: "int" = Int, optval: "char *" = Ptr, optlen: "int *" = Ptr) -> Int;
+        shutdown(fd: "int" = Fd, how: "int" = Int) -> Int;
+        sendmsg(fd: "int" = Fd, msg: "struct user_msghdr *" = Ptr, flags: "unsigned" = UInt) -> Int;
+        recvmsg(fd: "int" = Fd, msg: "struct user_msghdr *" = Ptr, flags: "unsigned" = UInt) -> Int;
+        readahead(fd: "int" = Fd, offset: "loff_t" = Int, count: "size_t" = UInt) -> Int;
+        brk(brk: "unsigned long" = UInt) -> Ptr;
+        munmap(addr: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        mremap(addr: "unsigned long" = UInt, old_len: "unsigned long" = UInt, new_len: "unsigned long" = UInt, flags: "unsigned long" = UInt, new_addr: "unsigned long" = UInt) -> Ptr;
+        add_key(_type: "const char *" = Str, _description: "const char *" = Str, _payload: "const void *" = Ptr, plen: "size_t" = UInt, destringid: "key_serial_t" = Int) -> Int;
+        request_key(_type: "const char *" = Str, _description: "const char *" = Str, _callout_info: "const char *" = Str, destringid: "key_serial_t" = Int) -> Int;
+        keyctl(cmd: "int" = Int, arg2: "unsigned long" = UInt, arg3: "unsigned long" = UInt, arg4: "unsigned long" = UInt, arg5: "unsigned long" = UInt) -> Int;
+        clone(clone_flags: "unsigned long" = UInt, newsp: "unsigned long" = UInt, parent_tidptr: "int *" = Ptr, tls: "unsigned long" = UInt, child_tidptr: "int *" = Ptr) -> Int;
+        execve(filename: "const char *" = Str, argv: "const char *const *" = Ptr, envp: "const char *const *" = Ptr) -> Int;
+        mmap(addr: "unsigned long" = UInt, len: "unsigned long" = UInt, prot: "unsigned long" = UInt, flags: "unsigned long" = UInt, fd: "unsigned long" = Fd, off: "unsigned long" = UInt) -> Ptr;
+        fadvise64(fd: "int" = Fd, offset: "loff_t" = Int, len: "size_t" = UInt, advice: "int" = Int) -> Int;
+        swapon(specialfile: "const char *" = Str, swap_flags: "int" = Int) -> Int;
+        swapoff(specialfile: "const char *" = Str) -> Int;
+        mprotect(start: "unsigned long" = UInt, len: "size_t" = UInt, prot: "unsigned long" = UInt) -> Int;
+        msync(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "int" = Int) -> Int;
+        mlock(start: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        munlock(start: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        mlockall(flags: "int" = Int) -> Int;
+        munlockall() -> Int;
+        mincore(start: "unsigned long" = UInt, len: "size_t" = UInt, vec: "unsigned char *" = Ptr) -> Int;
+        madvise(start: "unsigned long" = UInt, len: "size_t" = UInt, behavior: "int" = Int) -> Int;
+        remap_file_pages(start: "unsigned long" = UInt, size: "unsigned long" = UInt, prot: "unsigned long" = UInt, pgoff: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        mbind(start: "unsigned long" = UInt, len: "unsigned long" = UInt, mode: "unsigned long" = UInt, nmask: "const unsigned long *" = Ptr, maxnode: "unsigned long" = UInt, flags: "unsigned" = UInt) -> Int;
+        get_mempolicy(policy: "int *" = Ptr, nmask: "unsigned long *" = Ptr, maxnode: "unsigned long" = UInt, addr: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        set_mempolicy(mode: "int" = Int, nmask: "const unsigned long *" = Ptr, maxnode: "unsigned long" = UInt) -> Int;
+        migrate_pages(pid: "pid_t" = Int, maxnode: "unsigned long" = UInt, from: "const unsigned long *" = Ptr, to: "const unsigned long *" = Ptr) -> Int;
+        move_pages(pid: "pid_t" = Int, nr_pages: "unsigned long" = UInt, pages: "const void **" = Ptr, nodes: "const int *" = Ptr, status: "int *" = Ptr, flags: "int" = Int) -> Int;
+        rt_tgsigqueueinfo(tgid: "pid_t" = Int, pid: "pid_t" = Int, sig: "int" = Int, uinfo: "siginfo_t *" = Ptr) -> Int;
+        perf_event_open(attr_uptr: "struct perf_event_attr *" = Ptr, pid: "pid_t" = Int, cpu: "int" = Int, group_fd: "int" = Fd, flags: "unsigned long" = UInt) -> Fd;
+        accept4(fd: "int" = Fd, upeer_sockaddr: "struct sockaddr *" = Ptr, upeer_addrlen: "int *" = Ptr, flags: "int" = Int) -> Fd;
+        recvmmsg(fd: "int" = Fd, msg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned" = UInt, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        wait4(pid: "pid_t" = Int, stat_addr: "int *" = Ptr, options: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        prlimit64(pid: "pid_t" = Int, resource: "unsigned int" = UInt, new_rlim: "const struct rlimit64 *" = Ptr, old_rlim: "struct rlimit64 *" = Ptr) -> Int;
+        fanotify_init(flags: "unsigned int" = UInt, event_f_flags: "unsigned int" = UInt) -> Fd;
+        fanotify_mark(fanotify_fd: "int" = Fd, flags: "unsigned int" = UInt, mask: "u64" = UInt, fd: "int" = Fd, pathname: "const char *" = Str) -> Int;
+        name_to_handle_at(dfd: "int" = Fd, name: "const char *" = Str, handle: "struct file_handle *" = Ptr, mnt_id: "void *" = Ptr, flag: "int" = Int) -> Int;
+        open_by_handle_at(mountdirfd: "int" = Fd, handle: "struct file_handle *" = Ptr, flags: "int" = Int) -> Fd;
+        clock_adjtime(which_clock: "clockid_t" = Int, tx: "struct __kernel_timex *" = Ptr) -> Int;
+        syncfs(fd: "int" = Fd) -> Int;
+        setns(fd: "int" = Fd, nstype: "int" = Int) -> Int;
+        sendmmsg(fd: "int" = Fd, msg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned" = UInt) -> Int;
+        process_vm_readv(pid: "pid_t" = Int, lvec: "const struct iovec *" = Ptr, liovcnt: "unsigned long" = UInt, rvec: "const struct iovec *" = Ptr, riovcnt: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        process_vm_writev(pid: "pid_t" = Int, lvec: "const struct iovec *" = Ptr, liovcnt: "unsigned long" = UInt, rvec: "const struct iovec *" = Ptr, riovcnt: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        kcmp(pid1: "pid_t" = Int, pid2: "pid_t" = Int, type: "int" = Int, idx1: "unsigned long" = UInt, idx2: "unsigned long" = UInt) -> Int;
+        finit_module(fd: "int" = Fd, uargs: "const char *" = Str, flags: "int" = Int) -> Int;
+        sched_setattr(pid: "pid_t" = Int, attr: "struct sched_attr *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        sched_getattr(pid: "pid_t" = Int, attr: "struct sched_attr *" = Ptr, size: "unsigned int" = UInt, flags: "unsigned int" = UInt) -> Int;
+        renameat2(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str, flags: "unsigned int" = UInt) -> Int;
+        seccomp(op: "unsigned int" = UInt, flags: "unsigned int" = UInt, uargs: "void *" = Ptr) -> Int;
+        getrandom(buf: "char *" = Ptr, count: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        memfd_create(uname_ptr: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        bpf(cmd: "int" = Int, attr: "union bpf_attr *" = Ptr, size: "unsigned int" = UInt) -> Int;
+        execveat(dfd: "int" = Fd, filename: "const char *" = Str, argv: "const char *const *" = Ptr, envp: "const char *const *" = Ptr, flags: "int" = Int) -> Int;
+        userfaultfd(flags: "int" = Int) -> Fd;
+        membarrier(cmd: "int" = Int, flags: "unsigned int" = UInt, cpu_id: "int" = Int) -> Int;
+        mlock2(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "int" = Int) -> Int;
+        copy_file_range(fd_in: "int" = Fd, off_in: "loff_t *" = Ptr, fd_out: "int" = Fd, off_out: "loff_t *" = Ptr, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        preadv2(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt, flags: "rwf_t" = Int) -> Int;
+        pwritev2(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt, flags: "rwf_t" = Int) -> Int;
+        pkey_mprotect(start: "unsigned long" = UInt, len: "size_t" = UInt, prot: "unsigned long" = UInt, pkey: "int" = Int) -> Int;
+        pkey_alloc(flags: "unsigned long" = UInt, init_val: "unsigned long" = UInt) -> Int;
+        pkey_free(pkey: "int" = Int) -> Int;
+        statx(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt, mask: "unsigned" = UInt, buffer: "struct statx *" = Ptr) -> Int;
+        io_pgetevents(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr, sig: "const struct __aio_sigset *" = Ptr) -> Int;
+        rseq(rseq: "struct rseq *" = Ptr, rseq_len: "uint32_t" = UInt, flags: "int" = Int, sig: "uint32_t" = UInt) -> Int;
+        kexec_file_load(kernel_fd: "int" = Fd, initrd_fd: "int" = Fd, cmdline_len: "unsigned long" = UInt, cmdline_ptr: "const char *" = Str, flags: "unsigned long" = UInt) -> Int;
+        clock_gettime64(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_settime64(which_clock: "clockid_t" = Int, tp: "const struct __kernel_timespec *" = Ptr) -> Int;
+        clock_adjtime64(which_clock: "clockid_t" = Int, tx: "struct __kernel_timex *" = Ptr) -> Int;
+        clock_getres_time64(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_nanosleep_time64(which_clock: "clockid_t" = Int, flags: "int" = Int, rqtp: "const struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        timer_gettime64(timer_id: "timer_t" = Int, setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_settime64(timer_id: "timer_t" = Int, flags: "int" = Int, new_setting: "const struct __kernel_itimerspec *" = Ptr, old_setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_gettime64(ufd: "int" = Fd, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_settime64(ufd: "int" = Fd, flags: "int" = Int, utmr: "const struct __kernel_itimerspec *" = Ptr, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        utimensat_time64(dfd: "int" = Fd, filename: "const char *" = Str, t: "struct __kernel_timespec *" = Ptr, flags: "int" = Int) -> Int;
+        pselect6_time64(n: "int" = Int, inp: "fd_set *" = Ptr, outp: "fd_set *" = Ptr, exp: "fd_set *" = Ptr, tsp: "struct __kernel_timespec *" = Ptr, sig: "void *" = Ptr) -> Int;
+        ppoll_time64(ufds: "struct pollfd *" = Ptr, nfds: "unsigned int" = UInt, tsp: "struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        io_pgetevents_time64(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr, sig: "const struct __aio_sigset *" = Ptr) -> Int;
+        recvmmsg_time64(fd: "int" = Fd, mmsg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned int" = UInt, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedsend_time64(mqdes: "mqd_t" = Fd, u_msg_ptr: "const char *" = Ptr, msg_len: "unsigned int" = UInt, msg_prio: "unsigned int" = UInt, u_abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedreceive_time64(mqdes: "mqd_t" = Fd, u_msg_ptr: "char *" = Ptr, msg_len: "unsigned int" = UInt, u_msg_prio: "unsigned int *" = Ptr, u_abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        semtimedop_time64(semid: "int" = Int, tsops: "struct sembuf *" = Ptr, nsops: "unsigned int" = UInt, timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        rt_sigtimedwait_time64(uthese: "compat_sigset_t *" = Ptr, uinfo: "struct compat_siginfo *" = Ptr, uts: "struct __kernel_timespec *" = Ptr, sigsetsize: "compat_size_t" = UInt) -> Int;
+        futex_time64(uaddr: "u32 *" = Ptr, op: "int" = Int, val: "u32" = UInt, utime: "const struct __kernel_timespec *" = Ptr, uaddr2: "u32 *" = Ptr, val3: "u32" = UInt) -> Int;
+        sched_rr_get_interval_time64(pid: "pid_t" = Int, interval: "struct __kernel_timespec *" = Ptr) -> Int;
+        pidfd_send_signal(pidfd: "int" = Fd, sig: "int" = Int, info: "siginfo_t *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        io_uring_setup(entries: "u32" = UInt, p: "struct io_uring_params *" = Ptr) -> Fd;
+        io_uring_enter(fd: "unsigned int" = Fd, to_submit: "u32" = UInt, min_complete: "u32" = UInt, flags: "u32" = UInt, argp: "const void *" = Ptr, argsz: "size_t" = UInt) -> Int;
+        io_uring_register(fd: "unsigned int" = Fd, op: "unsigned int" = UInt, arg: "void *" = Ptr, nr_args: "unsigned int" = UInt) -> Int;
+        open_tree(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt) -> Fd;
+        move_mount(from_dfd: "int" = Fd, from_path: "const char *" = Str, to_dfd: "int" = Fd, to_path: "const char *" = Str, ms_flags: "unsigned int" = UInt) -> Int;
+        fsopen(fs_name: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        fsconfig(fs_fd: "int" = Fd, cmd: "unsigned int" = UInt, key: "const char *" = Str, value: "const void *" = Ptr, aux: "int" = Int) -> Int;
+        fsmount(fs_fd: "int" = Fd, flags: "unsigned int" = UInt, ms_flags: "unsigned int" = UInt) -> Fd;
+        fspick(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        pidfd_open(pid: "pid_t" = Int, flags: "unsigned int" = UInt) -> Fd;
+        clone3(uargs: "struct clone_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        close_range(fd: "unsigned int" = Fd, max_fd: "unsigned int" = Fd, flags: "unsigned int" = UInt) -> Int;
+        openat2(dfd: "int" = Fd, filename: "const char *" = Str, how: "struct open_how *" = Ptr, size: "size_t" = UInt) -> Fd;
+        pidfd_getfd(pidfd: "int" = Fd, fd: "int" = Fd, flags: "unsigned int" = UInt) -> Fd;
+        faccessat2(dfd: "int" = Fd, filename: "const char *" = Str, mode: "int" = Int, flags: "int" = Int) -> Int;
+        process_madvise(pidfd: "int" = Fd, vec: "const struct iovec *" = Ptr, vlen: "size_t" = UInt, behavior: "int" = Int, flags: "unsigned int" = UInt) -> Int;
+        epoll_pwait2(epfd: "int" = Fd, events: "struct epoll_event *" = Ptr, maxevents: "int" = Int, timeout: "const struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        mount_setattr(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned int" = UInt, uattr: "struct mount_attr *" = Ptr, usize: "size_t" = UInt) -> Int;
+        quotactl_fd(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, id: "qid_t" = UInt, addr: "void *" = Ptr) -> Int;
+        landlock_create_ruleset(attr: "const struct landlock_ruleset_attr *" = Ptr, size: "size_t" = UInt, flags: "__u32" = UInt) -> Fd;
+        landlock_add_rule(ruleset_fd: "int" = Fd, rule_type: "enum landlock_rule_type" = Int, rule_attr: "const void *" = Ptr, flags: "__u32" = UInt) -> Int;
+        landlock_restrict_self(ruleset_fd: "int" = Fd, flags: "__u32" = UInt) -> Int;
+        memfd_secret(flags: "unsigned int" = UInt) -> Fd;
+        process_mrelease(pidfd: "int" = Fd, flags: "unsigned int" = UInt) -> Int;
+        futex_waitv(waiters: "struct futex_waitv *" = Ptr, nr_futexes: "unsigned int" = UInt, flags: "unsigned int" = UInt, timeout: "struct __kernel_timespec *" = Ptr, clockid: "clockid_t" = Int) -> Int;
+        set_mempolicy_home_node(start: "unsigned long" = UInt, len: "unsigned long" = UInt, home_node: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        cachestat(fd: "unsigned int" = Fd, cstat_range: "struct cachestat_range *" = Ptr, cstat: "struct cachestat *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        fchmodat2(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        map_shadow_stack(addr: "unsigned long" = UInt, size: "unsigned long" = UInt, flags: "unsigned int" = UInt) -> Ptr;
+        futex_wake(uaddr: "void *" = Ptr, mask: "unsigned long" = UInt, nr: "int" = Int, flags: "unsigned int" = UInt) -> Int;
+        futex_wait(uaddr: "void *" = Ptr, val: "unsigned long" = UInt, mask: "unsigned long" = UInt, flags: "unsigned int" = UInt, timespec: "struct __kernel_timespec *" = Ptr, clockid: "clockid_t" = Int) -> Int;
+        futex_requeue(waiters: "struct futex_waitv *" = Ptr, flags: "unsigned int" = UInt, nr_wake: "int" = Int, nr_requeue: "int" = Int) -> Int;
+        statmount(req: "const struct mnt_id_req *" = Ptr, buf: "struct statmount *" = Ptr, bufsize: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        listmount(req: "const struct mnt_id_req *" = Ptr, mnt_ids: "u64 *" = Ptr, nr_mnt_ids: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        lsm_get_self_attr(attr: "unsigned int" = UInt, ctx: "struct lsm_ctx *" = Ptr, size: "u32 *" = Ptr, flags: "u32" = UInt) -> Int;
+        lsm_set_self_attr(attr: "unsigned int" = UInt, ctx: "struct lsm_ctx *" = Ptr, size: "u32" = UInt, flags: "u32" = UInt) -> Int;
+        lsm_list_modules(ids: "u64 *" = Ptr, size: "u32 *" = Ptr, flags: "u32" = UInt) -> Int;
+        mseal(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "unsigned long" = UInt) -> Int;
+        setxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str, args: "const struct xattr_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        getxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str, args: "struct xattr_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        listxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        removexattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str) -> Int;
+        open_tree_attr(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt, uattr: "struct mount_attr *" = Ptr, usize: "size_t" = UInt) -> Fd;
+        file_getattr(dfd: "int" = Fd, filename: "const char *" = Str, attr: "struct file_attr *" = Ptr, usize: "size_t" = UInt, at_flags: "unsigned int" = UInt) -> Int;
+        file_setattr(dfd: "int" = Fd, filename: "const char *" = Str, attr: "struct file_attr *" = Ptr, usize: "size_t" = UInt, at_flags: "unsigned int" = UInt) -> Int;
+        listns(req: "const struct ns_id_req *" = Ptr, ns_ids: "u64 *" = Ptr, nr_ns_ids: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        rseq_slice_yield() -> Int;
+    }
+}
+
+syscall_since! {
+    (3, 7) = [
+        io_setup, io_destroy, io_submit, io_cancel, io_getevents, setxattr,
+        lsetxattr, fsetxattr, getxattr, lgetxattr, fgetxattr, listxattr,
+        llistxattr, flistxattr, removexattr, lremovexattr, fremovexattr, getcwd,
+        lookup_dcookie, eventfd2, epoll_create1, epoll_ctl, epoll_pwait, dup,
+        dup3, fcntl, inotify_init1, inotify_add_watch, inotify_rm_watch, ioctl,
+        ioprio_set, ioprio_get, flock, mknodat, mkdirat, unlinkat, symlinkat,
+        linkat, renameat, umount2, mount, pivot_root, nfsservctl, statfs,
+        fstatfs, truncate, ftruncate, fallocate, faccessat, chdir, fchdir,
+        chroot, fchmod, fchmodat, fchownat, fchown, openat, close, vhangup,
+        pipe2, quotactl, getdents64, lseek, read, write, readv, writev, pread64,
+        pwrite64, preadv, pwritev, sendfile, pselect6, ppoll, signalfd4,
+        vmsplice, splice, tee, readlinkat, fstatat, fstat, sync, fsync,
+        fdatasync, sync_file_range, timerfd_create, timerfd_settime,
+        timerfd_gettime, utimensat, acct, capget, capset, personality, exit,
+        exit_group, waitid, set_tid_address, unshare, futex, set_robust_list,
+        get_robust_list, nanosleep, getitimer, setitimer, kexec_load,
+        init_module, delete_module, timer_create, timer_gettime,
+        timer_getoverrun, timer_settime, timer_delete, clock_settime,
+        clock_gettime, clock_getres, clock_nanosleep, syslog, ptrace,
+        sched_setparam, sched_setscheduler, sched_getscheduler, sched_getparam,
+        sched_setaffinity, sched_getaffinity, sched_yield,
+        sched_get_priority_max, sched_get_priority_min, sched_rr_get_interval,
+        restart_syscall, kill, tkill, tgkill, sigaltstack, rt_sigsuspend,
+        rt_sigaction, rt_sigprocmask, rt_sigpending, rt_sigtimedwait,
+        rt_sigqueueinfo, rt_sigreturn, setpriority, getpriority, reboot,
+        setregid, setgid, setreuid, setuid, setresuid, getresuid, setresgid,
+        getresgid, setfsuid, setfsgid, times, setpgid, getpgid, getsid, setsid,
+        getgroups, setgroups, uname, sethostname, setdomainname, getrlimit,
+        setrlimit, getrusage, umask, prctl, getcpu, gettimeofday, settimeofday,
+        adjtimex, getpid, getppid, getuid, geteuid, getgid, getegid, gettid,
+        sysinfo, mq_open, mq_unlink, mq_timedsend, mq_timedreceive, mq_notify,
+        mq_getsetattr, msgget, msgctl, msgrcv, msgsnd, semget, semctl,
+        semtimedop, semop, shmget, shmctl, shmat, shmdt, socket, socketpair,
+        bind, listen, accept, connect, getsockname, getpeername, sendto,
+        recvfrom, setsockopt, getsockopt, shutdown, sendmsg, recvmsg, readahead,
+        brk, munmap, mremap, add_key, request_key, keyctl, clone, execve, mmap,
+        fadvise64, swapon, swapoff, mprotect, msync, mlock, munlock, mlockall,
+        munlockall, mincore, madvise, remap_file_pages, mbind, get_mempolicy,
+        set_mempolicy, migrate_pages, move_pages, rt_tgsigqueueinfo,
+        perf_event_open, accept4, recvmmsg, wait4, prlimit64, fanotify_init,
+        fanotify_mark, name_to_handle_at, open_by_handle_at, clock_adjtime,
+        syncfs, setns, sendmmsg, process_vm_readv, process_vm_writev, kcmp,
+    ];
+
+    (3, 8) = [
+        finit_module,
+    ];
+
+    (3, 14) = [
+        sched_setattr, sched_getattr,
+    ];
+
+    (3, 15) = [
+        renameat2,
+    ];
+
+    (3, 17) = [
+        seccomp, getrandom, memfd_create,
+    ];
+
+    (3, 18) = [
+        bpf,
+    ];
+
+    (3, 19) = [
+        execveat,
+    ];
+
+    (4, 3) = [
+        userfaultfd, membarrier,
+    ];
+
+    (4, 4) = [
+        mlock2,
+    ];
+
+    (4, 5) = [
+        copy_file_range,
+    ];
+
+    (4, 6) = [
+        preadv2, pwritev2,
+    ];
+
+    (4, 9) = [
+        pkey_mprotect, pkey_alloc, pkey_free,
+    ];
+
+    (4, 11) = [
+        statx,
+    ];
+
+    (4, 18) = [
+        io_pgetevents, rseq,
+    ];
+
+    (5, 0) = [
+        kexec_file_load,
+    ];
+
+    (5, 1) = [
+        clock_gettime64, clock_settime64, clock_adjtime64, clock_getres_time64,
+        clock_nanosleep_time64, timer_gettime64, timer_settime64,
+        timerfd_gettime64, timerfd_settime64, utimensat_time64, pselect6_time64,
+        ppoll_time64, io_pgetevents_time64, recvmmsg_time64,
+        mq_timedsend_time64, mq_timedreceive_time64, semtimedop_time64,
+        rt_sigtimedwait_time64, futex_time64, sched_rr_get_interval_time64,
+        pidfd_send_signal, io_uring_setup, io_uring_enter, io_uring_register,
+    ];
+
+    (5, 2) = [
+        open_tree, move_mount, fsopen, fsconfig, fsmount, fspick,
+    ];
+
+    (5, 3) = [
+        pidfd_open, clone3,
+    ];
+
+    (5, 6) = [
+        openat2, pidfd_getfd,
+    ];
+
+    (5, 8) = [
+        faccessat2,
+    ];
+
+    (5, 9) = [
+        close_range,
+    ];
+
+    (5, 10) = [
+        process_madvise,
+    ];
+
+    (5, 11) = [
+        epoll_pwait2,
+    ];
+
+    (5, 12) = [
+        mount_setattr,
+    ];
+
+    (5, 13) = [
+        landlock_create_ruleset, landlock_add_rule, landlock_restrict_self,
+    ];
+
+    (5, 14) = [
+        quotactl_fd, memfd_secret,
+    ];
+
+    (5, 15) = [
+        process_mrelease,
+    ];
+
+    (5, 16) = [
+        futex_waitv,
+    ];
+
+    (5, 17) = [
+        set_mempolicy_home_node,
+    ];
+
+    (6, 5) = [
+        cachestat,
+    ];
+
+    (6, 6) = [
+        fchmodat2, map_shadow_stack,
+    ];
+
+    (6, 7) = [
+        futex_wake, futex_wait, futex_requeue,
+    ];
+
+    (6, 8) = [
+        statmount, listmount, lsm_get_self_attr, lsm_set_self_attr,
+        lsm_list_modules,
+    ];
+
+    (6, 10) = [
+        mseal,
+    ];
+
+    (6, 13) = [
+        setxattrat, getxattrat, listxattrat, removexattrat,
+    ];
+
+    (6, 15) = [
+        open_tree_attr,
+    ];
+
+    (6, 17) = [
+        file_getattr, file_setattr,
+    ];
+
+    (6, 18) = [
+        listns,
+    ];
+
+    (7, 0) = [
+        rseq_slice_yield,
+    ];
+}
+
+syscall_classes! {
+    /// Syscalls that take a file name as an argument (`%file`).
+    FILE = [
+        setxattr, lsetxattr, getxattr, lgetxattr, listxattr, llistxattr,
+        removexattr, lremovexattr, inotify_add_watch, mknodat, mkdirat,
+        unlinkat, symlinkat, linkat, renameat, umount2, mount, pivot_root,
+        statfs, truncate, faccessat, chdir, chroot, fchmodat, fchownat, openat,
+        quotactl, readlinkat, fstatat, utimensat, acct, execve, swapon, swapoff,
+        fanotify_mark, name_to_handle_at, renameat2, execveat, statx,
+        utimensat_time64, open_tree, move_mount, fspick, openat2, faccessat2,
+        mount_setattr, fchmodat2, setxattrat, getxattrat, listxattrat,
+        removexattrat, open_tree_attr, file_getattr, file_setattr,
+    ];
+
+    /// Syscalls that take or return a file descriptor (`%desc`).
+    DESC = [
+        fsetxattr, fgetxattr, flistxattr, fremovexattr, eventfd2, epoll_create1,
+        epoll_ctl, epoll_pwait, dup, dup3, fcntl, inotify_init1,
+        inotify_add_watch, inotify_rm_watch, ioctl, flock, mknodat, mkdirat,
+        unlinkat, symlinkat, linkat, renameat, fstatfs, ftruncate, fallocate,
+        faccessat, fchdir, fchmod, fchmodat, fchownat, fchown, openat, close,
+        pipe2, getdents64, lseek, read, write, readv, writev, pread64, pwrite64,
+        preadv, pwritev, sendfile, pselect6, ppoll, signalfd4, vmsplice, splice,
+        tee, readlinkat, fstatat, fstat, fsync, fdatasync, sync_file_range,
+        timerfd_create, timerfd_settime, timerfd_gettime, utimensat, mq_open,
+        mq_timedsend, mq_timedreceive, mq_notify, mq_getsetattr, readahead,
+        mmap, fadvise64, perf_event_open, fanotify_init, fanotify_mark,
+        name_to_handle_at, open_by_handle_at, syncfs, setns, finit_module,
+        renameat2, memfd_create, bpf, execveat, userfaultfd, copy_file_range,
+        preadv2, pwritev2, statx, kexec_file_load, timerfd_gettime64,
+        timerfd_settime64, utimensat_time64, pselect6_time64, ppoll_time64,
+        mq_timedsend_time64, mq_timedreceive_time64, pidfd_send_signal,
+        io_uring_setup, io_uring_enter, io_uring_register, open_tree,
+        move_mount, fsopen, fsconfig, fsmount, fspick, pidfd_open, close_range,
+        openat2, pidfd_getfd, faccessat2, process_madvise, epoll_pwait2,
+        mount_setattr, quotactl_fd, landlock_create_ruleset, landlock_add_rule,
+        landlock_restrict_self, memfd_secret, process_mrelease, cachestat,
+        fchmodat2, setxattrat, getxattrat, listxattrat, removexattrat,
+        open_tree_attr, file_getattr, file_setattr,
+    ];
+
+    /// Network-related syscalls (`%network`).
+    NETWORK = [
+        socket, socketpair, bind, listen, accept, connect, getsockname,
+        getpeername, sendto, recvfrom, setsockopt, getsockopt, shutdown,
+        sendmsg, recvmsg, accept4, recvmmsg, sendmmsg, recvmmsg_time64,
+    ];
+
+    /// Syscalls related to the process lifecycle (`%process`).
+    PROCESS = [
+        exit, exit_group, waitid, unshare, kill, tkill, tgkill, rt_sigqueueinfo,
+        clone, execve, rt_tgsigqueueinfo, wait4, execveat, pidfd_send_signal,
+        pidfd_open, clone3,
+    ];
+
+    /// Signal-related syscalls (`%signal`).
+    SIGNAL = [
+        signalfd4, kill, tkill, tgkill, sigaltstack, rt_sigsuspend,
+        rt_sigaction, rt_sigprocmask, rt_sigpending, rt_sigtimedwait,
+        rt_sigqueueinfo, rt_sigreturn, rt_tgsigqueueinfo,
+        rt_sigtimedwait_time64, pidfd_send_signal,
+    ];
+
+    /// System V IPC-related syscalls (`%ipc`).
+    IPC = [
+        msgget, msgctl, msgrcv, msgsnd, semget, semctl, semtimedop, semop,
+        shmget, shmctl, shmat, shmdt, semtimedop_time64,
+    ];
+
+    /// Memory mapping-related syscalls (`%memory`).
+    MEMORY = [
+        shmat, shmdt, brk, munmap, mremap, mmap, mprotect, msync, mlock,
+        munlock, mlockall, munlockall, mincore, madvise, remap_file_pages,
+        mbind, get_mempolicy, set_mempolicy, migrate_pages, move_pages, mlock2,
+        pkey_mprotect, set_mempolicy_home_node, map_shadow_stack, mseal,
+    ];
+
+    /// Syscalls that read or modify credentials (`%creds`).
+    CREDS = [
+        capget, capset, setregid, setgid, setreuid, setuid, setresuid,
+        getresuid, setresgid, getresgid, setfsuid, setfsgid, getgroups,
+        setgroups, prctl, getuid, geteuid, getgid, getegid,
+    ];
+
+    /// Syscalls that read or modify system clocks (`%clock`).
+    CLOCK = [
+        clock_settime, clock_gettime, clock_getres, gettimeofday, settimeofday,
+        adjtimex, clock_adjtime, clock_gettime64, clock_settime64,
+        clock_adjtime64, clock_getres_time64,
+    ];
+
+    /// Variants of the `stat` syscall (`%stat`).
+    STAT = [
+    ];
+
+    /// Variants of the `statfs` syscall (`%statfs`).
+    STATFS = [
+        statfs,
+    ];
+
+    /// Syscalls that always succeed and have no arguments (`%pure`).
+    PURE = [
+        getpid, getppid, getuid, geteuid, getgid, getegid, gettid,
+    ];
+}
+
+pub use crate::arch::markers::aarch64::*;
+pub use crate::regs::aarch64::Regs;
@@ -0,0 +1,820 @@
+use core::fmt;
+use core::hash::Hash;
+use core::str::FromStr;
+
+use super::audit::*;
+use crate::KernelVersion;
+
+macro_rules! arch_enum {
+    (
+        $(
+            $(#[$attr:meta])*
+            $variant:ident => $module:ident if $cfg:meta,
+                audit = $audit:expr;
+        )*
+    ) => {
+        /// A syscall table. Each one corresponds to an architecture module,
+        /// such as [`x86_64`](crate::x86_64), although only the tables of the
+        /// native architecture and the enabled features are available.
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum Arch {
+            $(
+                $(#[$attr])*
+                $variant,
+            )*
+        }
+
+        impl Arch {
+            /// All architectures, including the ones that aren't enabled.
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// Returns the name of the architecture module.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => core::stringify!($module),)*
+                }
+            }
+
+            /// Returns true if the syscall table of this architecture is
+            /// available.
+            pub const fn is_enabled(self) -> bool {
+                match self {
+                    $(Self::$variant => cfg!($cfg),)*
+                }
+            }
+        }
+
+        impl FromStr for Arch {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(core::stringify!($module) => Ok(Self::$variant),)*
+                    _ => Err(()),
+                }
+            }
+        }
+
+        impl AnySysno {
+            /// Constructs a syscall from its architecture and number. Returns
+            /// `None` if the number isn't in the table of that architecture
+            /// or if the table isn't enabled.
+            pub fn new(arch: Arch, id: usize) -> Option<Self> {
+                #[allow(unreachable_patterns)]
+                let id = match arch {
+                    $(
+                        #[cfg($cfg)]
+                        Arch::$variant => crate::$module::Sysno::new(id)?.id(),
+                    )*
+                    _ => return None,
+                };
+
+                Some(Self { arch, id })
+            }
+
+            /// Looks up a syscall by name. Returns `None` if there is no
+            /// syscall with this name in the table of that architecture or if
+            /// the table isn't enabled.
+            pub fn from_name(arch: Arch, name: &str) -> Option<Self> {
+                #[allow(unreachable_patterns)]
+                let id = match arch {
+                    $(
+                        #[cfg($cfg)]
+                        Arch::$variant => {
+                            name.parse::<crate::$module::Sysno>().ok()?.id()
+                        }
+                    )*
+                    _ => return None,
+                };
+
+                Some(Self { arch, id })
+            }
+
+            /// Returns the name of the syscall.
+            pub fn name(&self) -> &'static str {
+                #[allow(unreachable_patterns)]
+                match self.arch {
+                    $(
+                        #[cfg($cfg)]
+                        Arch::$variant => {
+                            crate::$module::Sysno::from(self.id).name()
+                        }
+                    )*
+                    // An `AnySysno` can only be constructed for enabled
+                    // architectures.
+                    _ => unreachable!(),
+                }
+            }
+
+            /// Returns the Linux version that added the syscall to its
+            /// architecture. See [`Sysno::since`](crate::Sysno::since).
+            pub fn since(&self) -> KernelVersion {
+                #[allow(unreachable_patterns)]
+                match self.arch {
+                    $(
+                        #[cfg($cfg)]
+                        Arch::$variant => {
+                            crate::$module::Sysno::from(self.id).since()
+                        }
+                    )*
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        /// The marker types of the architecture modules. Each is re-exported
+        /// by its module.
+        pub(crate) mod markers {
+            $(
+                #[cfg($cfg)]
+                pub mod $module {
+                    #[doc = core::concat!(
+                        "Marker type for the `",
+                        core::stringify!($module),
+                        "` syscall table. See [`SyscallArch`](crate::SyscallArch).",
+                    )]
+                    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+                    pub struct $variant;
+                }
+            )*
+        }
+
+        $(
+            #[cfg($cfg)]
+            impl sealed::Sealed for markers::$module::$variant {}
+
+            #[cfg($cfg)]
+            impl SyscallArch for markers::$module::$variant {
+                type Sysno = crate::$module::Sysno;
+
+                const ARCH: Arch = Arch::$variant;
+                const NAME: &'static str = core::stringify!($module);
+                const AUDIT_ARCH: u32 = $audit;
+            }
+
+            #[cfg($cfg)]
+            impl sealed::Sealed for crate::$module::Sysno {}
+
+            #[cfg($cfg)]
+            impl ArchSysno for crate::$module::Sysno {
+                type Arch = markers::$module::$variant;
+
+                const ALL: &'static [Self] = Self::ALL;
+                const GAP: (i32, i32) = Self::GAP;
+                const SINCE: &'static [KernelVersion] = Self::SINCE;
+                const CLASSES: Classes<Self> = {
+                    use crate::$module::class::*;
+
+                    Classes {
+                        file: FILE,
+                        desc: DESC,
+                        network: NETWORK,
+                        process: PROCESS,
+                        signal: SIGNAL,
+                        ipc: IPC,
+                        memory: MEMORY,
+                        creds: CREDS,
+                        clock: CLOCK,
+                        stat: STAT,
+                        statfs: STATFS,
+                        pure: PURE,
+                    }
+                };
+
+                fn id(&self) -> i32 {
+                    crate::$module::Sysno::id(self)
+                }
+
+                fn new(id: usize) -> Option<Self> {
+                    crate::$module::Sysno::new(id)
+                }
+
+                fn since(&self) -> KernelVersion {
+                    crate::$module::Sysno::since(self)
+                }
+            }
+        )*
+
+        /// The length of the largest enabled syscall table, including any
+        /// gaps.
+        pub(crate) const MAX_TABLE_SIZE: usize = {
+            let mut max = 0;
+
+            $(
+                #[cfg($cfg)]
+                if crate::$module::Sysno::table_size() > max {
+                    max = crate::$module::Sysno::table_size();
+                }
+            )*
+
+            max
+        };
+
+        $(
+            #[cfg($cfg)]
+            impl From<crate::$module::Sysno> for AnySysno {
+                fn from(sysno: crate::$module::Sysno) -> Self {
+                    Self {
+                        arch: Arch::$variant,
+                        id: sysno.id(),
+                    }
+                }
+            }
+
+            #[cfg($cfg)]
+            impl TryFrom<AnySysno> for crate::$module::Sysno {
+                type Error = ();
+
+                fn try_from(sysno: AnySysno) -> Result<Self, Self::Error> {
+                    if sysno.arch == Arch::$variant {
+                        Ok(Self::from(sysno.id))
+                    } else {
+                        Err(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+arch_enum! {
+    Aarch64 => aarch64 if any(target_arch = "aarch64", feature = "aarch64"),
+        audit = AUDIT_ARCH_AARCH64;
+    Arm => arm if any(target_arch = "arm", feature = "arm"),
+        audit = if cfg!(target_endian = "little") {
+            AUDIT_ARCH_ARM
+        } else {
+            AUDIT_ARCH_ARMEB
+        };
+    LoongArch64 => loongarch64
+        if any(target_arch = "loongarch64", feature = "loongarch64"),
+        audit = AUDIT_ARCH_LOONGARCH64;
+    Mips => mips if any(target_arch = "mips", feature = "mips"),
+        audit = if cfg!(target_endian = "little") {
+            AUDIT_ARCH_MIPSEL
+        } else {
+            AUDIT_ARCH_MIPS
+        };
+    Mips64 => mips64 if any(target_arch = "mips64", feature = "mips64"),
+        audit = if cfg!(target_endian = "little") {
+            AUDIT_ARCH_MIPSEL64
+        } else {
+            AUDIT_ARCH_MIPS64
+        };
+    MipsN32 => mipsn32
+        if any(
+            all(target_arch = "mips64", target_abi = "abin32"),
+            feature = "mipsn32"
+        ),
+        audit = if cfg!(target_endian = "little") {
+            AUDIT_ARCH_MIPSEL64N32
+        } else {
+            AUDIT_ARCH_MIPS64N32
+        };
+    PowerPc => powerpc if any(target_arch = "powerpc", feature = "powerpc"),
+        audit = AUDIT_ARCH_PPC;
+    PowerPc64 => powerpc64
+        if any(target_arch = "powerpc64", feature = "powerpc64"),
+        audit = if cfg!(target_endian = "little") {
+            AUDIT_ARCH_PPC64LE
+        } else {
+            AUDIT_ARCH_PPC64
+        };
+    Riscv32 => riscv32 if any(target_arch = "riscv32", feature = "riscv32"),
+        audit = AUDIT_ARCH_RISCV32;
+    Riscv64 => riscv64 if any(target_arch = "riscv64", feature = "riscv64"),
+        audit = AUDIT_ARCH_RISCV64;
+    S390x => s390x if any(target_arch = "s390x", feature = "s390x"),
+        audit = AUDIT_ARCH_S390X;
+    Sparc => sparc if any(target_arch = "sparc", feature = "sparc"),
+        audit = AUDIT_ARCH_SPARC;
+    Sparc64 => sparc64 if any(target_arch = "sparc64", feature = "sparc64"),
+        audit = AUDIT_ARCH_SPARC64;
+    X86 => x86 if any(target_arch = "x86", feature = "x86"),
+        audit = AUDIT_ARCH_I386;
+    X86_64 => x86_64 if any(target_arch = "x86_64", feature = "x86_64"),
+        audit = AUDIT_ARCH_X86_64;
+    /// The x32 ABI of x86-64. Its syscalls are reported with
+    /// `AUDIT_ARCH_X86_64` and have [`X32_SYSCALL_BIT`] set in their number.
+    ///
+    /// [`X32_SYSCALL_BIT`]: crate::seccomp::X32_SYSCALL_BIT
+    X32 => x32
+        if any(all(target_arch = "x86_64", target_abi = "x32"), feature = "x32"),
+        audit = AUDIT_ARCH_X86_64;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A syscall table, implemented by a marker type in each architecture module,
+/// such as [`x86_64::X86_64`](crate::x86_64::X86_64).
+///
+/// This is the compile-time counterpart of [`Arch`].
+pub trait SyscallArch:
+    sealed::Sealed
+    + fmt::Debug
+    + Default
+    + Clone
+    + Copy
+    + Eq
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + 'static
+{
+    /// The syscall numbers of this table.
+    type Sysno: ArchSysno<Arch = Self>;
+
+    /// The corresponding [`Arch`].
+    const ARCH: Arch;
+
+    /// The name of the architecture module.
+    const NAME: &'static str;
+
+    /// The `AUDIT_ARCH_*` value of syscalls made with this table. For
+    /// bi-endian architectures, this uses the endianness of the target.
+    const AUDIT_ARCH: u32;
+
+    /// The `e_machine` value of ELF files for this architecture. This is the
+    /// lower half of [`AUDIT_ARCH`](Self::AUDIT_ARCH).
+    const ELF_MACHINE: u16 = Self::AUDIT_ARCH as u16;
+}
+
+/// The `Sysno` type of an architecture module. [`SysnoSet`](crate::SysnoSet)
+/// and [`SysnoMap`](crate::SysnoMap) are generic over it, so that sets and maps
+/// of non-native syscalls can be built as well:
+///
+/// ```
+/// # #[cfg(any(target_arch = "x86", feature = "x86"))] {
+/// use syscalls::{x86, SysnoSet};
+///
+/// let compat = SysnoSet::new(&[x86::Sysno::socketcall, x86::Sysno::ipc]);
+/// assert!(compat.contains(x86::Sysno::ipc));
+/// # }
+/// ```
+pub trait ArchSysno:
+    sealed::Sealed
+    + fmt::Debug
+    + fmt::Display
+    + FromStr<Err = ()>
+    + Into<AnySysno>
+    + Clone
+    + Copy
+    + Eq
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + 'static
+{
+    /// The marker type of the architecture.
+    type Arch: SyscallArch<Sysno = Self>;
+
+    /// All syscalls of the table, ordered by number.
+    const ALL: &'static [Self];
+
+    /// The unused numbers before architecture-private syscalls, as
+    /// `(start, len)`.
+    #[doc(hidden)]
+    const GAP: (i32, i32);
+
+    /// The versions of [`ArchSysno::ALL`], in the same order.
+    #[doc(hidden)]
+    const SINCE: &'static [KernelVersion];
+
+    /// The syscall classes of the table, which are also in its `class`
+    /// module.
+    #[doc(hidden)]
+    const CLASSES: Classes<Self>;
+
+    /// Returns the syscall number.
+    fn id(&self) -> i32;
+
+    /// Constructs a syscall from the given number. Returns `None` if it isn't
+    /// in the table.
+    fn new(id: usize) -> Option<Self>;
+
+    /// Returns the Linux version that added the syscall to this architecture.
+    fn since(&self) -> KernelVersion;
+}
+
+/// The syscall classes of a table. See [`ArchSysno::CLASSES`].
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Classes<S: 'static> {
+    pub file: &'static [S],
+    pub desc: &'static [S],
+    pub network: &'static [S],
+    pub process: &'static [S],
+    pub signal: &'static [S],
+    pub ipc: &'static [S],
+    pub memory: &'static [S],
+    pub creds: &'static [S],
+    pub clock: &'static [S],
+    pub stat: &'static [S],
+    pub statfs: &'static [S],
+    pub pure: &'static [S],
+}
+
+/// Same as [`ArchSysno::id`], but usable in a `const fn`.
+pub(crate) const fn sysno_id<S: ArchSysno>(sysno: &S) -> i32 {
+    // SAFETY: `ArchSysno` is sealed and only implemented for the `Sysno` enums,
+    // which are `#[repr(i32)]`.
+    unsafe { *(sysno as *const S as *const i32) }
+}
+
+/// The first syscall number of a table.
+pub(crate) const fn first_id<S: ArchSysno>() -> i32 {
+    sysno_id(&S::ALL[0])
+}
+
+/// The position of a syscall in a [`SysnoSet`](crate::SysnoSet) or
+/// [`SysnoMap`](crate::SysnoMap). This skips over [`ArchSysno::GAP`] so that
+/// the architecture-private syscalls don't blow up their size.
+pub(crate) const fn sysno_index<S: ArchSysno>(sysno: &S) -> usize {
+    let (start, len) = S::GAP;
+    let mut id = sysno_id(sysno);
+    if id >= start {
+        id -= len;
+    }
+    (id - first_id::<S>()) as usize
+}
+
+/// The inverse of [`sysno_index`].
+pub(crate) const fn index_to_id<S: ArchSysno>(index: usize) -> i32 {
+    let (start, len) = S::GAP;
+    let id = index as i32 + first_id::<S>();
+    if id >= start {
+        id + len
+    } else {
+        id
+    }
+}
+
+/// The marker type of the native [`Sysno`](crate::Sysno) table.
+pub type Native = <crate::Sysno as ArchSysno>::Arch;
+
+impl Arch {
+    /// The architecture of the native [`Sysno`](crate::Sysno) table.
+    #[cfg(target_arch = "aarch64")]
+    pub const NATIVE: Self = Self::Aarch64;
+    #[cfg(target_arch = "arm")]
+    pub const NATIVE: Self = Self::Arm;
+    #[cfg(target_arch = "loongarch64")]
+    pub const NATIVE: Self = Self::LoongArch64;
+    #[cfg(target_arch = "mips")]
+    pub const NATIVE: Self = Self::Mips;
+    #[cfg(all(target_arch = "mips64", not(target_abi = "abin32")))]
+    pub const NATIVE: Self = Self::Mips64;
+    #[cfg(all(target_arch = "mips64", target_abi = "abin32"))]
+    pub const NATIVE: Self = Self::MipsN32;
+    #[cfg(target_arch = "powerpc")]
+    pub const NATIVE: Self = Self::PowerPc;
+    #[cfg(target_arch = "powerpc64")]
+    pub const NATIVE: Self = Self::PowerPc64;
+    #[cfg(target_arch = "riscv32")]
+    pub const NATIVE: Self = Self::Riscv32;
+    #[cfg(target_arch = "riscv64")]
+    pub const NATIVE: Self = Self::Riscv64;
+    #[cfg(target_arch = "s390x")]
+    pub const NATIVE: Self = Self::S390x;
+    #[cfg(target_arch = "sparc")]
+    pub const NATIVE: Self = Self::Sparc;
+    #[cfg(target_arch = "sparc64")]
+    pub const NATIVE: Self = Self::Sparc64;
+    #[cfg(target_arch = "x86")]
+    pub const NATIVE: Self = Self::X86;
+    #[cfg(all(target_arch = "x86_64", not(target_abi = "x32")))]
+    pub const NATIVE: Self = Self::X86_64;
+    #[cfg(all(target_arch = "x86_64", target_abi = "x32"))]
+    pub const NATIVE: Self = Self::X32;
+
+    /// Returns the architecture of an `AUDIT_ARCH_*` value. Both endiannesses
+    /// map to the same architecture, since they share a syscall table.
+    ///
+    /// `AUDIT_ARCH_X86_64` maps to [`Arch::X86_64`], since x32 syscalls can
+    /// only be told apart by their number. Use [`AnySysno::from_audit_arch`]
+    /// to decode those.
+    pub const fn from_audit_arch(audit_arch: u32) -> Option<Self> {
+        Some(match audit_arch {
+            AUDIT_ARCH_AARCH64 => Self::Aarch64,
+            AUDIT_ARCH_ARM | AUDIT_ARCH_ARMEB => Self::Arm,
+            AUDIT_ARCH_LOONGARCH64 => Self::LoongArch64,
+            AUDIT_ARCH_MIPS | AUDIT_ARCH_MIPSEL => Self::Mips,
+            AUDIT_ARCH_MIPS64 | AUDIT_ARCH_MIPSEL64 => Self::Mips64,
+            AUDIT_ARCH_MIPS64N32 | AUDIT_ARCH_MIPSEL64N32 => Self::MipsN32,
+            AUDIT_ARCH_PPC => Self::PowerPc,
+            AUDIT_ARCH_PPC64 | AUDIT_ARCH_PPC64LE => Self::PowerPc64,
+            AUDIT_ARCH_RISCV32 => Self::Riscv32,
+            AUDIT_ARCH_RISCV64 => Self::Riscv64,
+            AUDIT_ARCH_S390X => Self::S390x,
+            AUDIT_ARCH_SPARC => Self::Sparc,
+            AUDIT_ARCH_SPARC64 => Self::Sparc64,
+            AUDIT_ARCH_I386 => Self::X86,
+            AUDIT_ARCH_X86_64 => Self::X86_64,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Arch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A syscall of any enabled architecture.
+///
+/// The `Sysno` types of the architecture modules are unrelated types, which
+/// makes it hard to deal with more than one at a time. For example, a tracer
+/// on x86-64 can see syscalls from both x86-64 and i386 processes.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::{AnySysno, Arch, Sysno};
+///
+/// let sysno = AnySysno::from(Sysno::openat);
+/// assert_eq!(sysno.arch(), Arch::NATIVE);
+/// assert_eq!(sysno.name(), "openat");
+/// assert_eq!(Sysno::try_from(sysno), Ok(Sysno::openat));
+///
+/// // Without an architecture, the name refers to the native table.
+/// assert_eq!("openat".parse(), Ok(sysno));
+/// ```
+///
+/// With the `x86` feature enabled, i386 syscalls can be named as well:
+///
+/// ```
+/// # #[cfg(any(target_arch = "x86", feature = "x86"))] {
+/// use syscalls::{AnySysno, Arch};
+///
+/// let sysno: AnySysno = "x86:socketcall".parse().unwrap();
+/// assert_eq!(sysno.arch(), Arch::X86);
+/// assert_eq!(sysno.id(), 102);
+/// assert_eq!(sysno.to_string(), "x86:socketcall");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct AnySysno {
+    arch: Arch,
+    id: i32,
+}
+
+impl AnySysno {
+    /// Returns the architecture of the syscall.
+    pub const fn arch(&self) -> Arch {
+        self.arch
+    }
+
+    /// Returns the syscall number.
+    pub const fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Constructs a syscall from an `AUDIT_ARCH_*` value and a syscall
+    /// number, as found in seccomp data and `PTRACE_GET_SYSCALL_INFO`.
+    ///
+    /// An x86-64 syscall number with `X32_SYSCALL_BIT` set is looked up in
+    /// the x32 table.
+    pub fn from_audit_arch(audit_arch: u32, id: usize) -> Option<Self> {
+        let arch = match Arch::from_audit_arch(audit_arch)? {
+            Arch::X86_64 if id & X32_SYSCALL_BIT as usize != 0 => Arch::X32,
+            arch => arch,
+        };
+
+        Self::new(arch, id)
+    }
+}
+
+impl fmt::Display for AnySysno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.arch, self.name())
+    }
+}
+
+impl FromStr for AnySysno {
+    type Err = ();
+
+    /// Parses a syscall name, optionally qualified with an architecture, such
+    /// as `x86:socketcall`. Unqualified names refer to the native table.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (arch, name) = match s.split_once(':') {
+            Some((arch, name)) => (arch.parse()?, name),
+            None => (Arch::NATIVE, s),
+        };
+
+        Self::from_name(arch, name).ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sysno;
+
+    #[test]
+    fn test_arch() {
+        assert!(Arch::NATIVE.is_enabled());
+        #[cfg(not(target_abi = "x32"))]
+        assert_eq!(Arch::from_audit_arch(AUDIT_ARCH), Some(Arch::NATIVE));
+        assert_eq!(Arch::from_audit_arch(0), None);
+
+        for arch in Arch::ALL {
+            assert_eq!(arch.name().parse(), Ok(*arch));
+        }
+    }
+
+    #[test]
+    fn test_native() {
+        assert_eq!(Native::ARCH, Arch::NATIVE);
+        assert_eq!(Native::NAME, Arch::NATIVE.name());
+        assert_eq!(Native::AUDIT_ARCH, AUDIT_ARCH);
+        assert_eq!(<Sysno as ArchSysno>::ALL, Sysno::ALL);
+        assert_eq!(first_id::<Sysno>(), Sysno::first().id());
+
+        for sysno in Sysno::iter() {
+            assert_eq!(sysno_id(&sysno), sysno.id());
+            assert_eq!(
+                <Sysno as ArchSysno>::new(sysno.id() as usize),
+                Some(sysno)
+            );
+        }
+    }
+
+    #[cfg(all(feature = "x86", feature = "x86_64"))]
+    #[test]
+    fn test_elf_machine() {
+        use crate::{x86, x86_64};
+
+        assert_eq!(x86::X86::ELF_MACHINE, 3);
+        assert_eq!(x86_64::X86_64::ELF_MACHINE, 62);
+        assert_eq!(x86::X86::AUDIT_ARCH, AUDIT_ARCH_I386);
+    }
+
+    #[test]
+    fn test_any_sysno() {
+        for sysno in Sysno::iter() {
+            let any = AnySysno::from(sysno);
+            assert_eq!(any.name(), sysno.name());
+            assert_eq!(
+                AnySysno::new(Arch::NATIVE, sysno.id() as usize),
+                Some(any)
+            );
+            #[cfg(feature = "std")]
+            assert_eq!(any.to_string().parse(), Ok(any));
+            assert_eq!(Sysno::try_from(any), Ok(sysno));
+        }
+
+        assert_eq!(AnySysno::from_name(Arch::NATIVE, "bogus"), None);
+        assert_eq!("bogus:read".parse::<AnySysno>(), Err(()));
+    }
+
+    #[cfg(all(feature = "x86", feature = "x86_64"))]
+    #[test]
+    fn test_x86_and_x86_64() {
+        let socketcall: AnySysno = "x86:socketcall".parse().unwrap();
+        let socket = AnySysno::from(crate::x86_64::Sysno::socket);
+
+        assert_eq!(socketcall.id(), 102);
+        assert_eq!(
+            AnySysno::from_audit_arch(AUDIT_ARCH_I386, 102),
+            Some(socketcall)
+        );
+        assert_eq!(crate::x86_64::Sysno::try_from(socketcall), Err(()));
+        assert_eq!(
+            crate::x86::Sysno::try_from(socketcall),
+            Ok(crate::x86::Sysno::socketcall)
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(socket.to_string(), "x86_64:socket");
+        assert_eq!("x86_64:socketcall".parse::<AnySysno>(), Err(()));
+
+        // The same syscall was added at different times.
+        let x86_socket: AnySysno = "x86:socket".parse().unwrap();
+        assert_eq!(x86_socket.since(), KernelVersion(4, 3));
+        assert_eq!(socket.since(), KernelVersion::OLDEST);
+    }
+
+    /// Checks that no syscall is newer than the one with the next number,
+    /// since numbers are assigned in the order that syscalls are added.
+    fn check_since_order<S: ArchSysno>() {
+        // Numbers that were reserved long before the syscall was merged,
+        // syscalls that were removed and added back, and private syscalls
+        // that are numbered downwards.
+        const EXCEPTIONS: &[&str] =
+            &["close_range", "quotactl_fd", "fstat", "riscv_hwprobe"];
+
+        for pair in S::ALL.windows(2) {
+            let (a, b): (AnySysno, AnySysno) = (pair[0].into(), pair[1].into());
+
+            if b.id() == a.id() + 1 && !EXCEPTIONS.contains(&a.name()) {
+                assert!(
+                    a.since() <= b.since(),
+                    "{}: {} ({}) is newer than {} ({})",
+                    a.arch().name(),
+                    a.name(),
+                    a.since(),
+                    b.name(),
+                    b.since(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_since_order() {
+        check_since_order::<Sysno>();
+        #[cfg(feature = "aarch64")]
+        check_since_order::<crate::aarch64::Sysno>();
+        #[cfg(feature = "arm")]
+        check_since_order::<crate::arm::Sysno>();
+        #[cfg(feature = "loongarch64")]
+        check_since_order::<crate::loongarch64::Sysno>();
+        #[cfg(feature = "mips")]
+        check_since_order::<crate::mips::Sysno>();
+        #[cfg(feature = "mips64")]
+        check_since_order::<crate::mips64::Sysno>();
+        #[cfg(feature = "mipsn32")]
+        check_since_order::<crate::mipsn32::Sysno>();
+        #[cfg(feature = "powerpc")]
+        check_since_order::<crate::powerpc::Sysno>();
+        #[cfg(feature = "powerpc64")]
+        check_since_order::<crate::powerpc64::Sysno>();
+        #[cfg(feature = "riscv32")]
+        check_since_order::<crate::riscv32::Sysno>();
+        #[cfg(feature = "riscv64")]
+        check_since_order::<crate::riscv64::Sysno>();
+        #[cfg(feature = "s390x")]
+        check_since_order::<crate::s390x::Sysno>();
+        #[cfg(feature = "sparc")]
+        check_since_order::<crate::sparc::Sysno>();
+        #[cfg(feature = "sparc64")]
+        check_since_order::<crate::sparc64::Sysno>();
+        #[cfg(feature = "x86")]
+        check_since_order::<crate::x86::Sysno>();
+        #[cfg(feature = "x86_64")]
+        check_since_order::<crate::x86_64::Sysno>();
+        #[cfg(feature = "x32")]
+        check_since_order::<crate::x32::Sysno>();
+    }
+
+    #[cfg(feature = "x32")]
+    #[test]
+    fn test_x32() {
+        use crate::{x32, x86_64};
+
+        let bit = X32_SYSCALL_BIT as usize;
+
+        assert_eq!(x32::Sysno::first(), x32::Sysno::read);
+        assert_eq!(x32::Sysno::read.id() as usize, bit);
+        assert_eq!(x32::Sysno::rt_sigaction.id() as usize, bit | 512);
+        assert_eq!(x32::Sysno::new(0), None);
+        assert_eq!("uselib".parse::<x32::Sysno>(), Err(()));
+        assert_eq!(x32::X32::AUDIT_ARCH, AUDIT_ARCH_X86_64);
+
+        // Only the syscall number tells the two ABIs apart.
+        assert_eq!(
+            AnySysno::from_audit_arch(AUDIT_ARCH_X86_64, bit | 1),
+            Some(AnySysno::from(x32::Sysno::write))
+        );
+        assert_eq!(
+            AnySysno::from_audit_arch(AUDIT_ARCH_X86_64, 1),
+            Some(AnySysno::from(x86_64::Sysno::write))
+        );
+        assert_eq!(
+            AnySysno::from_audit_arch(AUDIT_ARCH_X86_64, bit | 13),
+            None
+        );
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            AnySysno::from(x32::Sysno::execve).to_string(),
+            "x32:execve"
+        );
+    }
+
+    #[cfg(feature = "mipsn32")]
+    #[test]
+    fn test_mipsn32() {
+        use crate::{mips64, mipsn32};
+
+        assert_eq!(mipsn32::Sysno::read.id(), 6000);
+        assert_eq!(mipsn32::Sysno::fcntl64.id(), 6212);
+        assert_eq!(mipsn32::Sysno::getdents64.id(), 6299);
+        assert_eq!(mipsn32::Sysno::statx.id(), 6330);
+        assert_eq!(mipsn32::Sysno::clock_gettime64.id(), 6403);
+        assert_eq!(mipsn32::Sysno::pidfd_send_signal.id(), 6424);
+        assert_eq!(mips64::Sysno::statx.id(), 5326);
+
+        for audit_arch in [AUDIT_ARCH_MIPS64N32, AUDIT_ARCH_MIPSEL64N32] {
+            assert_eq!(Arch::from_audit_arch(audit_arch), Some(Arch::MipsN32));
+            assert_eq!(
+                AnySysno::from_audit_arch(audit_arch, 6330),
+                Some(AnySysno::from(mipsn32::Sysno::statx))
+            );
+        }
+    }
+}
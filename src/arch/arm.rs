@@ -852,6 +852,797 @@ syscall_enum! {
         listns = 470,
         /// See [rseq_slice_yield(2)](https://man7.org/linux/man-pages/man2/rseq_slice_yield.2.html) for more info on this syscall.
         rseq_slice_yield = 471,
+        /// Architecture-private syscall. Not in the syscall table.
+        breakpoint = 983041,
+        /// Architecture-private syscall. Not in the syscall table.
+        cacheflush = 983042,
+        /// Architecture-private syscall. Not in the syscall table.
+        usr26 = 983043,
+        /// Architecture-private syscall. Not in the syscall table.
+        usr32 = 983044,
+        /// Architecture-private syscall. Not in the syscall table.
+        set_tls = 983045,
+        /// Architecture-private syscall. Not in the syscall table.
+        get_tls = 983046,
     }
-    LAST: rseq_slice_yield;
+    LAST: get_tls;
+    PRIVATE: breakpoint;
 }
+
+syscall_signatures! {
+    impl Sysno {
+        restart_syscall() -> Int;
+        exit(error_code: "int" = Int) -> Int;
+        fork() -> Int;
+        read(fd: "unsigned int" = Fd, buf: "char *" = Ptr, count: "size_t" = UInt) -> Int;
+        write(fd: "unsigned int" = Fd, buf: "const char *" = Ptr, count: "size_t" = UInt) -> Int;
+        open(filename: "const char *" = Str, flags: "int" = Int, mode: "umode_t" = UInt) -> Fd;
+        close(fd: "unsigned int" = Fd) -> Int;
+        creat(pathname: "const char *" = Str, mode: "umode_t" = UInt) -> Fd;
+        link(oldname: "const char *" = Str, newname: "const char *" = Str) -> Int;
+        unlink(pathname: "const char *" = Str) -> Int;
+        execve(filename: "const char *" = Str, argv: "const char *const *" = Ptr, envp: "const char *const *" = Ptr) -> Int;
+        chdir(filename: "const char *" = Str) -> Int;
+        mknod(filename: "const char *" = Str, mode: "umode_t" = UInt, dev: "unsigned" = UInt) -> Int;
+        chmod(filename: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        lchown(filename: "const char *" = Str, user: "old_uid_t" = UInt, group: "old_gid_t" = UInt) -> Int;
+        lseek(fd: "unsigned int" = Fd, offset: "off_t" = Int, whence: "unsigned int" = UInt) -> Int;
+        getpid() -> Int;
+        mount(dev_name: "char *" = Str, dir_name: "char *" = Str, type: "char *" = Str, flags: "unsigned long" = UInt, data: "void *" = Ptr) -> Int;
+        setuid(uid: "old_uid_t" = UInt) -> Int;
+        getuid() -> Int;
+        ptrace(request: "long" = Int, pid: "long" = Int, addr: "unsigned long" = UInt, data: "unsigned long" = UInt) -> Int;
+        pause() -> Int;
+        access(filename: "const char *" = Str, mode: "int" = Int) -> Int;
+        nice(increment: "int" = Int) -> Int;
+        sync() -> Int;
+        kill(pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        rename(oldname: "const char *" = Str, newname: "const char *" = Str) -> Int;
+        mkdir(pathname: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        rmdir(pathname: "const char *" = Str) -> Int;
+        dup(fildes: "unsigned int" = Fd) -> Fd;
+        pipe(fildes: "int *" = Ptr) -> Int;
+        times(tbuf: "struct tms *" = Ptr) -> Int;
+        brk(brk: "unsigned long" = UInt) -> Ptr;
+        setgid(gid: "old_gid_t" = UInt) -> Int;
+        getgid() -> Int;
+        geteuid() -> Int;
+        getegid() -> Int;
+        acct(name: "const char *" = Str) -> Int;
+        umount2(name: "char *" = Str, flags: "int" = Int) -> Int;
+        ioctl(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, arg: "unsigned long" = UInt) -> Int;
+        fcntl(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, arg: "unsigned long" = UInt) -> Int;
+        setpgid(pid: "pid_t" = Int, pgid: "pid_t" = Int) -> Int;
+        umask(mask: "int" = Int) -> Int;
+        chroot(filename: "const char *" = Str) -> Int;
+        ustat(dev: "unsigned" = UInt, ubuf: "struct ustat *" = Ptr) -> Int;
+        dup2(oldfd: "unsigned int" = Fd, newfd: "unsigned int" = Fd) -> Fd;
+        getppid() -> Int;
+        getpgrp() -> Int;
+        setsid() -> Int;
+        sigaction(sig: "int" = Int, act: "const struct old_sigaction *" = Ptr, oact: "struct old_sigaction *" = Ptr) -> Int;
+        setreuid(ruid: "old_uid_t" = UInt, euid: "old_uid_t" = UInt) -> Int;
+        setregid(rgid: "old_gid_t" = UInt, egid: "old_gid_t" = UInt) -> Int;
+        sigsuspend(mask: "old_sigset_t" = UInt) -> Int;
+        sigpending(uset: "old_sigset_t *" = Ptr) -> Int;
+        sethostname(name: "char *" = Str, len: "int" = Int) -> Int;
+        setrlimit(resource: "unsigned int" = UInt, rlim: "struct rlimit *" = Ptr) -> Int;
+        getrusage(who: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        gettimeofday(tv: "struct __kernel_old_timeval *" = Ptr, tz: "struct timezone *" = Ptr) -> Int;
+        settimeofday(tv: "struct __kernel_old_timeval *" = Ptr, tz: "struct timezone *" = Ptr) -> Int;
+        getgroups(gidsetsize: "int" = Int, grouplist: "old_gid_t *" = Ptr) -> Int;
+        setgroups(gidsetsize: "int" = Int, grouplist: "old_gid_t *" = Ptr) -> Int;
+        symlink(old: "const char *" = Str, new: "const char *" = Str) -> Int;
+        readlink(path: "const char *" = Str, buf: "char *" = Ptr, bufsiz: "int" = Int) -> Int;
+        uselib(library: "const char *" = Str) -> Int;
+        swapon(specialfile: "const char *" = Str, swap_flags: "int" = Int) -> Int;
+        reboot(magic1: "int" = Int, magic2: "int" = Int, cmd: "unsigned int" = UInt, arg: "void *" = Ptr) -> Int;
+        munmap(addr: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        truncate(path: "const char *" = Str, length: "long" = Int) -> Int;
+        ftruncate(fd: "unsigned int" = Fd, length: "off_t" = Int) -> Int;
+        fchmod(fd: "unsigned int" = Fd, mode: "umode_t" = UInt) -> Int;
+        fchown(fd: "unsigned int" = Fd, user: "old_uid_t" = UInt, group: "old_gid_t" = UInt) -> Int;
+        getpriority(which: "int" = Int, who: "int" = Int) -> Int;
+        setpriority(which: "int" = Int, who: "int" = Int, niceval: "int" = Int) -> Int;
+        statfs(path: "const char *" = Str, buf: "struct statfs *" = Ptr) -> Int;
+        fstatfs(fd: "unsigned int" = Fd, buf: "struct statfs *" = Ptr) -> Int;
+        syslog(type: "int" = Int, buf: "char *" = Ptr, len: "int" = Int) -> Int;
+        setitimer(which: "int" = Int, value: "struct __kernel_old_itimerval *" = Ptr, ovalue: "struct __kernel_old_itimerval *" = Ptr) -> Int;
+        getitimer(which: "int" = Int, value: "struct __kernel_old_itimerval *" = Ptr) -> Int;
+        stat(filename: "const char *" = Str, statbuf: "struct __old_kernel_stat *" = Ptr) -> Int;
+        lstat(filename: "const char *" = Str, statbuf: "struct __old_kernel_stat *" = Ptr) -> Int;
+        fstat(fd: "unsigned int" = Fd, statbuf: "struct __old_kernel_stat *" = Ptr) -> Int;
+        vhangup() -> Int;
+        wait4(pid: "pid_t" = Int, stat_addr: "int *" = Ptr, options: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        swapoff(specialfile: "const char *" = Str) -> Int;
+        sysinfo(info: "struct sysinfo *" = Ptr) -> Int;
+        fsync(fd: "unsigned int" = Fd) -> Int;
+        sigreturn() -> Int;
+        clone(clone_flags: "unsigned long" = UInt, newsp: "unsigned long" = UInt, parent_tidptr: "int *" = Ptr, tls: "unsigned long" = UInt, child_tidptr: "int *" = Ptr) -> Int;
+        setdomainname(name: "char *" = Str, len: "int" = Int) -> Int;
+        uname(name: "struct old_utsname *" = Ptr) -> Int;
+        adjtimex(txc_p: "struct __kernel_timex *" = Ptr) -> Int;
+        mprotect(start: "unsigned long" = UInt, len: "size_t" = UInt, prot: "unsigned long" = UInt) -> Int;
+        sigprocmask(how: "int" = Int, set: "old_sigset_t *" = Ptr, oset: "old_sigset_t *" = Ptr) -> Int;
+        init_module(umod: "void *" = Ptr, len: "unsigned long" = UInt, uargs: "const char *" = Str) -> Int;
+        delete_module(name_user: "const char *" = Str, flags: "unsigned int" = UInt) -> Int;
+        quotactl(cmd: "unsigned int" = UInt, special: "const char *" = Str, id: "qid_t" = UInt, addr: "void *" = Ptr) -> Int;
+        getpgid(pid: "pid_t" = Int) -> Int;
+        fchdir(fd: "unsigned int" = Fd) -> Int;
+        sysfs(option: "int" = Int, arg1: "unsigned long" = UInt, arg2: "unsigned long" = UInt) -> Int;
+        personality(personality: "unsigned int" = UInt) -> Int;
+        setfsuid(uid: "old_uid_t" = UInt) -> Int;
+        setfsgid(gid: "old_gid_t" = UInt) -> Int;
+        _llseek(fd: "unsigned int" = Fd, offset_high: "unsigned long" = UInt, offset_low: "unsigned long" = UInt, result: "loff_t *" = Ptr, whence: "unsigned int" = UInt) -> Int;
+        getdents(fd: "unsigned int" = Fd, dirent: "struct linux_dirent *" = Ptr, count: "unsigned int" = UInt) -> Int;
+        _newselect(n: "int" = Int, inp: "fd_set *" = Ptr, outp: "fd_set *" = Ptr, exp: "fd_set *" = Ptr, tvp: "struct __kernel_old_timeval *" = Ptr) -> Int;
+        flock(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt) -> Int;
+        msync(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "int" = Int) -> Int;
+        readv(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt) -> Int;
+        writev(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt) -> Int;
+        getsid(pid: "pid_t" = Int) -> Int;
+        fdatasync(fd: "unsigned int" = Fd) -> Int;
+        mlock(start: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        munlock(start: "unsigned long" = UInt, len: "size_t" = UInt) -> Int;
+        mlockall(flags: "int" = Int) -> Int;
+        munlockall() -> Int;
+        sched_setparam(pid: "pid_t" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_getparam(pid: "pid_t" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_setscheduler(pid: "pid_t" = Int, policy: "int" = Int, param: "struct sched_param *" = Ptr) -> Int;
+        sched_getscheduler(pid: "pid_t" = Int) -> Int;
+        sched_yield() -> Int;
+        sched_get_priority_max(policy: "int" = Int) -> Int;
+        sched_get_priority_min(policy: "int" = Int) -> Int;
+        sched_rr_get_interval(pid: "pid_t" = Int, interval: "struct __kernel_timespec *" = Ptr) -> Int;
+        nanosleep(rqtp: "struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        mremap(addr: "unsigned long" = UInt, old_len: "unsigned long" = UInt, new_len: "unsigned long" = UInt, flags: "unsigned long" = UInt, new_addr: "unsigned long" = UInt) -> Ptr;
+        setresuid(ruid: "old_uid_t" = UInt, euid: "old_uid_t" = UInt, suid: "old_uid_t" = UInt) -> Int;
+        getresuid(ruid: "old_uid_t *" = Ptr, euid: "old_uid_t *" = Ptr, suid: "old_uid_t *" = Ptr) -> Int;
+        poll(ufds: "struct pollfd *" = Ptr, nfds: "unsigned int" = UInt, timeout: "int" = Int) -> Int;
+        setresgid(rgid: "old_gid_t" = UInt, egid: "old_gid_t" = UInt, sgid: "old_gid_t" = UInt) -> Int;
+        getresgid(rgid: "old_gid_t *" = Ptr, egid: "old_gid_t *" = Ptr, sgid: "old_gid_t *" = Ptr) -> Int;
+        prctl(option: "int" = Int, arg2: "unsigned long" = UInt, arg3: "unsigned long" = UInt, arg4: "unsigned long" = UInt, arg5: "unsigned long" = UInt) -> Int;
+        rt_sigreturn() -> Int;
+        rt_sigaction(sig: "int" = Int, act: "const struct sigaction *" = Ptr, oact: "struct sigaction *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigprocmask(how: "int" = Int, set: "sigset_t *" = Ptr, oset: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigpending(set: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigtimedwait(uthese: "const sigset_t *" = Ptr, uinfo: "siginfo_t *" = Ptr, uts: "const struct __kernel_timespec *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        rt_sigqueueinfo(pid: "pid_t" = Int, sig: "int" = Int, uinfo: "siginfo_t *" = Ptr) -> Int;
+        rt_sigsuspend(unewset: "sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        pread64(fd: "unsigned int" = Fd, buf: "char *" = Ptr, count: "size_t" = UInt, pos: "loff_t" = Int) -> Int;
+        pwrite64(fd: "unsigned int" = Fd, buf: "const char *" = Ptr, count: "size_t" = UInt, pos: "loff_t" = Int) -> Int;
+        chown(filename: "const char *" = Str, user: "old_uid_t" = UInt, group: "old_gid_t" = UInt) -> Int;
+        getcwd(buf: "char *" = Ptr, size: "unsigned long" = UInt) -> Int;
+        capget(header: "cap_user_header_t" = Ptr, dataptr: "cap_user_data_t" = Ptr) -> Int;
+        capset(header: "cap_user_header_t" = Ptr, data: "const cap_user_data_t" = Ptr) -> Int;
+        sigaltstack(uss: "const struct sigaltstack *" = Ptr, uoss: "struct sigaltstack *" = Ptr) -> Int;
+        sendfile(out_fd: "int" = Fd, in_fd: "int" = Fd, offset: "off_t *" = Ptr, count: "size_t" = UInt) -> Int;
+        vfork() -> Int;
+        ugetrlimit(resource: "unsigned int" = UInt, rlim: "struct rlimit *" = Ptr) -> Int;
+        mmap2(addr: "unsigned long" = UInt, len: "unsigned long" = UInt, prot: "unsigned long" = UInt, flags: "unsigned long" = UInt, fd: "unsigned long" = Fd, pgoff: "unsigned long" = UInt) -> Ptr;
+        truncate64(path: "const char *" = Str, length: "loff_t" = Int) -> Int;
+        ftruncate64(fd: "unsigned int" = Fd, length: "loff_t" = Int) -> Int;
+        stat64(filename: "const char *" = Str, statbuf: "struct stat64 *" = Ptr) -> Int;
+        lstat64(filename: "const char *" = Str, statbuf: "struct stat64 *" = Ptr) -> Int;
+        fstat64(fd: "unsigned long" = Fd, statbuf: "struct stat64 *" = Ptr) -> Int;
+        lchown32(filename: "const char *" = Str, user: "uid_t" = UInt, group: "gid_t" = UInt) -> Int;
+        getuid32() -> Int;
+        getgid32() -> Int;
+        geteuid32() -> Int;
+        getegid32() -> Int;
+        setreuid32(ruid: "uid_t" = UInt, euid: "uid_t" = UInt) -> Int;
+        setregid32(rgid: "gid_t" = UInt, egid: "gid_t" = UInt) -> Int;
+        getgroups32(gidsetsize: "int" = Int, grouplist: "gid_t *" = Ptr) -> Int;
+        setgroups32(gidsetsize: "int" = Int, grouplist: "gid_t *" = Ptr) -> Int;
+        fchown32(fd: "unsigned int" = Fd, user: "uid_t" = UInt, group: "gid_t" = UInt) -> Int;
+        setresuid32(ruid: "uid_t" = UInt, euid: "uid_t" = UInt, suid: "uid_t" = UInt) -> Int;
+        getresuid32(ruid: "uid_t *" = Ptr, euid: "uid_t *" = Ptr, suid: "uid_t *" = Ptr) -> Int;
+        setresgid32(rgid: "gid_t" = UInt, egid: "gid_t" = UInt, sgid: "gid_t" = UInt) -> Int;
+        getresgid32(rgid: "gid_t *" = Ptr, egid: "gid_t *" = Ptr, sgid: "gid_t *" = Ptr) -> Int;
+        chown32(filename: "const char *" = Str, user: "uid_t" = UInt, group: "gid_t" = UInt) -> Int;
+        setuid32(uid: "uid_t" = UInt) -> Int;
+        setgid32(gid: "gid_t" = UInt) -> Int;
+        setfsuid32(uid: "uid_t" = UInt) -> Int;
+        setfsgid32(gid: "gid_t" = UInt) -> Int;
+        getdents64(fd: "unsigned int" = Fd, dirent: "struct linux_dirent64 *" = Ptr, count: "unsigned int" = UInt) -> Int;
+        pivot_root(new_root: "const char *" = Str, put_old: "const char *" = Str) -> Int;
+        mincore(start: "unsigned long" = UInt, len: "size_t" = UInt, vec: "unsigned char *" = Ptr) -> Int;
+        madvise(start: "unsigned long" = UInt, len: "size_t" = UInt, behavior: "int" = Int) -> Int;
+        fcntl64(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, arg: "unsigned long" = UInt) -> Int;
+        gettid() -> Int;
+        readahead(fd: "int" = Fd, offset: "loff_t" = Int, count: "size_t" = UInt) -> Int;
+        setxattr(path: "const char *" = Str, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        lsetxattr(path: "const char *" = Str, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        fsetxattr(fd: "int" = Fd, name: "const char *" = Str, value: "const void *" = Ptr, size: "size_t" = UInt, flags: "int" = Int) -> Int;
+        getxattr(path: "const char *" = Str, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        lgetxattr(path: "const char *" = Str, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        fgetxattr(fd: "int" = Fd, name: "const char *" = Str, value: "void *" = Ptr, size: "size_t" = UInt) -> Int;
+        listxattr(path: "const char *" = Str, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        llistxattr(path: "const char *" = Str, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        flistxattr(fd: "int" = Fd, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        removexattr(path: "const char *" = Str, name: "const char *" = Str) -> Int;
+        lremovexattr(path: "const char *" = Str, name: "const char *" = Str) -> Int;
+        fremovexattr(fd: "int" = Fd, name: "const char *" = Str) -> Int;
+        tkill(pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        sendfile64(out_fd: "int" = Fd, in_fd: "int" = Fd, offset: "loff_t *" = Ptr, count: "size_t" = UInt) -> Int;
+        futex(uaddr: "u32 *" = Ptr, op: "int" = Int, val: "u32" = UInt, utime: "const struct __kernel_timespec *" = Ptr, uaddr2: "u32 *" = Ptr, val3: "u32" = UInt) -> Int;
+        sched_setaffinity(pid: "pid_t" = Int, len: "unsigned int" = UInt, user_mask_ptr: "unsigned long *" = Ptr) -> Int;
+        sched_getaffinity(pid: "pid_t" = Int, len: "unsigned int" = UInt, user_mask_ptr: "unsigned long *" = Ptr) -> Int;
+        io_setup(nr_reqs: "unsigned" = UInt, ctx: "aio_context_t *" = Ptr) -> Int;
+        io_destroy(ctx: "aio_context_t" = UInt) -> Int;
+        io_getevents(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        io_submit(ctx_id: "aio_context_t" = UInt, nr: "long" = Int, iocbpp: "struct iocb **" = Ptr) -> Int;
+        io_cancel(ctx_id: "aio_context_t" = UInt, iocb: "struct iocb *" = Ptr, result: "struct io_event *" = Ptr) -> Int;
+        exit_group(error_code: "int" = Int) -> Int;
+        epoll_create(size: "int" = Int) -> Fd;
+        epoll_ctl(epfd: "int" = Fd, op: "int" = Int, fd: "int" = Fd, event: "struct epoll_event *" = Ptr) -> Int;
+        epoll_wait(epfd: "int" = Fd, events: "struct epoll_event *" = Ptr, maxevents: "int" = Int, timeout: "int" = Int) -> Int;
+        remap_file_pages(start: "unsigned long" = UInt, size: "unsigned long" = UInt, prot: "unsigned long" = UInt, pgoff: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        set_tid_address(tidptr: "int *" = Ptr) -> Int;
+        timer_create(which_clock: "clockid_t" = Int, timer_event_spec: "struct sigevent *" = Ptr, created_timer_id: "timer_t *" = Ptr) -> Int;
+        timer_settime(timer_id: "timer_t" = Int, flags: "int" = Int, new_setting: "const struct __kernel_itimerspec *" = Ptr, old_setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_gettime(timer_id: "timer_t" = Int, setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_getoverrun(timer_id: "timer_t" = Int) -> Int;
+        timer_delete(timer_id: "timer_t" = Int) -> Int;
+        clock_settime(which_clock: "clockid_t" = Int, tp: "const struct __kernel_timespec *" = Ptr) -> Int;
+        clock_gettime(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_getres(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_nanosleep(which_clock: "clockid_t" = Int, flags: "int" = Int, rqtp: "const struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        statfs64(path: "const char *" = Str, sz: "size_t" = UInt, buf: "struct statfs64 *" = Ptr) -> Int;
+        fstatfs64(fd: "unsigned int" = Fd, sz: "size_t" = UInt, buf: "struct statfs64 *" = Ptr) -> Int;
+        tgkill(tgid: "pid_t" = Int, pid: "pid_t" = Int, sig: "int" = Int) -> Int;
+        utimes(filename: "char *" = Str, utimes: "struct __kernel_old_timeval *" = Ptr) -> Int;
+        arm_fadvise64_64(fd: "int" = Fd, advice: "int" = Int, offset: "loff_t" = Int, len: "loff_t" = Int) -> Int;
+        pciconfig_iobase(which: "long" = Int, bus: "unsigned long" = UInt, devfn: "unsigned long" = UInt) -> Int;
+        pciconfig_read(bus: "unsigned long" = UInt, dfn: "unsigned long" = UInt, off: "unsigned long" = UInt, len: "unsigned long" = UInt, buf: "void *" = Ptr) -> Int;
+        pciconfig_write(bus: "unsigned long" = UInt, dfn: "unsigned long" = UInt, off: "unsigned long" = UInt, len: "unsigned long" = UInt, buf: "void *" = Ptr) -> Int;
+        mq_open(name: "const char *" = Str, oflag: "int" = Int, mode: "umode_t" = UInt, attr: "struct mq_attr *" = Ptr) -> Fd;
+        mq_unlink(name: "const char *" = Str) -> Int;
+        mq_timedsend(mqdes: "mqd_t" = Fd, msg_ptr: "const char *" = Ptr, msg_len: "size_t" = UInt, msg_prio: "unsigned int" = UInt, abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedreceive(mqdes: "mqd_t" = Fd, msg_ptr: "char *" = Ptr, msg_len: "size_t" = UInt, msg_prio: "unsigned int *" = Ptr, abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_notify(mqdes: "mqd_t" = Fd, notification: "const struct sigevent *" = Ptr) -> Int;
+        mq_getsetattr(mqdes: "mqd_t" = Fd, mqstat: "const struct mq_attr *" = Ptr, omqstat: "struct mq_attr *" = Ptr) -> Int;
+        waitid(which: "int" = Int, pid: "pid_t" = Int, infop: "struct siginfo *" = Ptr, options: "int" = Int, ru: "struct rusage *" = Ptr) -> Int;
+        socket(family: "int" = Int, type: "int" = Int, protocol: "int" = Int) -> Fd;
+        bind(fd: "int" = Fd, umyaddr: "struct sockaddr *" = Ptr, addrlen: "int" = Int) -> Int;
+        connect(fd: "int" = Fd, uservaddr: "struct sockaddr *" = Ptr, addrlen: "int" = Int) -> Int;
+        listen(fd: "int" = Fd, backlog: "int" = Int) -> Int;
+        accept(fd: "int" = Fd, upeer_sockaddr: "struct sockaddr *" = Ptr, upeer_addrlen: "int *" = Ptr) -> Fd;
+        getsockname(fd: "int" = Fd, usockaddr: "struct sockaddr *" = Ptr, usockaddr_len: "int *" = Ptr) -> Int;
+        getpeername(fd: "int" = Fd, usockaddr: "struct sockaddr *" = Ptr, usockaddr_len: "int *" = Ptr) -> Int;
+        socketpair(family: "int" = Int, type: "int" = Int, protocol: "int" = Int, usockvec: "int *" = Ptr) -> Int;
+        send(fd: "int" = Fd, buff: "void *" = Ptr, len: "size_t" = UInt, flags: "unsigned" = UInt) -> Int;
+        sendto(fd: "int" = Fd, buff: "void *" = Ptr, len: "size_t" = UInt, flags: "unsigned" = UInt, addr: "struct sockaddr *" = Ptr, addr_len: "int" = Int) -> Int;
+        recv(fd: "int" = Fd, ubuf: "void *" = Ptr, size: "size_t" = UInt, flags: "unsigned" = UInt) -> Int;
+        recvfrom(fd: "int" = Fd, ubuf: "void *" = Ptr, size: "size_t" = UInt, flags: "unsigned" = UInt, addr: "struct sockaddr *" = Ptr, addr_len: "int *" = Ptr) -> Int;
+        shutdown(fd: "int" = Fd, how: "int" = Int) -> Int;
+        setsockopt(fd: "int" = Fd, level: "int" = Int, optname: "int" = Int, optval: "char *" = Ptr, optlen: "int" = Int) -> Int;
+        getsockopt(fd: "int" = Fd, level: "int" = Int, optname: "int" = Int, optval: "char *" = Ptr, optlen: "int *" = Ptr) -> Int;
+        sendmsg(fd: "int" = Fd, msg: "struct user_msghdr *" = Ptr, flags: "unsigned" = UInt) -> Int;
+        recvmsg(fd: "int" = Fd, msg: "struct user_msghdr *" = Ptr, flags: "unsigned" = UInt) -> Int;
+        semop(semid: "int" = Int, sops: "struct sembuf *" = Ptr, nsops: "unsigned" = UInt) -> Int;
+        semget(key: "key_t" = Int, nsems: "int" = Int, semflg: "int" = Int) -> Int;
+        semctl(semid: "int" = Int, semnum: "int" = Int, cmd: "int" = Int, arg: "unsigned long" = UInt) -> Int;
+        msgsnd(msqid: "int" = Int, msgp: "struct msgbuf *" = Ptr, msgsz: "size_t" = UInt, msgflg: "int" = Int) -> Int;
+        msgrcv(msqid: "int" = Int, msgp: "struct msgbuf *" = Ptr, msgsz: "size_t" = UInt, msgtyp: "long" = Int, msgflg: "int" = Int) -> Int;
+        msgget(key: "key_t" = Int, msgflg: "int" = Int) -> Int;
+        msgctl(msqid: "int" = Int, cmd: "int" = Int, buf: "struct msqid_ds *" = Ptr) -> Int;
+        shmat(shmid: "int" = Int, shmaddr: "char *" = Ptr, shmflg: "int" = Int) -> Ptr;
+        shmdt(shmaddr: "char *" = Ptr) -> Int;
+        shmget(key: "key_t" = Int, size: "size_t" = UInt, flag: "int" = Int) -> Int;
+        shmctl(shmid: "int" = Int, cmd: "int" = Int, buf: "struct shmid_ds *" = Ptr) -> Int;
+        add_key(_type: "const char *" = Str, _description: "const char *" = Str, _payload: "const void *" = Ptr, plen: "size_t" = UInt, destringid: "key_serial_t" = Int) -> Int;
+        request_key(_type: "const char *" = Str, _description: "const char *" = Str, _callout_info: "const char *" = Str, destringid: "key_serial_t" = Int) -> Int;
+        keyctl(cmd: "int" = Int, arg2: "unsigned long" = UInt, arg3: "unsigned long" = UInt, arg4: "unsigned long" = UInt, arg5: "unsigned long" = UInt) -> Int;
+        semtimedop(semid: "int" = Int, sops: "struct sembuf *" = Ptr, nsops: "unsigned" = UInt, timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        ioprio_set(which: "int" = Int, who: "int" = Int, ioprio: "int" = Int) -> Int;
+        ioprio_get(which: "int" = Int, who: "int" = Int) -> Int;
+        inotify_init() -> Fd;
+        inotify_add_watch(fd: "int" = Fd, path: "const char *" = Str, mask: "u32" = UInt) -> Int;
+        inotify_rm_watch(fd: "int" = Fd, wd: "__s32" = Int) -> Int;
+        mbind(start: "unsigned long" = UInt, len: "unsigned long" = UInt, mode: "unsigned long" = UInt, nmask: "const unsigned long *" = Ptr, maxnode: "unsigned long" = UInt, flags: "unsigned" = UInt) -> Int;
+        get_mempolicy(policy: "int *" = Ptr, nmask: "unsigned long *" = Ptr, maxnode: "unsigned long" = UInt, addr: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        set_mempolicy(mode: "int" = Int, nmask: "const unsigned long *" = Ptr, maxnode: "unsigned long" = UInt) -> Int;
+        openat(dfd: "int" = Fd, filename: "const char *" = Str, flags: "int" = Int, mode: "umode_t" = UInt) -> Fd;
+        mkdirat(dfd: "int" = Fd, pathname: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        mknodat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt, dev: "unsigned" = UInt) -> Int;
+        fchownat(dfd: "int" = Fd, filename: "const char *" = Str, user: "uid_t" = UInt, group: "gid_t" = UInt, flag: "int" = Int) -> Int;
+        futimesat(dfd: "int" = Fd, filename: "const char *" = Str, utimes: "struct __kernel_old_timeval *" = Ptr) -> Int;
+        fstatat64(dfd: "int" = Fd, filename: "const char *" = Str, statbuf: "struct stat64 *" = Ptr, flag: "int" = Int) -> Int;
+        unlinkat(dfd: "int" = Fd, pathname: "const char *" = Str, flag: "int" = Int) -> Int;
+        renameat(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str) -> Int;
+        linkat(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str, flags: "int" = Int) -> Int;
+        symlinkat(oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str) -> Int;
+        readlinkat(dfd: "int" = Fd, path: "const char *" = Str, buf: "char *" = Ptr, bufsiz: "int" = Int) -> Int;
+        fchmodat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt) -> Int;
+        faccessat(dfd: "int" = Fd, filename: "const char *" = Str, mode: "int" = Int) -> Int;
+        pselect6(n: "int" = Int, inp: "fd_set *" = Ptr, outp: "fd_set *" = Ptr, exp: "fd_set *" = Ptr, tsp: "struct __kernel_timespec *" = Ptr, sig: "void *" = Ptr) -> Int;
+        ppoll(ufds: "struct pollfd *" = Ptr, nfds: "unsigned int" = UInt, tsp: "struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        unshare(unshare_flags: "unsigned long" = UInt) -> Int;
+        set_robust_list(head: "struct robust_list_head *" = Ptr, len: "size_t" = UInt) -> Int;
+        get_robust_list(pid: "int" = Int, head_ptr: "struct robust_list_head **" = Ptr, len_ptr: "size_t *" = Ptr) -> Int;
+        splice(fd_in: "int" = Fd, off_in: "loff_t *" = Ptr, fd_out: "int" = Fd, off_out: "loff_t *" = Ptr, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        arm_sync_file_range(fd: "int" = Fd, flags: "unsigned int" = UInt, offset: "loff_t" = Int, nbytes: "loff_t" = Int) -> Int;
+        tee(fdin: "int" = Fd, fdout: "int" = Fd, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        vmsplice(fd: "int" = Fd, vec: "const struct iovec *" = Ptr, nr_segs: "unsigned long" = UInt, flags: "unsigned int" = UInt) -> Int;
+        move_pages(pid: "pid_t" = Int, nr_pages: "unsigned long" = UInt, pages: "const void **" = Ptr, nodes: "const int *" = Ptr, status: "int *" = Ptr, flags: "int" = Int) -> Int;
+        getcpu(cpu: "unsigned *" = Ptr, node: "unsigned *" = Ptr, cache: "struct getcpu_cache *" = Ptr) -> Int;
+        epoll_pwait(epfd: "int" = Fd, events: "struct epoll_event *" = Ptr, maxevents: "int" = Int, timeout: "int" = Int, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        kexec_load(entry: "unsigned long" = UInt, nr_segments: "unsigned long" = UInt, segments: "struct kexec_segment *" = Ptr, flags: "unsigned long" = UInt) -> Int;
+        utimensat(dfd: "int" = Fd, filename: "const char *" = Str, utimes: "struct __kernel_timespec *" = Ptr, flags: "int" = Int) -> Int;
+        signalfd(ufd: "int" = Fd, user_mask: "sigset_t *" = Ptr, sizemask: "size_t" = UInt) -> Fd;
+        timerfd_create(clockid: "int" = Int, flags: "int" = Int) -> Fd;
+        eventfd(count: "unsigned int" = UInt) -> Fd;
+        fallocate(fd: "int" = Fd, mode: "int" = Int, offset: "loff_t" = Int, len: "loff_t" = Int) -> Int;
+        timerfd_settime(ufd: "int" = Fd, flags: "int" = Int, utmr: "const struct __kernel_itimerspec *" = Ptr, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_gettime(ufd: "int" = Fd, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        signalfd4(ufd: "int" = Fd, user_mask: "sigset_t *" = Ptr, sizemask: "size_t" = UInt, flags: "int" = Int) -> Fd;
+        eventfd2(count: "unsigned int" = UInt, flags: "int" = Int) -> Fd;
+        epoll_create1(flags: "int" = Int) -> Fd;
+        dup3(oldfd: "unsigned int" = Fd, newfd: "unsigned int" = Fd, flags: "int" = Int) -> Fd;
+        pipe2(fildes: "int *" = Ptr, flags: "int" = Int) -> Int;
+        inotify_init1(flags: "int" = Int) -> Fd;
+        preadv(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt) -> Int;
+        pwritev(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt) -> Int;
+        rt_tgsigqueueinfo(tgid: "pid_t" = Int, pid: "pid_t" = Int, sig: "int" = Int, uinfo: "siginfo_t *" = Ptr) -> Int;
+        perf_event_open(attr_uptr: "struct perf_event_attr *" = Ptr, pid: "pid_t" = Int, cpu: "int" = Int, group_fd: "int" = Fd, flags: "unsigned long" = UInt) -> Fd;
+        recvmmsg(fd: "int" = Fd, msg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned" = UInt, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        accept4(fd: "int" = Fd, upeer_sockaddr: "struct sockaddr *" = Ptr, upeer_addrlen: "int *" = Ptr, flags: "int" = Int) -> Fd;
+        fanotify_init(flags: "unsigned int" = UInt, event_f_flags: "unsigned int" = UInt) -> Fd;
+        fanotify_mark(fanotify_fd: "int" = Fd, flags: "unsigned int" = UInt, mask: "u64" = UInt, fd: "int" = Fd, pathname: "const char *" = Str) -> Int;
+        prlimit64(pid: "pid_t" = Int, resource: "unsigned int" = UInt, new_rlim: "const struct rlimit64 *" = Ptr, old_rlim: "struct rlimit64 *" = Ptr) -> Int;
+        name_to_handle_at(dfd: "int" = Fd, name: "const char *" = Str, handle: "struct file_handle *" = Ptr, mnt_id: "void *" = Ptr, flag: "int" = Int) -> Int;
+        open_by_handle_at(mountdirfd: "int" = Fd, handle: "struct file_handle *" = Ptr, flags: "int" = Int) -> Fd;
+        clock_adjtime(which_clock: "clockid_t" = Int, tx: "struct __kernel_timex *" = Ptr) -> Int;
+        syncfs(fd: "int" = Fd) -> Int;
+        sendmmsg(fd: "int" = Fd, msg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned" = UInt) -> Int;
+        setns(fd: "int" = Fd, nstype: "int" = Int) -> Int;
+        process_vm_readv(pid: "pid_t" = Int, lvec: "const struct iovec *" = Ptr, liovcnt: "unsigned long" = UInt, rvec: "const struct iovec *" = Ptr, riovcnt: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        process_vm_writev(pid: "pid_t" = Int, lvec: "const struct iovec *" = Ptr, liovcnt: "unsigned long" = UInt, rvec: "const struct iovec *" = Ptr, riovcnt: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        kcmp(pid1: "pid_t" = Int, pid2: "pid_t" = Int, type: "int" = Int, idx1: "unsigned long" = UInt, idx2: "unsigned long" = UInt) -> Int;
+        finit_module(fd: "int" = Fd, uargs: "const char *" = Str, flags: "int" = Int) -> Int;
+        sched_setattr(pid: "pid_t" = Int, attr: "struct sched_attr *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        sched_getattr(pid: "pid_t" = Int, attr: "struct sched_attr *" = Ptr, size: "unsigned int" = UInt, flags: "unsigned int" = UInt) -> Int;
+        renameat2(olddfd: "int" = Fd, oldname: "const char *" = Str, newdfd: "int" = Fd, newname: "const char *" = Str, flags: "unsigned int" = UInt) -> Int;
+        seccomp(op: "unsigned int" = UInt, flags: "unsigned int" = UInt, uargs: "void *" = Ptr) -> Int;
+        getrandom(buf: "char *" = Ptr, count: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        memfd_create(uname_ptr: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        bpf(cmd: "int" = Int, attr: "union bpf_attr *" = Ptr, size: "unsigned int" = UInt) -> Int;
+        execveat(dfd: "int" = Fd, filename: "const char *" = Str, argv: "const char *const *" = Ptr, envp: "const char *const *" = Ptr, flags: "int" = Int) -> Int;
+        userfaultfd(flags: "int" = Int) -> Fd;
+        membarrier(cmd: "int" = Int, flags: "unsigned int" = UInt, cpu_id: "int" = Int) -> Int;
+        mlock2(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "int" = Int) -> Int;
+        copy_file_range(fd_in: "int" = Fd, off_in: "loff_t *" = Ptr, fd_out: "int" = Fd, off_out: "loff_t *" = Ptr, len: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        preadv2(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt, flags: "rwf_t" = Int) -> Int;
+        pwritev2(fd: "unsigned long" = Fd, vec: "const struct iovec *" = Ptr, vlen: "unsigned long" = UInt, pos_l: "unsigned long" = UInt, pos_h: "unsigned long" = UInt, flags: "rwf_t" = Int) -> Int;
+        pkey_mprotect(start: "unsigned long" = UInt, len: "size_t" = UInt, prot: "unsigned long" = UInt, pkey: "int" = Int) -> Int;
+        pkey_alloc(flags: "unsigned long" = UInt, init_val: "unsigned long" = UInt) -> Int;
+        pkey_free(pkey: "int" = Int) -> Int;
+        statx(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt, mask: "unsigned" = UInt, buffer: "struct statx *" = Ptr) -> Int;
+        rseq(rseq: "struct rseq *" = Ptr, rseq_len: "uint32_t" = UInt, flags: "int" = Int, sig: "uint32_t" = UInt) -> Int;
+        io_pgetevents(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr, sig: "const struct __aio_sigset *" = Ptr) -> Int;
+        migrate_pages(pid: "pid_t" = Int, maxnode: "unsigned long" = UInt, from: "const unsigned long *" = Ptr, to: "const unsigned long *" = Ptr) -> Int;
+        kexec_file_load(kernel_fd: "int" = Fd, initrd_fd: "int" = Fd, cmdline_len: "unsigned long" = UInt, cmdline_ptr: "const char *" = Str, flags: "unsigned long" = UInt) -> Int;
+        clock_gettime64(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_settime64(which_clock: "clockid_t" = Int, tp: "const struct __kernel_timespec *" = Ptr) -> Int;
+        clock_adjtime64(which_clock: "clockid_t" = Int, tx: "struct __kernel_timex *" = Ptr) -> Int;
+        clock_getres_time64(which_clock: "clockid_t" = Int, tp: "struct __kernel_timespec *" = Ptr) -> Int;
+        clock_nanosleep_time64(which_clock: "clockid_t" = Int, flags: "int" = Int, rqtp: "const struct __kernel_timespec *" = Ptr, rmtp: "struct __kernel_timespec *" = Ptr) -> Int;
+        timer_gettime64(timer_id: "timer_t" = Int, setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timer_settime64(timer_id: "timer_t" = Int, flags: "int" = Int, new_setting: "const struct __kernel_itimerspec *" = Ptr, old_setting: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_gettime64(ufd: "int" = Fd, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        timerfd_settime64(ufd: "int" = Fd, flags: "int" = Int, utmr: "const struct __kernel_itimerspec *" = Ptr, otmr: "struct __kernel_itimerspec *" = Ptr) -> Int;
+        utimensat_time64(dfd: "int" = Fd, filename: "const char *" = Str, t: "struct __kernel_timespec *" = Ptr, flags: "int" = Int) -> Int;
+        pselect6_time64(n: "int" = Int, inp: "fd_set *" = Ptr, outp: "fd_set *" = Ptr, exp: "fd_set *" = Ptr, tsp: "struct __kernel_timespec *" = Ptr, sig: "void *" = Ptr) -> Int;
+        ppoll_time64(ufds: "struct pollfd *" = Ptr, nfds: "unsigned int" = UInt, tsp: "struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        io_pgetevents_time64(ctx_id: "aio_context_t" = UInt, min_nr: "long" = Int, nr: "long" = Int, events: "struct io_event *" = Ptr, timeout: "struct __kernel_timespec *" = Ptr, sig: "const struct __aio_sigset *" = Ptr) -> Int;
+        recvmmsg_time64(fd: "int" = Fd, mmsg: "struct mmsghdr *" = Ptr, vlen: "unsigned int" = UInt, flags: "unsigned int" = UInt, timeout: "struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedsend_time64(mqdes: "mqd_t" = Fd, u_msg_ptr: "const char *" = Ptr, msg_len: "unsigned int" = UInt, msg_prio: "unsigned int" = UInt, u_abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        mq_timedreceive_time64(mqdes: "mqd_t" = Fd, u_msg_ptr: "char *" = Ptr, msg_len: "unsigned int" = UInt, u_msg_prio: "unsigned int *" = Ptr, u_abs_timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        semtimedop_time64(semid: "int" = Int, tsops: "struct sembuf *" = Ptr, nsops: "unsigned int" = UInt, timeout: "const struct __kernel_timespec *" = Ptr) -> Int;
+        rt_sigtimedwait_time64(uthese: "compat_sigset_t *" = Ptr, uinfo: "struct compat_siginfo *" = Ptr, uts: "struct __kernel_timespec *" = Ptr, sigsetsize: "compat_size_t" = UInt) -> Int;
+        futex_time64(uaddr: "u32 *" = Ptr, op: "int" = Int, val: "u32" = UInt, utime: "const struct __kernel_timespec *" = Ptr, uaddr2: "u32 *" = Ptr, val3: "u32" = UInt) -> Int;
+        sched_rr_get_interval_time64(pid: "pid_t" = Int, interval: "struct __kernel_timespec *" = Ptr) -> Int;
+        pidfd_send_signal(pidfd: "int" = Fd, sig: "int" = Int, info: "siginfo_t *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        io_uring_setup(entries: "u32" = UInt, p: "struct io_uring_params *" = Ptr) -> Fd;
+        io_uring_enter(fd: "unsigned int" = Fd, to_submit: "u32" = UInt, min_complete: "u32" = UInt, flags: "u32" = UInt, argp: "const void *" = Ptr, argsz: "size_t" = UInt) -> Int;
+        io_uring_register(fd: "unsigned int" = Fd, op: "unsigned int" = UInt, arg: "void *" = Ptr, nr_args: "unsigned int" = UInt) -> Int;
+        open_tree(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt) -> Fd;
+        move_mount(from_dfd: "int" = Fd, from_path: "const char *" = Str, to_dfd: "int" = Fd, to_path: "const char *" = Str, ms_flags: "unsigned int" = UInt) -> Int;
+        fsopen(fs_name: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        fsconfig(fs_fd: "int" = Fd, cmd: "unsigned int" = UInt, key: "const char *" = Str, value: "const void *" = Ptr, aux: "int" = Int) -> Int;
+        fsmount(fs_fd: "int" = Fd, flags: "unsigned int" = UInt, ms_flags: "unsigned int" = UInt) -> Fd;
+        fspick(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned int" = UInt) -> Fd;
+        pidfd_open(pid: "pid_t" = Int, flags: "unsigned int" = UInt) -> Fd;
+        clone3(uargs: "struct clone_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        close_range(fd: "unsigned int" = Fd, max_fd: "unsigned int" = Fd, flags: "unsigned int" = UInt) -> Int;
+        openat2(dfd: "int" = Fd, filename: "const char *" = Str, how: "struct open_how *" = Ptr, size: "size_t" = UInt) -> Fd;
+        pidfd_getfd(pidfd: "int" = Fd, fd: "int" = Fd, flags: "unsigned int" = UInt) -> Fd;
+        faccessat2(dfd: "int" = Fd, filename: "const char *" = Str, mode: "int" = Int, flags: "int" = Int) -> Int;
+        process_madvise(pidfd: "int" = Fd, vec: "const struct iovec *" = Ptr, vlen: "size_t" = UInt, behavior: "int" = Int, flags: "unsigned int" = UInt) -> Int;
+        epoll_pwait2(epfd: "int" = Fd, events: "struct epoll_event *" = Ptr, maxevents: "int" = Int, timeout: "const struct __kernel_timespec *" = Ptr, sigmask: "const sigset_t *" = Ptr, sigsetsize: "size_t" = UInt) -> Int;
+        mount_setattr(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned int" = UInt, uattr: "struct mount_attr *" = Ptr, usize: "size_t" = UInt) -> Int;
+        quotactl_fd(fd: "unsigned int" = Fd, cmd: "unsigned int" = UInt, id: "qid_t" = UInt, addr: "void *" = Ptr) -> Int;
+        landlock_create_ruleset(attr: "const struct landlock_ruleset_attr *" = Ptr, size: "size_t" = UInt, flags: "__u32" = UInt) -> Fd;
+        landlock_add_rule(ruleset_fd: "int" = Fd, rule_type: "enum landlock_rule_type" = Int, rule_attr: "const void *" = Ptr, flags: "__u32" = UInt) -> Int;
+        landlock_restrict_self(ruleset_fd: "int" = Fd, flags: "__u32" = UInt) -> Int;
+        process_mrelease(pidfd: "int" = Fd, flags: "unsigned int" = UInt) -> Int;
+        futex_waitv(waiters: "struct futex_waitv *" = Ptr, nr_futexes: "unsigned int" = UInt, flags: "unsigned int" = UInt, timeout: "struct __kernel_timespec *" = Ptr, clockid: "clockid_t" = Int) -> Int;
+        set_mempolicy_home_node(start: "unsigned long" = UInt, len: "unsigned long" = UInt, home_node: "unsigned long" = UInt, flags: "unsigned long" = UInt) -> Int;
+        cachestat(fd: "unsigned int" = Fd, cstat_range: "struct cachestat_range *" = Ptr, cstat: "struct cachestat *" = Ptr, flags: "unsigned int" = UInt) -> Int;
+        fchmodat2(dfd: "int" = Fd, filename: "const char *" = Str, mode: "umode_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        map_shadow_stack(addr: "unsigned long" = UInt, size: "unsigned long" = UInt, flags: "unsigned int" = UInt) -> Ptr;
+        futex_wake(uaddr: "void *" = Ptr, mask: "unsigned long" = UInt, nr: "int" = Int, flags: "unsigned int" = UInt) -> Int;
+        futex_wait(uaddr: "void *" = Ptr, val: "unsigned long" = UInt, mask: "unsigned long" = UInt, flags: "unsigned int" = UInt, timespec: "struct __kernel_timespec *" = Ptr, clockid: "clockid_t" = Int) -> Int;
+        futex_requeue(waiters: "struct futex_waitv *" = Ptr, flags: "unsigned int" = UInt, nr_wake: "int" = Int, nr_requeue: "int" = Int) -> Int;
+        statmount(req: "const struct mnt_id_req *" = Ptr, buf: "struct statmount *" = Ptr, bufsize: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        listmount(req: "const struct mnt_id_req *" = Ptr, mnt_ids: "u64 *" = Ptr, nr_mnt_ids: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        lsm_get_self_attr(attr: "unsigned int" = UInt, ctx: "struct lsm_ctx *" = Ptr, size: "u32 *" = Ptr, flags: "u32" = UInt) -> Int;
+        lsm_set_self_attr(attr: "unsigned int" = UInt, ctx: "struct lsm_ctx *" = Ptr, size: "u32" = UInt, flags: "u32" = UInt) -> Int;
+        lsm_list_modules(ids: "u64 *" = Ptr, size: "u32 *" = Ptr, flags: "u32" = UInt) -> Int;
+        mseal(start: "unsigned long" = UInt, len: "size_t" = UInt, flags: "unsigned long" = UInt) -> Int;
+        setxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str, args: "const struct xattr_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        getxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str, args: "struct xattr_args *" = Ptr, size: "size_t" = UInt) -> Int;
+        listxattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, list: "char *" = Ptr, size: "size_t" = UInt) -> Int;
+        removexattrat(dfd: "int" = Fd, path: "const char *" = Str, at_flags: "unsigned int" = UInt, name: "const char *" = Str) -> Int;
+        open_tree_attr(dfd: "int" = Fd, path: "const char *" = Str, flags: "unsigned" = UInt, uattr: "struct mount_attr *" = Ptr, usize: "size_t" = UInt) -> Fd;
+        file_getattr(dfd: "int" = Fd, filename: "const char *" = Str, attr: "struct file_attr *" = Ptr, usize: "size_t" = UInt, at_flags: "unsigned int" = UInt) -> Int;
+        file_setattr(dfd: "int" = Fd, filename: "const char *" = Str, attr: "struct file_attr *" = Ptr, usize: "size_t" = UInt, at_flags: "unsigned int" = UInt) -> Int;
+        listns(req: "const struct ns_id_req *" = Ptr, ns_ids: "u64 *" = Ptr, nr_ns_ids: "size_t" = UInt, flags: "unsigned int" = UInt) -> Int;
+        rseq_slice_yield() -> Int;
+        breakpoint() -> Int;
+        cacheflush(start: "unsigned long" = UInt, end: "unsigned long" = UInt, flags: "int" = Int) -> Int;
+        usr26() -> Int;
+        usr32() -> Int;
+        set_tls(val: "unsigned long" = UInt) -> Int;
+        get_tls() -> Ptr;
+    }
+}
+
+syscall_since! {
+    (3, 0) = [
+        restart_syscall, exit, fork, read, write, open, close, creat, link,
+        unlink, execve, chdir, mknod, chmod, lchown, lseek, getpid, mount,
+        setuid, getuid, ptrace, pause, access, nice, sync, kill, rename, mkdir,
+        rmdir, dup, pipe, times, brk, setgid, getgid, geteuid, getegid, acct,
+        umount2, ioctl, fcntl, setpgid, umask, chroot, ustat, dup2, getppid,
+        getpgrp, setsid, sigaction, setreuid, setregid, sigsuspend, sigpending,
+        sethostname, setrlimit, getrusage, gettimeofday, settimeofday,
+        getgroups, setgroups, symlink, readlink, uselib, swapon, reboot, munmap,
+        truncate, ftruncate, fchmod, fchown, getpriority, setpriority, statfs,
+        fstatfs, syslog, setitimer, getitimer, stat, lstat, fstat, vhangup,
+        wait4, swapoff, sysinfo, fsync, sigreturn, clone, setdomainname, uname,
+        adjtimex, mprotect, sigprocmask, init_module, delete_module, quotactl,
+        getpgid, fchdir, bdflush, sysfs, personality, setfsuid, setfsgid,
+        _llseek, getdents, _newselect, flock, msync, readv, writev, getsid,
+        fdatasync, _sysctl, mlock, munlock, mlockall, munlockall,
+        sched_setparam, sched_getparam, sched_setscheduler, sched_getscheduler,
+        sched_yield, sched_get_priority_max, sched_get_priority_min,
+        sched_rr_get_interval, nanosleep, mremap, setresuid, getresuid, poll,
+        nfsservctl, setresgid, getresgid, prctl, rt_sigreturn, rt_sigaction,
+        rt_sigprocmask, rt_sigpending, rt_sigtimedwait, rt_sigqueueinfo,
+        rt_sigsuspend, pread64, pwrite64, chown, getcwd, capget, capset,
+        sigaltstack, sendfile, vfork, ugetrlimit, mmap2, truncate64,
+        ftruncate64, stat64, lstat64, fstat64, lchown32, getuid32, getgid32,
+        geteuid32, getegid32, setreuid32, setregid32, getgroups32, setgroups32,
+        fchown32, setresuid32, getresuid32, setresgid32, getresgid32, chown32,
+        setuid32, setgid32, setfsuid32, setfsgid32, getdents64, pivot_root,
+        mincore, madvise, fcntl64, gettid, readahead, setxattr, lsetxattr,
+        fsetxattr, getxattr, lgetxattr, fgetxattr, listxattr, llistxattr,
+        flistxattr, removexattr, lremovexattr, fremovexattr, tkill, sendfile64,
+        futex, sched_setaffinity, sched_getaffinity, io_setup, io_destroy,
+        io_getevents, io_submit, io_cancel, exit_group, lookup_dcookie,
+        epoll_create, epoll_ctl, epoll_wait, remap_file_pages, set_tid_address,
+        timer_create, timer_settime, timer_gettime, timer_getoverrun,
+        timer_delete, clock_settime, clock_gettime, clock_getres,
+        clock_nanosleep, statfs64, fstatfs64, tgkill, utimes, arm_fadvise64_64,
+        pciconfig_iobase, pciconfig_read, pciconfig_write, mq_open, mq_unlink,
+        mq_timedsend, mq_timedreceive, mq_notify, mq_getsetattr, waitid, socket,
+        bind, connect, listen, accept, getsockname, getpeername, socketpair,
+        send, sendto, recv, recvfrom, shutdown, setsockopt, getsockopt, sendmsg,
+        recvmsg, semop, semget, semctl, msgsnd, msgrcv, msgget, msgctl, shmat,
+        shmdt, shmget, shmctl, add_key, request_key, keyctl, semtimedop,
+        vserver, ioprio_set, ioprio_get, inotify_init, inotify_add_watch,
+        inotify_rm_watch, mbind, get_mempolicy, set_mempolicy, openat, mkdirat,
+        mknodat, fchownat, futimesat, fstatat64, unlinkat, renameat, linkat,
+        symlinkat, readlinkat, fchmodat, faccessat, pselect6, ppoll, unshare,
+        set_robust_list, get_robust_list, splice, arm_sync_file_range, tee,
+        vmsplice, move_pages, getcpu, epoll_pwait, kexec_load, utimensat,
+        signalfd, timerfd_create, eventfd, fallocate, timerfd_settime,
+        timerfd_gettime, signalfd4, eventfd2, epoll_create1, dup3, pipe2,
+        inotify_init1, preadv, pwritev, rt_tgsigqueueinfo, perf_event_open,
+        recvmmsg, accept4, fanotify_init, fanotify_mark, prlimit64,
+        name_to_handle_at, open_by_handle_at, clock_adjtime, syncfs, sendmmsg,
+        setns, breakpoint, cacheflush, usr26, usr32, set_tls,
+    ];
+
+    (3, 2) = [
+        process_vm_readv, process_vm_writev,
+    ];
+
+    (3, 5) = [
+        kcmp,
+    ];
+
+    (3, 8) = [
+        finit_module,
+    ];
+
+    (3, 14) = [
+        sched_setattr, sched_getattr,
+    ];
+
+    (3, 15) = [
+        renameat2,
+    ];
+
+    (3, 17) = [
+        seccomp, getrandom, memfd_create,
+    ];
+
+    (3, 18) = [
+        bpf,
+    ];
+
+    (3, 19) = [
+        execveat,
+    ];
+
+    (4, 3) = [
+        userfaultfd, membarrier,
+    ];
+
+    (4, 4) = [
+        mlock2,
+    ];
+
+    (4, 5) = [
+        copy_file_range,
+    ];
+
+    (4, 6) = [
+        preadv2, pwritev2,
+    ];
+
+    (4, 9) = [
+        pkey_mprotect, pkey_alloc, pkey_free,
+    ];
+
+    (4, 11) = [
+        statx,
+    ];
+
+    (4, 15) = [
+        get_tls,
+    ];
+
+    (4, 18) = [
+        rseq, io_pgetevents,
+    ];
+
+    (5, 1) = [
+        migrate_pages, kexec_file_load, clock_gettime64, clock_settime64,
+        clock_adjtime64, clock_getres_time64, clock_nanosleep_time64,
+        timer_gettime64, timer_settime64, timerfd_gettime64, timerfd_settime64,
+        utimensat_time64, pselect6_time64, ppoll_time64, io_pgetevents_time64,
+        recvmmsg_time64, mq_timedsend_time64, mq_timedreceive_time64,
+        semtimedop_time64, rt_sigtimedwait_time64, futex_time64,
+        sched_rr_get_interval_time64, pidfd_send_signal, io_uring_setup,
+        io_uring_enter, io_uring_register,
+    ];
+
+    (5, 2) = [
+        open_tree, move_mount, fsopen, fsconfig, fsmount, fspick,
+    ];
+
+    (5, 3) = [
+        pidfd_open, clone3,
+    ];
+
+    (5, 6) = [
+        openat2, pidfd_getfd,
+    ];
+
+    (5, 8) = [
+        faccessat2,
+    ];
+
+    (5, 9) = [
+        close_range,
+    ];
+
+    (5, 10) = [
+        process_madvise,
+    ];
+
+    (5, 11) = [
+        epoll_pwait2,
+    ];
+
+    (5, 12) = [
+        mount_setattr,
+    ];
+
+    (5, 13) = [
+        landlock_create_ruleset, landlock_add_rule, landlock_restrict_self,
+    ];
+
+    (5, 14) = [
+        quotactl_fd,
+    ];
+
+    (5, 15) = [
+        process_mrelease,
+    ];
+
+    (5, 16) = [
+        futex_waitv,
+    ];
+
+    (5, 17) = [
+        set_mempolicy_home_node,
+    ];
+
+    (6, 5) = [
+        cachestat,
+    ];
+
+    (6, 6) = [
+        fchmodat2, map_shadow_stack,
+    ];
+
+    (6, 7) = [
+        futex_wake, futex_wait, futex_requeue,
+    ];
+
+    (6, 8) = [
+        statmount, listmount, lsm_get_self_attr, lsm_set_self_attr,
+        lsm_list_modules,
+    ];
+
+    (6, 10) = [
+        mseal,
+    ];
+
+    (6, 13) = [
+        setxattrat, getxattrat, listxattrat, removexattrat,
+    ];
+
+    (6, 15) = [
+        open_tree_attr,
+    ];
+
+    (6, 17) = [
+        file_getattr, file_setattr,
+    ];
+
+    (6, 18) = [
+        listns,
+    ];
+
+    (7, 0) = [
+        rseq_slice_yield,
+    ];
+}
+
+syscall_classes! {
+    /// Syscalls that take a file name as an argument (`%file`).
+    FILE = [
+        open, creat, link, unlink, execve, chdir, mknod, chmod, lchown, mount,
+        access, rename, mkdir, rmdir, acct, umount2, chroot, symlink, readlink,
+        uselib, swapon, truncate, statfs, stat, lstat, swapoff, quotactl, chown,
+        truncate64, stat64, lstat64, lchown32, chown32, pivot_root, setxattr,
+        lsetxattr, getxattr, lgetxattr, listxattr, llistxattr, removexattr,
+        lremovexattr, statfs64, utimes, inotify_add_watch, openat, mkdirat,
+        mknodat, fchownat, futimesat, fstatat64, unlinkat, renameat, linkat,
+        symlinkat, readlinkat, fchmodat, faccessat, utimensat, fanotify_mark,
+        name_to_handle_at, renameat2, execveat, statx, utimensat_time64,
+        open_tree, move_mount, fspick, openat2, faccessat2, mount_setattr,
+        fchmodat2, setxattrat, getxattrat, listxattrat, removexattrat,
+        open_tree_attr, file_getattr, file_setattr,
+    ];
+
+    /// Syscalls that take or return a file descriptor (`%desc`).
+    DESC = [
+        read, write, open, close, creat, lseek, dup, pipe, ioctl, fcntl, dup2,
+        ftruncate, fchmod, fchown, fstatfs, fstat, fsync, fchdir, _llseek,
+        getdents, _newselect, flock, readv, writev, fdatasync, poll, pread64,
+        pwrite64, sendfile, mmap2, ftruncate64, fstat64, fchown32, getdents64,
+        fcntl64, readahead, fsetxattr, fgetxattr, flistxattr, fremovexattr,
+        sendfile64, epoll_create, epoll_ctl, epoll_wait, fstatfs64,
+        arm_fadvise64_64, mq_open, mq_timedsend, mq_timedreceive, mq_notify,
+        mq_getsetattr, inotify_init, inotify_add_watch, inotify_rm_watch,
+        openat, mkdirat, mknodat, fchownat, futimesat, fstatat64, unlinkat,
+        renameat, linkat, symlinkat, readlinkat, fchmodat, faccessat, pselect6,
+        ppoll, splice, arm_sync_file_range, tee, vmsplice, epoll_pwait,
+        utimensat, signalfd, timerfd_create, eventfd, fallocate,
+        timerfd_settime, timerfd_gettime, signalfd4, eventfd2, epoll_create1,
+        dup3, pipe2, inotify_init1, preadv, pwritev, perf_event_open,
+        fanotify_init, fanotify_mark, name_to_handle_at, open_by_handle_at,
+        syncfs, setns, finit_module, renameat2, memfd_create, bpf, execveat,
+        userfaultfd, copy_file_range, preadv2, pwritev2, statx, kexec_file_load,
+        timerfd_gettime64, timerfd_settime64, utimensat_time64, pselect6_time64,
+        ppoll_time64, mq_timedsend_time64, mq_timedreceive_time64,
+        pidfd_send_signal, io_uring_setup, io_uring_enter, io_uring_register,
+        open_tree, move_mount, fsopen, fsconfig, fsmount, fspick, pidfd_open,
+        close_range, openat2, pidfd_getfd, faccessat2, process_madvise,
+        epoll_pwait2, mount_setattr, quotactl_fd, landlock_create_ruleset,
+        landlock_add_rule, landlock_restrict_self, process_mrelease, cachestat,
+        fchmodat2, setxattrat, getxattrat, listxattrat, removexattrat,
+        open_tree_attr, file_getattr, file_setattr,
+    ];
+
+    /// Network-related syscalls (`%network`).
+    NETWORK = [
+        socket, bind, connect, listen, accept, getsockname, getpeername,
+        socketpair, send, sendto, recv, recvfrom, shutdown, setsockopt,
+        getsockopt, sendmsg, recvmsg, recvmmsg, accept4, sendmmsg,
+        recvmmsg_time64,
+    ];
+
+    /// Syscalls related to the process lifecycle (`%process`).
+    PROCESS = [
+        exit, fork, execve, kill, wait4, clone, rt_sigqueueinfo, vfork, tkill,
+        exit_group, tgkill, waitid, unshare, rt_tgsigqueueinfo, execveat,
+        pidfd_send_signal, pidfd_open, clone3,
+    ];
+
+    /// Signal-related syscalls (`%signal`).
+    SIGNAL = [
+        pause, kill, sigaction, sigsuspend, sigpending, sigreturn, sigprocmask,
+        rt_sigreturn, rt_sigaction, rt_sigprocmask, rt_sigpending,
+        rt_sigtimedwait, rt_sigqueueinfo, rt_sigsuspend, sigaltstack, tkill,
+        tgkill, signalfd, signalfd4, rt_tgsigqueueinfo, rt_sigtimedwait_time64,
+        pidfd_send_signal,
+    ];
+
+    /// System V IPC-related syscalls (`%ipc`).
+    IPC = [
+        semop, semget, semctl, msgsnd, msgrcv, msgget, msgctl, shmat, shmdt,
+        shmget, shmctl, semtimedop, semtimedop_time64,
+    ];
+
+    /// Memory mapping-related syscalls (`%memory`).
+    MEMORY = [
+        brk, munmap, mprotect, msync, mlock, munlock, mlockall, munlockall,
+        mremap, mmap2, mincore, madvise, remap_file_pages, shmat, shmdt, mbind,
+        get_mempolicy, set_mempolicy, move_pages, mlock2, pkey_mprotect,
+        migrate_pages, set_mempolicy_home_node, map_shadow_stack, mseal,
+    ];
+
+    /// Syscalls that read or modify credentials (`%creds`).
+    CREDS = [
+        setuid, getuid, setgid, getgid, geteuid, getegid, setreuid, setregid,
+        getgroups, setgroups, setfsuid, setfsgid, setresuid, getresuid,
+        setresgid, getresgid, prctl, capget, capset, getuid32, getgid32,
+        geteuid32, getegid32, setreuid32, setregid32, getgroups32, setgroups32,
+        setresuid32, getresuid32, setresgid32, getresgid32, setuid32, setgid32,
+        setfsuid32, setfsgid32,
+    ];
+
+    /// Syscalls that read or modify system clocks (`%clock`).
+    CLOCK = [
+        gettimeofday, settimeofday, adjtimex, clock_settime, clock_gettime,
+        clock_getres, clock_adjtime, clock_gettime64, clock_settime64,
+        clock_adjtime64, clock_getres_time64,
+    ];
+
+    /// Variants of the `stat` syscall (`%stat`).
+    STAT = [
+        stat, stat64,
+    ];
+
+    /// Variants of the `statfs` syscall (`%statfs`).
+    STATFS = [
+        statfs, statfs64,
+    ];
+
+    /// Syscalls that always succeed and have no arguments (`%pure`).
+    PURE = [
+        getpid, getuid, getgid, geteuid, getegid, getppid, getpgrp, getuid32,
+        getgid32, geteuid32, getegid32, gettid,
+    ];
+}
+
+pub use crate::arch::markers::arm::*;
+pub use crate::regs::arm::Regs;
@@ -0,0 +1,89 @@
+//! `AUDIT_ARCH_*` values, which identify the architecture and ABI of a
+//! syscall in seccomp filters, audit logs, and `PTRACE_GET_SYSCALL_INFO`.
+
+// `AUDIT_ARCH` is only used by the modules that require the `std` feature.
+#![cfg_attr(not(feature = "std"), allow(dead_code))]
+
+pub const AUDIT_ARCH_AARCH64: u32 = 0xc000_00b7;
+pub const AUDIT_ARCH_ARM: u32 = 0x4000_0028;
+pub const AUDIT_ARCH_ARMEB: u32 = 0x0000_0028;
+pub const AUDIT_ARCH_I386: u32 = 0x4000_0003;
+pub const AUDIT_ARCH_LOONGARCH64: u32 = 0xc000_0102;
+pub const AUDIT_ARCH_MIPS: u32 = 0x0000_0008;
+pub const AUDIT_ARCH_MIPSEL: u32 = 0x4000_0008;
+pub const AUDIT_ARCH_MIPS64: u32 = 0x8000_0008;
+pub const AUDIT_ARCH_MIPSEL64: u32 = 0xc000_0008;
+pub const AUDIT_ARCH_MIPS64N32: u32 = 0xa000_0008;
+pub const AUDIT_ARCH_MIPSEL64N32: u32 = 0xe000_0008;
+pub const AUDIT_ARCH_PPC: u32 = 0x0000_0014;
+pub const AUDIT_ARCH_PPC64: u32 = 0x8000_0015;
+pub const AUDIT_ARCH_PPC64LE: u32 = 0xc000_0015;
+pub const AUDIT_ARCH_RISCV32: u32 = 0x4000_00f3;
+pub const AUDIT_ARCH_RISCV64: u32 = 0xc000_00f3;
+pub const AUDIT_ARCH_S390X: u32 = 0x8000_0016;
+pub const AUDIT_ARCH_SPARC: u32 = 0x0000_0002;
+pub const AUDIT_ARCH_SPARC64: u32 = 0x8000_002b;
+pub const AUDIT_ARCH_X86_64: u32 = 0xc000_003e;
+
+/// Syscalls made with the x32 ABI have this bit set in their number, but are
+/// otherwise reported with [`AUDIT_ARCH_X86_64`].
+pub const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+/// The `AUDIT_ARCH_*` value of the native [`Sysno`](crate::Sysno) table.
+/// Compiled seccomp filters only match syscalls made with this architecture.
+#[cfg(target_arch = "aarch64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_AARCH64;
+#[cfg(all(target_arch = "arm", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARM;
+#[cfg(all(target_arch = "arm", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARMEB;
+#[cfg(target_arch = "loongarch64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_LOONGARCH64;
+#[cfg(all(target_arch = "mips", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS;
+#[cfg(all(target_arch = "mips", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL;
+#[cfg(all(
+    target_arch = "mips64",
+    not(target_abi = "abin32"),
+    target_endian = "big"
+))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS64;
+#[cfg(all(
+    target_arch = "mips64",
+    not(target_abi = "abin32"),
+    target_endian = "little"
+))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL64;
+#[cfg(all(
+    target_arch = "mips64",
+    target_abi = "abin32",
+    target_endian = "big"
+))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS64N32;
+#[cfg(all(
+    target_arch = "mips64",
+    target_abi = "abin32",
+    target_endian = "little"
+))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL64N32;
+#[cfg(target_arch = "powerpc")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC;
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64;
+#[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64LE;
+#[cfg(target_arch = "riscv32")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_RISCV32;
+#[cfg(target_arch = "riscv64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_RISCV64;
+#[cfg(target_arch = "s390x")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_S390X;
+#[cfg(target_arch = "sparc")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_SPARC;
+#[cfg(target_arch = "sparc64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_SPARC64;
+#[cfg(target_arch = "x86")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_I386;
+#[cfg(target_arch = "x86_64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_X86_64;
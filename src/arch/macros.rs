@@ -1,5 +1,18 @@
 /// Helper for generating support code for a list of syscalls.
 macro_rules! syscall_enum {
+    (@gap) => {
+        (i32::MAX, 0)
+    };
+    (@gap $private:ident) => {{
+        // The gap starts right after the syscall preceding the first
+        // architecture-private one.
+        let mut i = 0;
+        while Self::ALL[i] as i32 != Self::$private as i32 {
+            i += 1;
+        }
+        let start = Self::ALL[i - 1] as i32 + 1;
+        (start, Self::$private as i32 - start)
+    }};
     (
         $(#[$outer:meta])*
         $vis:vis enum $Name:ident {
@@ -12,6 +25,7 @@ macro_rules! syscall_enum {
         }
 
         LAST: $last_syscall:ident;
+        $(PRIVATE: $private_syscall:ident;)?
     ) => {
         /// Complete list of Linux syscalls.
         $(#[$outer])*
@@ -38,6 +52,12 @@ macro_rules! syscall_enum {
                 )*
             ];
 
+            /// The unused numbers between the syscall table and the
+            /// architecture-private syscalls (such as `__ARM_NR_cacheflush`),
+            /// as `(start, len)`. This is empty if there are none.
+            pub(crate) const GAP: (i32, i32) =
+                syscall_enum!(@gap $($private_syscall)?);
+
             /// Constructs a new syscall from the given ID. If the ID does not
             /// represent a valid syscall, returns `None`.
             pub const fn new(id: usize) -> Option<Self> {
@@ -62,6 +82,25 @@ macro_rules! syscall_enum {
                 }
             }
 
+            /// Returns the errors that the man page of the syscall documents,
+            /// as error codes of the architecture of this table. This is
+            /// empty if the syscall has no man page.
+            pub fn documented_errors(&self) -> crate::ErrnoSet {
+                use crate::{Arch, ArchSysno, SyscallArch};
+
+                let errors = crate::errno::documented_errors(self.name());
+                let arch = <<Self as ArchSysno>::Arch as SyscallArch>::ARCH;
+
+                if arch == Arch::NATIVE {
+                    return errors;
+                }
+
+                errors
+                    .iter()
+                    .filter_map(|errno| errno.translate(Arch::NATIVE, arch))
+                    .collect()
+            }
+
             /// Returns the next syscall in the table. Returns `None` if this is
             /// the last syscall.
             pub const fn next(&self) -> Option<Self> {
@@ -71,6 +110,10 @@ macro_rules! syscall_enum {
 
                 let mut next_id = self.id() + 1;
 
+                if next_id == Self::GAP.0 {
+                    next_id += Self::GAP.1;
+                }
+
                 while next_id < Self::last().id() {
                     if let Some(next) = Self::new(next_id as usize) {
                         return Some(next);
@@ -110,8 +153,12 @@ macro_rules! syscall_enum {
 
             /// Returns the length of the syscall table, including any gaps.
             /// This is not the same thing as the total number of syscalls.
+            ///
+            /// The range of unused numbers before architecture-private
+            /// syscalls isn't counted.
             pub const fn table_size() -> usize {
-                (Self::last().id() - Self::first().id()) as usize + 1
+                (Self::last().id() - Self::first().id() - Self::GAP.1) as usize
+                    + 1
             }
 
             /// Returns an iterator that iterates over all possible syscalls.
@@ -161,3 +208,128 @@ macro_rules! syscall_enum {
         }
     }
 }
+
+/// Helper for generating signature metadata for a list of syscalls. Syscalls
+/// that aren't listed have no known signature.
+macro_rules! syscall_signatures {
+    (
+        impl $Name:ident {
+            $(
+                $syscall:ident(
+                    $($arg:ident: $ty:literal = $kind:ident),*
+                ) -> $ret:ident;
+            )*
+        }
+    ) => {
+        impl $Name {
+            /// Returns the arguments of the syscall as declared by the kernel,
+            /// or `None` if the signature isn't known (e.g., the syscall isn't
+            /// implemented on this architecture).
+            ///
+            /// Note that this follows the C prototype of the syscall. On 32-bit
+            /// architectures, a 64-bit argument (such as `loff_t`) may occupy
+            /// two registers.
+            pub const fn args(&self) -> Option<&'static [$crate::SyscallArg]> {
+                match self {
+                    $(
+                        Self::$syscall => Some(&[
+                            $(
+                                $crate::SyscallArg {
+                                    name: core::stringify!($arg),
+                                    ty: $ty,
+                                    kind: $crate::ArgKind::$kind,
+                                },
+                            )*
+                        ]),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// Returns the number of arguments the syscall takes, or `None` if
+            /// the signature isn't known. See [`Self::args`].
+            pub const fn arg_count(&self) -> Option<usize> {
+                match self.args() {
+                    Some(args) => Some(args.len()),
+                    None => None,
+                }
+            }
+
+            /// Returns the kind of value the syscall returns on success. This
+            /// is [`ArgKind::Int`][crate::ArgKind::Int] if the signature isn't
+            /// known.
+            pub const fn return_kind(&self) -> $crate::ArgKind {
+                match self {
+                    $(
+                        Self::$syscall => $crate::ArgKind::$ret,
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => $crate::ArgKind::Int,
+                }
+            }
+        }
+    }
+}
+
+/// Helper for generating the Linux versions that added each syscall. Every
+/// syscall must be listed exactly once.
+macro_rules! syscall_since {
+    (
+        $(
+            ($major:literal, $minor:literal) = [$($syscall:ident),* $(,)?];
+        )*
+    ) => {
+        impl Sysno {
+            /// Returns the Linux version that added this syscall to this
+            /// architecture. Syscalls that are older than
+            /// [`KernelVersion::OLDEST`][crate::KernelVersion::OLDEST] return
+            /// that version.
+            ///
+            /// Note that a syscall may still fail with `ENOSYS` on a newer
+            /// kernel if it was disabled in the kernel config.
+            pub const fn since(&self) -> $crate::KernelVersion {
+                match self {
+                    $(
+                        $(Self::$syscall)|* => $crate::KernelVersion($major, $minor),
+                    )*
+                }
+            }
+
+            /// The versions of [`Self::ALL`], in the same order.
+            pub(crate) const SINCE: &'static [$crate::KernelVersion] = &{
+                let mut since = [$crate::KernelVersion::OLDEST; Sysno::ALL.len()];
+
+                let mut i = 0;
+                while i < since.len() {
+                    since[i] = Sysno::ALL[i].since();
+                    i += 1;
+                }
+
+                since
+            };
+        }
+    }
+}
+
+/// Helper for generating lists of syscalls grouped into classes.
+macro_rules! syscall_classes {
+    (
+        $(
+            $(#[$attr:meta])*
+            $class:ident = [$($syscall:ident),* $(,)?];
+        )*
+    ) => {
+        /// Syscalls grouped into the same classes that `strace` uses for
+        /// `-e trace=%class`. Syscalls that aren't implemented on this
+        /// architecture are left out.
+        pub mod class {
+            use super::Sysno;
+
+            $(
+                $(#[$attr])*
+                pub const $class: &[Sysno] = &[$(Sysno::$syscall),*];
+            )*
+        }
+    }
+}
@@ -1,6 +1,14 @@
 #[macro_use]
 mod macros;
 
+mod any;
+pub(crate) mod audit;
+mod raw_sysno;
+
+pub(crate) use any::{index_to_id, markers, sysno_index, MAX_TABLE_SIZE};
+pub use any::{AnySysno, Arch, ArchSysno, Native, SyscallArch};
+pub use raw_sysno::RawSysno;
+
 #[cfg(any(target_arch = "aarch64", feature = "aarch64"))]
 pub mod aarch64;
 #[cfg(any(target_arch = "arm", feature = "arm"))]
@@ -11,6 +19,11 @@ pub mod loongarch64;
 pub mod mips;
 #[cfg(any(target_arch = "mips64", feature = "mips64"))]
 pub mod mips64;
+#[cfg(any(
+    all(target_arch = "mips64", target_abi = "abin32"),
+    feature = "mipsn32"
+))]
+pub mod mipsn32;
 #[cfg(any(target_arch = "powerpc", feature = "powerpc"))]
 pub mod powerpc;
 #[cfg(any(target_arch = "powerpc64", feature = "powerpc64"))]
@@ -25,6 +38,8 @@ pub mod s390x;
 pub mod sparc;
 #[cfg(any(target_arch = "sparc64", feature = "sparc64"))]
 pub mod sparc64;
+#[cfg(any(all(target_arch = "x86_64", target_abi = "x32"), feature = "x32"))]
+pub mod x32;
 #[cfg(any(target_arch = "x86", feature = "x86"))]
 pub mod x86;
 #[cfg(any(target_arch = "x86_64", feature = "x86_64"))]
@@ -42,9 +57,12 @@ pub use loongarch64::*;
 #[cfg(target_arch = "mips")]
 pub use mips::*;
 
-#[cfg(target_arch = "mips64")]
+#[cfg(all(target_arch = "mips64", not(target_abi = "abin32")))]
 pub use mips64::*;
 
+#[cfg(all(target_arch = "mips64", target_abi = "abin32"))]
+pub use mipsn32::*;
+
 #[cfg(target_arch = "powerpc")]
 pub use powerpc::*;
 
@@ -69,5 +87,8 @@ pub use sparc64::*;
 #[cfg(target_arch = "x86")]
 pub use x86::*;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(target_abi = "x32")))]
 pub use x86_64::*;
+
+#[cfg(all(target_arch = "x86_64", target_abi = "x32"))]
+pub use x32::*;
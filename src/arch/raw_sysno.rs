@@ -0,0 +1,184 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Sysno;
+
+const PREFIX: &str = "syscall_0x";
+
+/// A syscall number of the native architecture that may not be in the
+/// syscall table.
+///
+/// Unlike [`Sysno`], this can hold any number, such as a syscall added by a
+/// newer kernel, a number with the x32 bit set, or an architecture-private
+/// syscall. Known syscalls are displayed by name and unknown ones by number,
+/// like `syscall_0x1c8`. Either form can be parsed back.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::{RawSysno, Sysno};
+///
+/// let known = RawSysno::from(Sysno::getpid);
+/// assert_eq!(known.sysno(), Some(Sysno::getpid));
+/// assert_eq!(known.to_string(), "getpid");
+///
+/// let unknown = RawSysno::new(0xdead);
+/// assert_eq!(unknown.sysno(), None);
+/// assert_eq!(unknown.to_string(), "syscall_0xdead");
+/// assert_eq!("syscall_0xdead".parse(), Ok(unknown));
+///
+/// // The raw syscall functions take any number.
+/// let pid = unsafe { syscalls::raw::syscall0(known.id()) };
+/// assert_eq!(pid, std::process::id() as usize);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct RawSysno(usize);
+
+impl RawSysno {
+    /// Creates a syscall number.
+    pub const fn new(id: usize) -> Self {
+        Self(id)
+    }
+
+    /// Returns the syscall number.
+    pub const fn id(&self) -> usize {
+        self.0
+    }
+
+    /// Returns the syscall if it is in the table.
+    pub const fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.0)
+    }
+
+    /// Returns the name of the syscall if it is in the table.
+    pub const fn name(&self) -> Option<&'static str> {
+        match self.sysno() {
+            Some(sysno) => Some(sysno.name()),
+            None => None,
+        }
+    }
+}
+
+impl From<Sysno> for RawSysno {
+    fn from(sysno: Sysno) -> Self {
+        Self(sysno.id() as usize)
+    }
+}
+
+impl From<usize> for RawSysno {
+    fn from(id: usize) -> Self {
+        Self(id)
+    }
+}
+
+impl From<RawSysno> for usize {
+    fn from(sysno: RawSysno) -> Self {
+        sysno.0
+    }
+}
+
+impl TryFrom<RawSysno> for Sysno {
+    type Error = RawSysno;
+
+    /// Returns the syscall number back if it isn't in the table.
+    fn try_from(sysno: RawSysno) -> Result<Self, Self::Error> {
+        sysno.sysno().ok_or(sysno)
+    }
+}
+
+impl fmt::Display for RawSysno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}{:x}", PREFIX, self.0),
+        }
+    }
+}
+
+impl fmt::Debug for RawSysno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for RawSysno {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix(PREFIX) {
+            Some(hex) if !hex.starts_with('+') => {
+                usize::from_str_radix(hex, 16).map(Self).map_err(|_| ())
+            }
+            Some(_) => Err(()),
+            None => s.parse::<Sysno>().map(Self::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known() {
+        for sysno in Sysno::iter() {
+            let raw = RawSysno::from(sysno);
+            assert_eq!(raw.sysno(), Some(sysno));
+            assert_eq!(raw.name(), Some(sysno.name()));
+            assert_eq!(sysno.name().parse(), Ok(raw));
+            assert_eq!(Sysno::try_from(raw), Ok(sysno));
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        for id in [
+            usize::MAX,
+            0x4000_0000 | 39,
+            Sysno::last().id() as usize + 1,
+        ] {
+            let raw = RawSysno::new(id);
+            assert_eq!(raw.id(), id);
+            assert_eq!(raw.sysno(), None);
+            assert_eq!(raw.name(), None);
+            assert_eq!(Sysno::try_from(raw), Err(raw));
+            assert_eq!(usize::from(raw), id);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("syscall_0x1c8".parse(), Ok(RawSysno::new(0x1c8)));
+        assert_eq!("syscall_0x".parse::<RawSysno>(), Err(()));
+        assert_eq!("syscall_0x+1".parse::<RawSysno>(), Err(()));
+        assert_eq!("syscall_0xg".parse::<RawSysno>(), Err(()));
+        assert_eq!("bogus".parse::<RawSysno>(), Err(()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display() {
+        assert_eq!(RawSysno::new(0xdead).to_string(), "syscall_0xdead");
+        assert_eq!(format!("{:?}", RawSysno::new(0xdead)), "syscall_0xdead");
+        assert_eq!(RawSysno::from(Sysno::read).to_string(), "read");
+
+        let raw = RawSysno::new(usize::MAX);
+        assert_eq!(raw.to_string().parse(), Ok(raw));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        for raw in [RawSysno::from(Sysno::read), RawSysno::new(usize::MAX)] {
+            let s = serde_json::to_string(&raw).unwrap();
+            assert_eq!(s, raw.id().to_string());
+            assert_eq!(serde_json::from_str::<RawSysno>(&s).unwrap(), raw);
+        }
+    }
+}
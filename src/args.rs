@@ -3,6 +3,8 @@
 //!
 //! `io:Error` is not implemented for better `no_std` support.
 
+use crate::SyscallRegs;
+
 /// The 6 arguments of a syscall, raw untyped version.
 #[derive(PartialEq, Debug, Eq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -33,6 +35,30 @@ impl SyscallArgs {
             arg5: a5,
         }
     }
+
+    /// Reads the arguments from a register set that is stopped at syscall
+    /// entry. Arguments that aren't passed in registers are zero.
+    pub fn from_regs<R: SyscallRegs>(regs: &R) -> Self {
+        SyscallArgs {
+            arg0: regs.arg(0),
+            arg1: regs.arg(1),
+            arg2: regs.arg(2),
+            arg3: regs.arg(3),
+            arg4: regs.arg(4),
+            arg5: regs.arg(5),
+        }
+    }
+
+    /// Writes the arguments to a register set that is stopped at syscall
+    /// entry. Arguments that aren't passed in registers are ignored.
+    pub fn write_to_regs<R: SyscallRegs>(&self, regs: &mut R) {
+        regs.set_arg(0, self.arg0);
+        regs.set_arg(1, self.arg1);
+        regs.set_arg(2, self.arg2);
+        regs.set_arg(3, self.arg3);
+        regs.set_arg(4, self.arg4);
+        regs.set_arg(5, self.arg5);
+    }
 }
 
 impl From<&[usize; 6]> for SyscallArgs {
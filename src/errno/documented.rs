@@ -0,0 +1,1739 @@
+// This file is automatically generated. Do not edit!
+
+documented_errors! {
+    _llseek = [
+        EBADF, EFAULT, EINVAL,
+    ];
+
+    _newselect = [
+        EBADF, EINTR, EINVAL, ENOMEM,
+    ];
+
+    _sysctl = [
+        EACCES, EPERM, EFAULT, ENOTDIR,
+    ];
+
+    accept = [
+        EAGAIN, EWOULDBLOCK, EBADF, ECONNABORTED, EFAULT, EINTR, EINVAL, EMFILE,
+        ENFILE, ENOBUFS, ENOMEM, ENOTSOCK, EOPNOTSUPP, EPERM, EPROTO,
+    ];
+
+    accept4 = [
+        EAGAIN, EWOULDBLOCK, EBADF, ECONNABORTED, EFAULT, EINTR, EINVAL, EMFILE,
+        ENFILE, ENOBUFS, ENOMEM, ENOTSOCK, EOPNOTSUPP, EPERM, EPROTO,
+    ];
+
+    access = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS, ETXTBSY,
+    ];
+
+    acct = [
+        EACCES, EFAULT, EIO, EISDIR, ELOOP, ENAMETOOLONG, ENFILE, ENOENT,
+        ENOMEM, ENOSYS, ENOTDIR, EPERM, EROFS, EUSERS,
+    ];
+
+    add_key = [
+        EACCES, EDQUOT, EFAULT, EINVAL, EKEYEXPIRED, EKEYREVOKED, ENOKEY,
+        ENOMEM, EPERM,
+    ];
+
+    adjtimex = [
+        EFAULT, EINVAL, ENODEV, EOPNOTSUPP, EPERM,
+    ];
+
+    arch_prctl = [
+        EFAULT, EINVAL, ENODEV, EPERM,
+    ];
+
+    arm_fadvise64_64 = [
+        EBADF, EINVAL, ESPIPE,
+    ];
+
+    arm_sync_file_range = [
+        EBADF, EINVAL, EIO, ENOMEM, ENOSPC, ESPIPE,
+    ];
+
+    bdflush = [
+        EBUSY, EFAULT, EINVAL, EPERM,
+    ];
+
+    bind = [
+        EACCES, EADDRINUSE, EBADF, EINVAL, ENOTSOCK, EADDRNOTAVAIL, EFAULT,
+        ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR, EROFS,
+    ];
+
+    bpf = [
+        E2BIG, EACCES, EBADF, EFAULT, EINVAL, ENOENT, ENOMEM, EPERM,
+    ];
+
+    cacheflush = [
+        EFAULT, EINVAL,
+    ];
+
+    capget = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    capset = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    chdir = [
+        EACCES, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR,
+        EBADF,
+    ];
+
+    chmod = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, ENOTSUP, EPERM, EROFS,
+    ];
+
+    chown = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    chown32 = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    chroot = [
+        EACCES, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR,
+        EPERM,
+    ];
+
+    clock_adjtime = [
+        EFAULT, EINVAL, ENODEV, EOPNOTSUPP, EPERM,
+    ];
+
+    clock_adjtime64 = [
+        EFAULT, EINVAL, ENODEV, EOPNOTSUPP, EPERM,
+    ];
+
+    clock_getres = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clock_getres_time64 = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clock_gettime = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clock_gettime64 = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clock_nanosleep = [
+        EFAULT, EINTR, EINVAL, ENOTSUP,
+    ];
+
+    clock_nanosleep_time64 = [
+        EFAULT, EINTR, EINVAL, ENOTSUP,
+    ];
+
+    clock_settime = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clock_settime64 = [
+        EACCES, EFAULT, EINVAL, ENODEV, ENOTSUP, EPERM,
+    ];
+
+    clone = [
+        EACCES, EAGAIN, EBUSY, EEXIST, EINVAL, ENOMEM, ENOSPC, EUSERS,
+        EOPNOTSUPP, EPERM, ERESTARTNOINTR,
+    ];
+
+    clone3 = [
+        EACCES, EAGAIN, EBUSY, EEXIST, EINVAL, ENOMEM, ENOSPC, EUSERS,
+        EOPNOTSUPP, EPERM, ERESTARTNOINTR,
+    ];
+
+    close = [
+        EBADF, EINTR, EIO, ENOSPC, EDQUOT,
+    ];
+
+    close_range = [
+        EINVAL, EMFILE, ENOMEM,
+    ];
+
+    connect = [
+        EACCES, EPERM, EADDRINUSE, EADDRNOTAVAIL, EAFNOSUPPORT, EAGAIN,
+        EALREADY, EBADF, ECONNREFUSED, EFAULT, EINPROGRESS, EINTR, EISCONN,
+        ENETUNREACH, ENOTSOCK, EPROTOTYPE, ETIMEDOUT,
+    ];
+
+    copy_file_range = [
+        EBADF, EFBIG, EINVAL, EIO, EISDIR, ENOMEM, ENOSPC, EOPNOTSUPP,
+        EOVERFLOW, EPERM, ETXTBSY, EXDEV,
+    ];
+
+    creat = [
+        EACCES, EBADF, EBUSY, EDQUOT, EEXIST, EFAULT, EFBIG, EINTR, EINVAL,
+        EISDIR, ELOOP, EMFILE, ENAMETOOLONG, ENFILE, ENODEV, ENOENT, ENOMEM,
+        ENOSPC, ENOTDIR, ENXIO, EOPNOTSUPP, EOVERFLOW, EPERM, EROFS, ETXTBSY,
+        EWOULDBLOCK,
+    ];
+
+    create_module = [
+        EEXIST, EFAULT, EINVAL, ENOMEM, ENOSYS, EPERM,
+    ];
+
+    delete_module = [
+        EBUSY, EFAULT, ENOENT, EPERM, EWOULDBLOCK,
+    ];
+
+    dup = [
+        EBADF, EBUSY, EINTR, EINVAL, EMFILE,
+    ];
+
+    dup2 = [
+        EBADF, EBUSY, EINTR, EINVAL, EMFILE,
+    ];
+
+    dup3 = [
+        EBADF, EBUSY, EINTR, EINVAL, EMFILE,
+    ];
+
+    epoll_create = [
+        EINVAL, EMFILE, ENFILE, ENOMEM,
+    ];
+
+    epoll_create1 = [
+        EINVAL, EMFILE, ENFILE, ENOMEM,
+    ];
+
+    epoll_ctl = [
+        EBADF, EEXIST, EINVAL, ELOOP, ENOENT, ENOMEM, ENOSPC, EPERM,
+    ];
+
+    epoll_pwait = [
+        EBADF, EFAULT, EINTR, EINVAL,
+    ];
+
+    epoll_pwait2 = [
+        EBADF, EFAULT, EINTR, EINVAL,
+    ];
+
+    epoll_wait = [
+        EBADF, EFAULT, EINTR, EINVAL,
+    ];
+
+    eventfd = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM,
+    ];
+
+    eventfd2 = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM,
+    ];
+
+    execve = [
+        E2BIG, EACCES, EAGAIN, EFAULT, EINVAL, EIO, EISDIR, ELIBBAD, ELOOP,
+        EMFILE, ENAMETOOLONG, ENFILE, ENOENT, ENOEXEC, ENOMEM, ENOTDIR, EPERM,
+        ETXTBSY,
+    ];
+
+    execveat = [
+        EINVAL, ELOOP, ENOENT, ENOTDIR,
+    ];
+
+    faccessat = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS, ETXTBSY,
+    ];
+
+    faccessat2 = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS, ETXTBSY,
+    ];
+
+    fadvise64 = [
+        EBADF, EINVAL, ESPIPE,
+    ];
+
+    fadvise64_64 = [
+        EBADF, EINVAL, ESPIPE,
+    ];
+
+    fallocate = [
+        EBADF, EFBIG, EINTR, EINVAL, EIO, ENODEV, ENOSPC, ENOSYS, EOPNOTSUPP,
+        EPERM, ESPIPE, ETXTBSY,
+    ];
+
+    fanotify_init = [
+        EINVAL, EMFILE, ENOMEM, ENOSYS, EPERM,
+    ];
+
+    fanotify_mark = [
+        EBADF, EEXIST, EINVAL, EISDIR, ENODEV, ENOENT, ENOMEM, ENOSPC, ENOSYS,
+        ENOTDIR, EOPNOTSUPP, EPERM, EXDEV,
+    ];
+
+    fchdir = [
+        EACCES, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM, ENOTDIR,
+        EBADF,
+    ];
+
+    fchmod = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, ENOTSUP, EPERM, EROFS,
+    ];
+
+    fchmodat = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, ENOTSUP, EPERM, EROFS,
+    ];
+
+    fchown = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    fchown32 = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    fchownat = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    fcntl = [
+        EACCES, EAGAIN, EBADF, EBUSY, EDEADLK, EFAULT, EINTR, EINVAL, EMFILE,
+        ENOLCK, ENOTDIR, EPERM,
+    ];
+
+    fcntl64 = [
+        EACCES, EAGAIN, EBADF, EBUSY, EDEADLK, EFAULT, EINTR, EINVAL, EMFILE,
+        ENOLCK, ENOTDIR, EPERM,
+    ];
+
+    fdatasync = [
+        EBADF, EINTR, EIO, ENOSPC, EROFS, EINVAL, EDQUOT,
+    ];
+
+    fgetxattr = [
+        E2BIG, ENODATA, ENOTSUP, ERANGE,
+    ];
+
+    finit_module = [
+        EBADMSG, EBUSY, EFAULT, ENOKEY, ENOMEM, EPERM, EEXIST, EINVAL, ENOEXEC,
+        EBADF, EFBIG, ETXTBSY,
+    ];
+
+    flistxattr = [
+        E2BIG, ENOTSUP, ERANGE,
+    ];
+
+    flock = [
+        EBADF, EINTR, EINVAL, ENOLCK, EWOULDBLOCK,
+    ];
+
+    fork = [
+        EAGAIN, ENOMEM, ENOSYS, ERESTARTNOINTR,
+    ];
+
+    fremovexattr = [
+        ENODATA, ENOTSUP,
+    ];
+
+    fsetxattr = [
+        EDQUOT, EEXIST, ENODATA, ENOSPC, ENOTSUP, EPERM, ERANGE,
+    ];
+
+    fstat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    fstat64 = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    fstatat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    fstatat64 = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    fstatfs = [
+        EACCES, EBADF, EFAULT, EINTR, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOSYS, ENOTDIR, EOVERFLOW,
+    ];
+
+    fstatfs64 = [
+        EACCES, EBADF, EFAULT, EINTR, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOSYS, ENOTDIR, EOVERFLOW,
+    ];
+
+    fsync = [
+        EBADF, EINTR, EIO, ENOSPC, EROFS, EINVAL, EDQUOT,
+    ];
+
+    ftruncate = [
+        EACCES, EFAULT, EFBIG, EINTR, EINVAL, EIO, EISDIR, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOTDIR, EPERM, EROFS, ETXTBSY, EBADF,
+    ];
+
+    ftruncate64 = [
+        EACCES, EFAULT, EFBIG, EINTR, EINVAL, EIO, EISDIR, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOTDIR, EPERM, EROFS, ETXTBSY, EBADF,
+    ];
+
+    futex = [
+        EACCES, EAGAIN, EDEADLK, EFAULT, EINTR, EINVAL, ENFILE, ENOMEM, ENOSYS,
+        EPERM, ESRCH, ETIMEDOUT,
+    ];
+
+    futex_time64 = [
+        EACCES, EAGAIN, EDEADLK, EFAULT, EINTR, EINVAL, ENFILE, ENOMEM, ENOSYS,
+        EPERM, ESRCH, ETIMEDOUT,
+    ];
+
+    futimesat = [
+        EBADF, ENOTDIR,
+    ];
+
+    get_kernel_syms = [
+        ENOSYS,
+    ];
+
+    get_mempolicy = [
+        EFAULT, EINVAL,
+    ];
+
+    get_robust_list = [
+        EINVAL, EFAULT, EPERM, ESRCH,
+    ];
+
+    get_thread_area = [
+        EFAULT, EINVAL, ENOSYS, ESRCH,
+    ];
+
+    getcpu = [
+        EFAULT,
+    ];
+
+    getcwd = [
+        EACCES, EFAULT, EINVAL, ENAMETOOLONG, ENOENT, ENOMEM, ERANGE,
+    ];
+
+    getdents = [
+        EBADF, EFAULT, EINVAL, ENOENT, ENOTDIR,
+    ];
+
+    getdents64 = [
+        EBADF, EFAULT, EINVAL, ENOENT, ENOTDIR,
+    ];
+
+    getdomainname = [
+        EFAULT, EINVAL, EPERM,
+    ];
+
+    getgroups = [
+        EFAULT, EINVAL, ENOMEM, EPERM,
+    ];
+
+    getgroups32 = [
+        EFAULT, EINVAL, ENOMEM, EPERM,
+    ];
+
+    getitimer = [
+        EFAULT, EINVAL,
+    ];
+
+    getpeername = [
+        EBADF, EFAULT, EINVAL, ENOBUFS, ENOTCONN, ENOTSOCK,
+    ];
+
+    getpgid = [
+        EACCES, EINVAL, EPERM, ESRCH,
+    ];
+
+    getpgrp = [
+        EACCES, EINVAL, EPERM, ESRCH,
+    ];
+
+    getpriority = [
+        EACCES, EINVAL, EPERM, ESRCH,
+    ];
+
+    getrandom = [
+        EAGAIN, EFAULT, EINTR, EINVAL, ENOSYS,
+    ];
+
+    getresgid = [
+        EFAULT,
+    ];
+
+    getresgid32 = [
+        EFAULT,
+    ];
+
+    getresuid = [
+        EFAULT,
+    ];
+
+    getresuid32 = [
+        EFAULT,
+    ];
+
+    getrlimit = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    getrusage = [
+        EFAULT, EINVAL,
+    ];
+
+    getsid = [
+        EPERM, ESRCH,
+    ];
+
+    getsockname = [
+        EBADF, EFAULT, EINVAL, ENOBUFS, ENOTSOCK,
+    ];
+
+    getsockopt = [
+        EBADF, EFAULT, EINVAL, ENOPROTOOPT, ENOTSOCK,
+    ];
+
+    gettimeofday = [
+        EFAULT, EINVAL, EPERM,
+    ];
+
+    getxattr = [
+        E2BIG, ENODATA, ENOTSUP, ERANGE,
+    ];
+
+    idle = [
+        EPERM,
+    ];
+
+    init_module = [
+        EBADMSG, EBUSY, EFAULT, ENOKEY, ENOMEM, EPERM, EEXIST, EINVAL, ENOEXEC,
+        EBADF, EFBIG, ETXTBSY,
+    ];
+
+    inotify_add_watch = [
+        EACCES, EBADF, EEXIST, EFAULT, EINVAL, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOSPC, ENOTDIR,
+    ];
+
+    inotify_init = [
+        EINVAL, EMFILE, ENFILE, ENOMEM,
+    ];
+
+    inotify_init1 = [
+        EINVAL, EMFILE, ENFILE, ENOMEM,
+    ];
+
+    inotify_rm_watch = [
+        EBADF, EINVAL,
+    ];
+
+    io_cancel = [
+        EAGAIN, EFAULT, EINVAL, ENOSYS,
+    ];
+
+    io_destroy = [
+        EFAULT, EINVAL, ENOSYS,
+    ];
+
+    io_getevents = [
+        EFAULT, EINTR, EINVAL, ENOSYS,
+    ];
+
+    io_setup = [
+        EAGAIN, EFAULT, EINVAL, ENOMEM, ENOSYS,
+    ];
+
+    io_submit = [
+        EAGAIN, EBADF, EFAULT, EINVAL, ENOSYS, EPERM,
+    ];
+
+    ioctl = [
+        EBADF, EFAULT, EINVAL, ENOTTY,
+    ];
+
+    ioperm = [
+        EINVAL, EIO, ENOMEM, EPERM,
+    ];
+
+    iopl = [
+        EINVAL, ENOSYS, EPERM,
+    ];
+
+    ioprio_get = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    ioprio_set = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    kcmp = [
+        EBADF, EFAULT, EINVAL, ENOENT, EPERM, ESRCH,
+    ];
+
+    kexec_file_load = [
+        EADDRNOTAVAIL, EBADF, EBUSY, EINVAL, ENOEXEC, ENOMEM, EPERM,
+    ];
+
+    kexec_load = [
+        EADDRNOTAVAIL, EBADF, EBUSY, EINVAL, ENOEXEC, ENOMEM, EPERM,
+    ];
+
+    keyctl = [
+        EACCES, EAGAIN, EDEADLK, EDQUOT, EEXIST, EFAULT, EINVAL, EKEYEXPIRED,
+        EKEYREJECTED, EKEYREVOKED, ELOOP, EMSGSIZE, ENFILE, ENOENT, ENOKEY,
+        ENOMEM, ENOTDIR, EOPNOTSUPP, EPERM, ETIMEDOUT,
+    ];
+
+    kill = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    landlock_add_rule = [
+        EOPNOTSUPP, EINVAL, ENOMSG, EBADF, EBADFD, EPERM, EFAULT,
+    ];
+
+    landlock_create_ruleset = [
+        EOPNOTSUPP, EINVAL, E2BIG, EFAULT, ENOMSG,
+    ];
+
+    landlock_restrict_self = [
+        EOPNOTSUPP, EINVAL, EBADF, EBADFD, EPERM, E2BIG,
+    ];
+
+    lchown = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    lchown32 = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS,
+    ];
+
+    lgetxattr = [
+        E2BIG, ENODATA, ENOTSUP, ERANGE,
+    ];
+
+    link = [
+        EACCES, EDQUOT, EEXIST, EFAULT, EIO, ELOOP, EMLINK, ENAMETOOLONG,
+        ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS, EXDEV, EBADF, EINVAL,
+    ];
+
+    linkat = [
+        EACCES, EDQUOT, EEXIST, EFAULT, EIO, ELOOP, EMLINK, ENAMETOOLONG,
+        ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS, EXDEV, EBADF, EINVAL,
+    ];
+
+    listen = [
+        EADDRINUSE, EBADF, ENOTSOCK, EOPNOTSUPP,
+    ];
+
+    listxattr = [
+        E2BIG, ENOTSUP, ERANGE,
+    ];
+
+    llistxattr = [
+        E2BIG, ENOTSUP, ERANGE,
+    ];
+
+    llseek = [
+        EBADF, EFAULT, EINVAL,
+    ];
+
+    lookup_dcookie = [
+        EFAULT, EINVAL, ENAMETOOLONG, ENOMEM, EPERM, ERANGE,
+    ];
+
+    lremovexattr = [
+        ENODATA, ENOTSUP,
+    ];
+
+    lseek = [
+        EBADF, EINVAL, ENXIO, EOVERFLOW, ESPIPE,
+    ];
+
+    lsetxattr = [
+        EDQUOT, EEXIST, ENODATA, ENOSPC, ENOTSUP, EPERM, ERANGE,
+    ];
+
+    lstat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    lstat64 = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    madvise = [
+        EACCES, EAGAIN, EBADF, EBUSY, EFAULT, EINVAL, EIO, ENOMEM, EPERM,
+        EHWPOISON,
+    ];
+
+    mbind = [
+        EFAULT, EINVAL, EIO, ENOMEM, EPERM,
+    ];
+
+    membarrier = [
+        EINVAL, ENOSYS, EPERM,
+    ];
+
+    memfd_create = [
+        EFAULT, EINVAL, EMFILE, ENFILE, ENOMEM,
+    ];
+
+    memfd_secret = [
+        EINVAL, EMFILE, ENOMEM, ENOSYS,
+    ];
+
+    migrate_pages = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    mincore = [
+        EFAULT, EINVAL, ENOMEM,
+    ];
+
+    mkdir = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EINVAL, ELOOP, EMLINK,
+        ENAMETOOLONG, ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    mkdirat = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EINVAL, ELOOP, EMLINK,
+        ENAMETOOLONG, ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    mknod = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EINVAL, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    mknodat = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EINVAL, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    mlock = [
+        EAGAIN, EINVAL, ENOMEM, EPERM,
+    ];
+
+    mlock2 = [
+        EAGAIN, EINVAL, ENOMEM, EPERM,
+    ];
+
+    mlockall = [
+        EAGAIN, EINVAL, ENOMEM, EPERM,
+    ];
+
+    mmap = [
+        EACCES, EAGAIN, EBADF, EEXIST, EINVAL, ENFILE, ENODEV, ENOMEM,
+        EOVERFLOW, EPERM, ETXTBSY,
+    ];
+
+    mmap2 = [
+        EFAULT, EINVAL,
+    ];
+
+    modify_ldt = [
+        EFAULT, EINVAL, ENOSYS,
+    ];
+
+    mount = [
+        EACCES, EBUSY, EFAULT, EINVAL, ELOOP, EMFILE, ENAMETOOLONG, ENODEV,
+        ENOENT, ENOMEM, ENOTBLK, ENOTDIR, ENXIO, EPERM, EROFS,
+    ];
+
+    mount_setattr = [
+        EBADF, EBUSY, EINVAL, ENOENT, ENOMEM, ENOSPC, EPERM,
+    ];
+
+    move_pages = [
+        E2BIG, EACCES, EFAULT, EINVAL, ENODEV, EPERM, ESRCH,
+    ];
+
+    mprotect = [
+        EACCES, EINVAL, ENOMEM,
+    ];
+
+    mq_notify = [
+        EBADF, EBUSY, EINVAL, ENOMEM,
+    ];
+
+    mq_open = [
+        EACCES, EEXIST, EINVAL, EMFILE, ENAMETOOLONG, ENFILE, ENOENT, ENOMEM,
+        ENOSPC,
+    ];
+
+    mq_timedreceive = [
+        EAGAIN, EBADF, EINTR, EINVAL, EMSGSIZE, ETIMEDOUT,
+    ];
+
+    mq_timedreceive_time64 = [
+        EAGAIN, EBADF, EINTR, EINVAL, EMSGSIZE, ETIMEDOUT,
+    ];
+
+    mq_timedsend = [
+        EAGAIN, EBADF, EINTR, EINVAL, EMSGSIZE, ETIMEDOUT,
+    ];
+
+    mq_timedsend_time64 = [
+        EAGAIN, EBADF, EINTR, EINVAL, EMSGSIZE, ETIMEDOUT,
+    ];
+
+    mq_unlink = [
+        EACCES, ENAMETOOLONG, ENOENT,
+    ];
+
+    mremap = [
+        EAGAIN, EFAULT, EINVAL, ENOMEM,
+    ];
+
+    msgctl = [
+        EACCES, EFAULT, EIDRM, EINVAL, EPERM,
+    ];
+
+    msgget = [
+        EACCES, EEXIST, ENOENT, ENOMEM, ENOSPC,
+    ];
+
+    msgrcv = [
+        EACCES, EAGAIN, EFAULT, EIDRM, EINTR, EINVAL, ENOMEM, E2BIG, ENOMSG,
+        ENOSYS,
+    ];
+
+    msgsnd = [
+        EACCES, EAGAIN, EFAULT, EIDRM, EINTR, EINVAL, ENOMEM, E2BIG, ENOMSG,
+        ENOSYS,
+    ];
+
+    msync = [
+        EBUSY, EINVAL, ENOMEM,
+    ];
+
+    munlock = [
+        EAGAIN, EINVAL, ENOMEM, EPERM,
+    ];
+
+    munlockall = [
+        EAGAIN, EINVAL, ENOMEM, EPERM,
+    ];
+
+    munmap = [
+        EACCES, EAGAIN, EBADF, EEXIST, EINVAL, ENFILE, ENODEV, ENOMEM,
+        EOVERFLOW, EPERM, ETXTBSY,
+    ];
+
+    name_to_handle_at = [
+        EFAULT, EINVAL, ENOENT, ENOTDIR, EOPNOTSUPP, EOVERFLOW, EBADF, ELOOP,
+        EPERM, ESTALE,
+    ];
+
+    nanosleep = [
+        EFAULT, EINTR, EINVAL,
+    ];
+
+    newfstatat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    nice = [
+        EPERM,
+    ];
+
+    oldfstat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    oldlstat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    oldolduname = [
+        EFAULT,
+    ];
+
+    oldstat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    olduname = [
+        EFAULT,
+    ];
+
+    open = [
+        EACCES, EBADF, EBUSY, EDQUOT, EEXIST, EFAULT, EFBIG, EINTR, EINVAL,
+        EISDIR, ELOOP, EMFILE, ENAMETOOLONG, ENFILE, ENODEV, ENOENT, ENOMEM,
+        ENOSPC, ENOTDIR, ENXIO, EOPNOTSUPP, EOVERFLOW, EPERM, EROFS, ETXTBSY,
+        EWOULDBLOCK,
+    ];
+
+    open_by_handle_at = [
+        EFAULT, EINVAL, ENOENT, ENOTDIR, EOPNOTSUPP, EOVERFLOW, EBADF, ELOOP,
+        EPERM, ESTALE,
+    ];
+
+    openat = [
+        EACCES, EBADF, EBUSY, EDQUOT, EEXIST, EFAULT, EFBIG, EINTR, EINVAL,
+        EISDIR, ELOOP, EMFILE, ENAMETOOLONG, ENFILE, ENODEV, ENOENT, ENOMEM,
+        ENOSPC, ENOTDIR, ENXIO, EOPNOTSUPP, EOVERFLOW, EPERM, EROFS, ETXTBSY,
+        EWOULDBLOCK,
+    ];
+
+    openat2 = [
+        E2BIG, EAGAIN, EINVAL, ELOOP, EXDEV,
+    ];
+
+    pause = [
+        EINTR,
+    ];
+
+    pciconfig_iobase = [
+        EINVAL, EIO, ENODEV, ENOSYS, EOPNOTSUPP, EPERM,
+    ];
+
+    pciconfig_read = [
+        EINVAL, EIO, ENODEV, ENOSYS, EOPNOTSUPP, EPERM,
+    ];
+
+    pciconfig_write = [
+        EINVAL, EIO, ENODEV, ENOSYS, EOPNOTSUPP, EPERM,
+    ];
+
+    perf_event_open = [
+        E2BIG, EACCES, EBADF, EBUSY, EFAULT, EINTR, EINVAL, EMFILE, ENODEV,
+        ENOENT, ENOSPC, ENOSYS, EOPNOTSUPP, EOVERFLOW, EPERM, ESRCH,
+    ];
+
+    personality = [
+        EINVAL,
+    ];
+
+    pidfd_getfd = [
+        EBADF, EINVAL, EMFILE, ENFILE, EPERM, ESRCH,
+    ];
+
+    pidfd_open = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, ESRCH,
+    ];
+
+    pidfd_send_signal = [
+        EBADF, EINVAL, EPERM, ESRCH,
+    ];
+
+    pipe = [
+        EFAULT, EINVAL, EMFILE, ENFILE, ENOPKG,
+    ];
+
+    pipe2 = [
+        EFAULT, EINVAL, EMFILE, ENFILE, ENOPKG,
+    ];
+
+    pivot_root = [
+        EBUSY, EINVAL, ENOTDIR, EPERM,
+    ];
+
+    pkey_alloc = [
+        EINVAL, ENOSPC,
+    ];
+
+    pkey_free = [
+        EINVAL, ENOSPC,
+    ];
+
+    pkey_mprotect = [
+        EACCES, EINVAL, ENOMEM,
+    ];
+
+    poll = [
+        EFAULT, EINTR, EINVAL, ENOMEM,
+    ];
+
+    ppoll = [
+        EFAULT, EINTR, EINVAL, ENOMEM,
+    ];
+
+    ppoll_time64 = [
+        EFAULT, EINTR, EINVAL, ENOMEM,
+    ];
+
+    prctl = [
+        EACCES, EBADF, EBUSY, EFAULT, EINVAL, ENODEV, ENXIO, EOPNOTSUPP, EPERM,
+        ERANGE,
+    ];
+
+    pread64 = [
+        EAGAIN, EWOULDBLOCK, EBADF, EFAULT, EINTR, EINVAL, EIO, EISDIR,
+    ];
+
+    preadv = [
+        EINVAL, EOPNOTSUPP,
+    ];
+
+    preadv2 = [
+        EINVAL, EOPNOTSUPP,
+    ];
+
+    prlimit64 = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    process_madvise = [
+        EBADF, EFAULT, EINVAL, ENOMEM, EPERM, ESRCH,
+    ];
+
+    process_vm_readv = [
+        EFAULT, EINVAL, ENOMEM, EPERM, ESRCH,
+    ];
+
+    process_vm_writev = [
+        EFAULT, EINVAL, ENOMEM, EPERM, ESRCH,
+    ];
+
+    pselect6 = [
+        EBADF, EINTR, EINVAL, ENOMEM,
+    ];
+
+    pselect6_time64 = [
+        EBADF, EINTR, EINVAL, ENOMEM,
+    ];
+
+    ptrace = [
+        EBUSY, EFAULT, EINVAL, EIO, EPERM, ESRCH,
+    ];
+
+    pwrite64 = [
+        EAGAIN, EWOULDBLOCK, EBADF, EFAULT, EINTR, EINVAL, EIO, EISDIR,
+    ];
+
+    pwritev = [
+        EINVAL, EOPNOTSUPP,
+    ];
+
+    pwritev2 = [
+        EINVAL, EOPNOTSUPP,
+    ];
+
+    query_module = [
+        EFAULT, EINVAL, ENOENT, ENOSPC, ENOSYS,
+    ];
+
+    quotactl = [
+        EACCES, EBUSY, EFAULT, EINVAL, ENOENT, ENOSYS, ENOTBLK, EPERM, ERANGE,
+        ESRCH,
+    ];
+
+    read = [
+        EAGAIN, EWOULDBLOCK, EBADF, EFAULT, EINTR, EINVAL, EIO, EISDIR,
+    ];
+
+    readahead = [
+        EBADF, EINVAL,
+    ];
+
+    readdir = [
+        EBADF, EFAULT, EINVAL, ENOENT, ENOTDIR,
+    ];
+
+    readlink = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR,
+    ];
+
+    readlinkat = [
+        EACCES, EBADF, EFAULT, EINVAL, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR,
+    ];
+
+    readv = [
+        EINVAL, EOPNOTSUPP,
+    ];
+
+    reboot = [
+        EFAULT, EINVAL, EPERM,
+    ];
+
+    recv = [
+        EAGAIN, EWOULDBLOCK, EBADF, ECONNREFUSED, EFAULT, EINTR, EINVAL, ENOMEM,
+        ENOTCONN, ENOTSOCK,
+    ];
+
+    recvfrom = [
+        EAGAIN, EWOULDBLOCK, EBADF, ECONNREFUSED, EFAULT, EINTR, EINVAL, ENOMEM,
+        ENOTCONN, ENOTSOCK,
+    ];
+
+    recvmmsg = [
+        EINVAL,
+    ];
+
+    recvmmsg_time64 = [
+        EINVAL,
+    ];
+
+    recvmsg = [
+        EAGAIN, EWOULDBLOCK, EBADF, ECONNREFUSED, EFAULT, EINTR, EINVAL, ENOMEM,
+        ENOTCONN, ENOTSOCK,
+    ];
+
+    remap_file_pages = [
+        EINVAL,
+    ];
+
+    removexattr = [
+        ENODATA, ENOTSUP,
+    ];
+
+    rename = [
+        EACCES, EBUSY, EDQUOT, EFAULT, EINVAL, EISDIR, ELOOP, EMLINK,
+        ENAMETOOLONG, ENOENT, ENOMEM, ENOSPC, ENOTDIR, ENOTEMPTY, EEXIST, EPERM,
+        EROFS, EXDEV, EBADF,
+    ];
+
+    renameat = [
+        EACCES, EBUSY, EDQUOT, EFAULT, EINVAL, EISDIR, ELOOP, EMLINK,
+        ENAMETOOLONG, ENOENT, ENOMEM, ENOSPC, ENOTDIR, ENOTEMPTY, EEXIST, EPERM,
+        EROFS, EXDEV, EBADF,
+    ];
+
+    renameat2 = [
+        EACCES, EBUSY, EDQUOT, EFAULT, EINVAL, EISDIR, ELOOP, EMLINK,
+        ENAMETOOLONG, ENOENT, ENOMEM, ENOSPC, ENOTDIR, ENOTEMPTY, EEXIST, EPERM,
+        EROFS, EXDEV, EBADF,
+    ];
+
+    request_key = [
+        EACCES, EDQUOT, EFAULT, EINTR, EINVAL, EKEYEXPIRED, EKEYREJECTED,
+        EKEYREVOKED, ENOKEY, ENOMEM, EPERM,
+    ];
+
+    rmdir = [
+        EACCES, EBUSY, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, ENOTEMPTY, EPERM, EROFS,
+    ];
+
+    rt_sigaction = [
+        EFAULT, EINVAL,
+    ];
+
+    rt_sigpending = [
+        EFAULT,
+    ];
+
+    rt_sigprocmask = [
+        EFAULT, EINVAL,
+    ];
+
+    rt_sigqueueinfo = [
+        EAGAIN, EINVAL, EPERM, ESRCH,
+    ];
+
+    rt_sigsuspend = [
+        EFAULT, EINTR,
+    ];
+
+    rt_sigtimedwait = [
+        EAGAIN, EINTR, EINVAL,
+    ];
+
+    rt_sigtimedwait_time64 = [
+        EAGAIN, EINTR, EINVAL,
+    ];
+
+    rt_tgsigqueueinfo = [
+        EAGAIN, EINVAL, EPERM, ESRCH,
+    ];
+
+    s390_guarded_storage = [
+        EFAULT, EINVAL, ENOMEM, EOPNOTSUPP,
+    ];
+
+    s390_pci_mmio_read = [
+        EFAULT, EINVAL, ENODEV, ENOMEM,
+    ];
+
+    s390_pci_mmio_write = [
+        EFAULT, EINVAL, ENODEV, ENOMEM,
+    ];
+
+    s390_runtime_instr = [
+        EINVAL, ENOMEM, EOPNOTSUPP,
+    ];
+
+    s390_sthyi = [
+        EFAULT, EINVAL, ENOMEM, EOPNOTSUPP,
+    ];
+
+    sched_get_priority_max = [
+        EINVAL,
+    ];
+
+    sched_get_priority_min = [
+        EINVAL,
+    ];
+
+    sched_getaffinity = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    sched_getattr = [
+        EINVAL, ESRCH, E2BIG, EBUSY, EPERM,
+    ];
+
+    sched_getparam = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    sched_getscheduler = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    sched_rr_get_interval = [
+        EFAULT, EINVAL, ENOSYS, ESRCH,
+    ];
+
+    sched_rr_get_interval_time64 = [
+        EFAULT, EINVAL, ENOSYS, ESRCH,
+    ];
+
+    sched_setaffinity = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    sched_setattr = [
+        EINVAL, ESRCH, E2BIG, EBUSY, EPERM,
+    ];
+
+    sched_setparam = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    sched_setscheduler = [
+        EINVAL, EPERM, ESRCH,
+    ];
+
+    seccomp = [
+        EACCES, EBUSY, EFAULT, EINVAL, ENOMEM, EOPNOTSUPP, ESRCH,
+    ];
+
+    select = [
+        EBADF, EINTR, EINVAL, ENOMEM,
+    ];
+
+    semctl = [
+        EACCES, EFAULT, EIDRM, EINVAL, EPERM, ERANGE,
+    ];
+
+    semget = [
+        EACCES, EEXIST, EINVAL, ENOENT, ENOMEM, ENOSPC,
+    ];
+
+    semop = [
+        E2BIG, EACCES, EAGAIN, EFAULT, EFBIG, EIDRM, EINTR, EINVAL, ENOMEM,
+        ERANGE,
+    ];
+
+    semtimedop = [
+        E2BIG, EACCES, EAGAIN, EFAULT, EFBIG, EIDRM, EINTR, EINVAL, ENOMEM,
+        ERANGE,
+    ];
+
+    semtimedop_time64 = [
+        E2BIG, EACCES, EAGAIN, EFAULT, EFBIG, EIDRM, EINTR, EINVAL, ENOMEM,
+        ERANGE,
+    ];
+
+    send = [
+        EACCES, EAGAIN, EWOULDBLOCK, EALREADY, EBADF, ECONNRESET, EDESTADDRREQ,
+        EFAULT, EINTR, EINVAL, EISCONN, EMSGSIZE, ENOBUFS, ENOMEM, ENOTCONN,
+        ENOTSOCK, EOPNOTSUPP, EPIPE,
+    ];
+
+    sendfile = [
+        EAGAIN, EBADF, EFAULT, EINVAL, EIO, ENOMEM, EOVERFLOW, ESPIPE,
+    ];
+
+    sendfile64 = [
+        EAGAIN, EBADF, EFAULT, EINVAL, EIO, ENOMEM, EOVERFLOW, ESPIPE,
+    ];
+
+    sendmmsg = [
+        EACCES, EAGAIN, EWOULDBLOCK, EALREADY, EBADF, ECONNRESET, EDESTADDRREQ,
+        EFAULT, EINTR, EINVAL, EISCONN, EMSGSIZE, ENOBUFS, ENOMEM, ENOTCONN,
+        ENOTSOCK, EOPNOTSUPP, EPIPE,
+    ];
+
+    sendmsg = [
+        EACCES, EAGAIN, EWOULDBLOCK, EALREADY, EBADF, ECONNRESET, EDESTADDRREQ,
+        EFAULT, EINTR, EINVAL, EISCONN, EMSGSIZE, ENOBUFS, ENOMEM, ENOTCONN,
+        ENOTSOCK, EOPNOTSUPP, EPIPE,
+    ];
+
+    sendto = [
+        EACCES, EAGAIN, EWOULDBLOCK, EALREADY, EBADF, ECONNRESET, EDESTADDRREQ,
+        EFAULT, EINTR, EINVAL, EISCONN, EMSGSIZE, ENOBUFS, ENOMEM, ENOTCONN,
+        ENOTSOCK, EOPNOTSUPP, EPIPE,
+    ];
+
+    set_mempolicy = [
+        EFAULT, EINVAL, ENOMEM,
+    ];
+
+    set_robust_list = [
+        EINVAL, EFAULT, EPERM, ESRCH,
+    ];
+
+    set_thread_area = [
+        EFAULT, EINVAL, ENOSYS, ESRCH,
+    ];
+
+    setdomainname = [
+        EFAULT, EINVAL, EPERM,
+    ];
+
+    setgid = [
+        EINVAL, EPERM,
+    ];
+
+    setgid32 = [
+        EINVAL, EPERM,
+    ];
+
+    setgroups = [
+        EFAULT, EINVAL, ENOMEM, EPERM,
+    ];
+
+    setgroups32 = [
+        EFAULT, EINVAL, ENOMEM, EPERM,
+    ];
+
+    sethostname = [
+        EFAULT, EINVAL, ENAMETOOLONG, EPERM,
+    ];
+
+    setitimer = [
+        EFAULT, EINVAL,
+    ];
+
+    setns = [
+        EBADF, EINVAL, ENOMEM, EPERM, ESRCH,
+    ];
+
+    setpgid = [
+        EACCES, EINVAL, EPERM, ESRCH,
+    ];
+
+    setpriority = [
+        EACCES, EINVAL, EPERM, ESRCH,
+    ];
+
+    setregid = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setregid32 = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setresgid = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setresgid32 = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setresuid = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setresuid32 = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setreuid = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setreuid32 = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setrlimit = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    setsid = [
+        EPERM,
+    ];
+
+    setsockopt = [
+        EBADF, EFAULT, EINVAL, ENOPROTOOPT, ENOTSOCK,
+    ];
+
+    settimeofday = [
+        EFAULT, EINVAL, EPERM,
+    ];
+
+    setuid = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setuid32 = [
+        EAGAIN, EINVAL, EPERM,
+    ];
+
+    setxattr = [
+        EDQUOT, EEXIST, ENODATA, ENOSPC, ENOTSUP, EPERM, ERANGE,
+    ];
+
+    shmat = [
+        EACCES, EIDRM, EINVAL, ENOMEM,
+    ];
+
+    shmctl = [
+        EACCES, EFAULT, EIDRM, EINVAL, ENOMEM, EOVERFLOW, EPERM,
+    ];
+
+    shmdt = [
+        EACCES, EIDRM, EINVAL, ENOMEM,
+    ];
+
+    shmget = [
+        EACCES, EEXIST, EINVAL, ENFILE, ENOENT, ENOMEM, ENOSPC, EPERM,
+    ];
+
+    shutdown = [
+        EBADF, EINVAL, ENOTCONN, ENOTSOCK,
+    ];
+
+    sigaction = [
+        EFAULT, EINVAL,
+    ];
+
+    sigaltstack = [
+        EFAULT, EINVAL, ENOMEM, EPERM,
+    ];
+
+    signal = [
+        EINVAL,
+    ];
+
+    signalfd = [
+        EBADF, EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM,
+    ];
+
+    signalfd4 = [
+        EBADF, EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM,
+    ];
+
+    sigpending = [
+        EFAULT,
+    ];
+
+    sigprocmask = [
+        EFAULT, EINVAL,
+    ];
+
+    sigsuspend = [
+        EFAULT, EINTR,
+    ];
+
+    socket = [
+        EACCES, EAFNOSUPPORT, EINVAL, EMFILE, ENFILE, ENOBUFS, ENOMEM,
+        EPROTONOSUPPORT,
+    ];
+
+    socketpair = [
+        EAFNOSUPPORT, EFAULT, EMFILE, ENFILE, EOPNOTSUPP, EPROTONOSUPPORT,
+    ];
+
+    splice = [
+        EAGAIN, EBADF, EINVAL, ENOMEM, ESPIPE,
+    ];
+
+    spu_create = [
+        EACCES, EEXIST, EFAULT, EINVAL, ELOOP, EMFILE, ENAMETOOLONG, ENFILE,
+        ENODEV, ENOENT, ENOMEM, ENOSPC, ENOSYS, ENOTDIR, EPERM,
+    ];
+
+    spu_run = [
+        EBADF, EFAULT, EINTR, EINVAL, ENOMEM, ENOSYS,
+    ];
+
+    stat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    stat64 = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EOVERFLOW,
+    ];
+
+    statfs = [
+        EACCES, EBADF, EFAULT, EINTR, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOSYS, ENOTDIR, EOVERFLOW,
+    ];
+
+    statfs64 = [
+        EACCES, EBADF, EFAULT, EINTR, EIO, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOSYS, ENOTDIR, EOVERFLOW,
+    ];
+
+    statx = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR,
+    ];
+
+    stime = [
+        EFAULT, EPERM,
+    ];
+
+    subpage_prot = [
+        EFAULT, EINVAL, ENOMEM,
+    ];
+
+    swapoff = [
+        EBUSY, EINVAL, ENFILE, ENOENT, ENOMEM, EPERM,
+    ];
+
+    swapon = [
+        EBUSY, EINVAL, ENFILE, ENOENT, ENOMEM, EPERM,
+    ];
+
+    symlink = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT,
+        ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    symlinkat = [
+        EACCES, EBADF, EDQUOT, EEXIST, EFAULT, EIO, ELOOP, ENAMETOOLONG, ENOENT,
+        ENOMEM, ENOSPC, ENOTDIR, EPERM, EROFS,
+    ];
+
+    sync = [
+        EBADF, EIO, ENOSPC, EDQUOT,
+    ];
+
+    sync_file_range = [
+        EBADF, EINVAL, EIO, ENOMEM, ENOSPC, ESPIPE,
+    ];
+
+    sync_file_range2 = [
+        EBADF, EINVAL, EIO, ENOMEM, ENOSPC, ESPIPE,
+    ];
+
+    syncfs = [
+        EBADF, EIO, ENOSPC, EDQUOT,
+    ];
+
+    sysfs = [
+        EFAULT, EINVAL,
+    ];
+
+    sysinfo = [
+        EFAULT,
+    ];
+
+    syslog = [
+        EINVAL, ENOSYS, EPERM, ERESTARTSYS,
+    ];
+
+    tee = [
+        EAGAIN, EINVAL, ENOMEM,
+    ];
+
+    tgkill = [
+        EAGAIN, EINVAL, EPERM, ESRCH,
+    ];
+
+    time = [
+        EFAULT,
+    ];
+
+    timer_create = [
+        EAGAIN, EINVAL, ENOMEM, ENOTSUP, EPERM,
+    ];
+
+    timer_delete = [
+        EINVAL,
+    ];
+
+    timer_getoverrun = [
+        EINVAL,
+    ];
+
+    timer_gettime = [
+        EFAULT, EINVAL,
+    ];
+
+    timer_gettime64 = [
+        EFAULT, EINVAL,
+    ];
+
+    timer_settime = [
+        EFAULT, EINVAL,
+    ];
+
+    timer_settime64 = [
+        EFAULT, EINVAL,
+    ];
+
+    timerfd_create = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, EPERM, EBADF, EFAULT, ECANCELED,
+    ];
+
+    timerfd_gettime = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, EPERM, EBADF, EFAULT, ECANCELED,
+    ];
+
+    timerfd_gettime64 = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, EPERM, EBADF, EFAULT, ECANCELED,
+    ];
+
+    timerfd_settime = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, EPERM, EBADF, EFAULT, ECANCELED,
+    ];
+
+    timerfd_settime64 = [
+        EINVAL, EMFILE, ENFILE, ENODEV, ENOMEM, EPERM, EBADF, EFAULT, ECANCELED,
+    ];
+
+    times = [
+        EFAULT,
+    ];
+
+    tkill = [
+        EAGAIN, EINVAL, EPERM, ESRCH,
+    ];
+
+    truncate = [
+        EACCES, EFAULT, EFBIG, EINTR, EINVAL, EIO, EISDIR, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOTDIR, EPERM, EROFS, ETXTBSY, EBADF,
+    ];
+
+    truncate64 = [
+        EACCES, EFAULT, EFBIG, EINTR, EINVAL, EIO, EISDIR, ELOOP, ENAMETOOLONG,
+        ENOENT, ENOTDIR, EPERM, EROFS, ETXTBSY, EBADF,
+    ];
+
+    ugetrlimit = [
+        EFAULT, EINVAL, EPERM, ESRCH,
+    ];
+
+    umount = [
+        EAGAIN, EBUSY, EFAULT, EINVAL, ENAMETOOLONG, ENOENT, ENOMEM, EPERM,
+    ];
+
+    umount2 = [
+        EAGAIN, EBUSY, EFAULT, EINVAL, ENAMETOOLONG, ENOENT, ENOMEM, EPERM,
+    ];
+
+    uname = [
+        EFAULT,
+    ];
+
+    unlink = [
+        EACCES, EBUSY, EFAULT, EIO, EISDIR, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS, EBADF, EINVAL,
+    ];
+
+    unlinkat = [
+        EACCES, EBUSY, EFAULT, EIO, EISDIR, ELOOP, ENAMETOOLONG, ENOENT, ENOMEM,
+        ENOTDIR, EPERM, EROFS, EBADF, EINVAL,
+    ];
+
+    unshare = [
+        EINVAL, ENOMEM, ENOSPC, EUSERS, EPERM,
+    ];
+
+    uselib = [
+        EACCES, ENFILE, ENOEXEC,
+    ];
+
+    userfaultfd = [
+        EINVAL, EMFILE, ENFILE, ENOMEM, EPERM,
+    ];
+
+    ustat = [
+        EFAULT, EINVAL, ENOSYS,
+    ];
+
+    utime = [
+        EACCES, ENOENT, EPERM, EROFS,
+    ];
+
+    utimensat = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOTDIR,
+        EPERM, EROFS, ESRCH,
+    ];
+
+    utimensat_time64 = [
+        EACCES, EBADF, EFAULT, EINVAL, ELOOP, ENAMETOOLONG, ENOENT, ENOTDIR,
+        EPERM, EROFS, ESRCH,
+    ];
+
+    utimes = [
+        EACCES, ENOENT, EPERM, EROFS,
+    ];
+
+    vhangup = [
+        EPERM,
+    ];
+
+    vm86 = [
+        EFAULT, ENOSYS, EPERM,
+    ];
+
+    vm86old = [
+        EFAULT, ENOSYS, EPERM,
+    ];
+
+    vmsplice = [
+        EAGAIN, EBADF, EINVAL, ENOMEM,
+    ];
+
+    wait4 = [
+        EAGAIN, ECHILD, EINTR, EINVAL, ESRCH,
+    ];
+
+    waitid = [
+        EAGAIN, ECHILD, EINTR, EINVAL, ESRCH,
+    ];
+
+    waitpid = [
+        EAGAIN, ECHILD, EINTR, EINVAL, ESRCH,
+    ];
+
+    write = [
+        EAGAIN, EWOULDBLOCK, EBADF, EDESTADDRREQ, EDQUOT, EFAULT, EFBIG, EINTR,
+        EINVAL, EIO, ENOSPC, EPERM, EPIPE,
+    ];
+
+    writev = [
+        EINVAL, EOPNOTSUPP,
+    ];
+}
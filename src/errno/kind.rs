@@ -0,0 +1,238 @@
+//! Categories of error codes that don't depend on `std`.
+
+use super::Errno;
+
+/// A category of error codes. This mirrors how `std` maps error codes to
+/// `std::io::ErrorKind`, but is available without `std`. See
+/// [`Errno::kind`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An entity was not found, often a file (`ENOENT`).
+    NotFound,
+    /// The operation lacked the necessary privileges (`EACCES` or `EPERM`).
+    PermissionDenied,
+    /// The connection was refused by the remote server (`ECONNREFUSED`).
+    ConnectionRefused,
+    /// The connection was reset by the remote server (`ECONNRESET`).
+    ConnectionReset,
+    /// The remote host is not reachable (`EHOSTUNREACH`).
+    HostUnreachable,
+    /// The network containing the remote host is not reachable
+    /// (`ENETUNREACH`).
+    NetworkUnreachable,
+    /// The connection was aborted by the remote server (`ECONNABORTED`).
+    ConnectionAborted,
+    /// The network operation failed because it was not connected yet
+    /// (`ENOTCONN`).
+    NotConnected,
+    /// A socket address could not be bound because the address is already in
+    /// use elsewhere (`EADDRINUSE`).
+    AddrInUse,
+    /// A nonexistent interface was requested or the requested address was not
+    /// local (`EADDRNOTAVAIL`).
+    AddrNotAvailable,
+    /// The system's networking is down (`ENETDOWN`).
+    NetworkDown,
+    /// The operation failed because a pipe was closed (`EPIPE`).
+    BrokenPipe,
+    /// An entity already exists, often a file (`EEXIST`).
+    AlreadyExists,
+    /// The operation needs to block to complete, but the blocking operation
+    /// was requested to not occur (`EAGAIN` or `EWOULDBLOCK`).
+    WouldBlock,
+    /// A filesystem object is, unexpectedly, not a directory (`ENOTDIR`).
+    NotADirectory,
+    /// The filesystem object is, unexpectedly, a directory (`EISDIR`).
+    IsADirectory,
+    /// A non-empty directory was specified where an empty directory was
+    /// expected (`ENOTEMPTY`).
+    DirectoryNotEmpty,
+    /// The filesystem or storage medium is read-only (`EROFS`).
+    ReadOnlyFilesystem,
+    /// Loop in the filesystem or IO subsystem, often too many levels of
+    /// symbolic links (`ELOOP`).
+    FilesystemLoop,
+    /// Stale network file handle (`ESTALE`).
+    StaleNetworkFileHandle,
+    /// A parameter was incorrect (`EINVAL`).
+    InvalidInput,
+    /// The I/O operation's timeout expired (`ETIMEDOUT`).
+    TimedOut,
+    /// The underlying storage is full (`ENOSPC`).
+    StorageFull,
+    /// Seek on unseekable file (`ESPIPE`).
+    NotSeekable,
+    /// The filesystem quota was exceeded (`EDQUOT`).
+    QuotaExceeded,
+    /// File larger than allowed or supported (`EFBIG`).
+    FileTooLarge,
+    /// The resource is busy (`EBUSY`).
+    ResourceBusy,
+    /// An executable file is busy (`ETXTBSY`).
+    ExecutableFileBusy,
+    /// A deadlock would occur (`EDEADLK`).
+    Deadlock,
+    /// A cross-device or cross-filesystem (hard) link or rename (`EXDEV`).
+    CrossesDevices,
+    /// Too many (hard) links to the same filesystem object (`EMLINK`).
+    TooManyLinks,
+    /// A filename was invalid, often because it is too long
+    /// (`ENAMETOOLONG`).
+    InvalidFilename,
+    /// The program argument list was too long (`E2BIG`).
+    ArgumentListTooLong,
+    /// The operation was interrupted (`EINTR`).
+    Interrupted,
+    /// The operation is unsupported on this platform (`ENOSYS` or
+    /// `EOPNOTSUPP`).
+    Unsupported,
+    /// An operation could not be completed, because it failed to allocate
+    /// enough memory (`ENOMEM`).
+    OutOfMemory,
+    /// The operation was partially successful and needs to be checked later
+    /// on due to not blocking (`EINPROGRESS`).
+    InProgress,
+    /// Any other error code.
+    Uncategorized,
+}
+
+impl Errno {
+    /// Returns the category of the error code, the same way that `std` maps
+    /// it to a `std::io::ErrorKind`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syscalls::{Errno, ErrorKind};
+    ///
+    /// assert_eq!(Errno::ENOENT.kind(), ErrorKind::NotFound);
+    /// assert_eq!(Errno::EWOULDBLOCK.kind(), ErrorKind::WouldBlock);
+    /// assert_eq!(Errno::ENOTBLK.kind(), ErrorKind::Uncategorized);
+    /// ```
+    pub const fn kind(&self) -> ErrorKind {
+        use ErrorKind::*;
+
+        match *self {
+            Self::E2BIG => ArgumentListTooLong,
+            Self::EADDRINUSE => AddrInUse,
+            Self::EADDRNOTAVAIL => AddrNotAvailable,
+            Self::EBUSY => ResourceBusy,
+            Self::ECONNABORTED => ConnectionAborted,
+            Self::ECONNREFUSED => ConnectionRefused,
+            Self::ECONNRESET => ConnectionReset,
+            Self::EDEADLK => Deadlock,
+            Self::EDQUOT => QuotaExceeded,
+            Self::EEXIST => AlreadyExists,
+            Self::EFBIG => FileTooLarge,
+            Self::EHOSTUNREACH => HostUnreachable,
+            Self::EINTR => Interrupted,
+            Self::EINVAL => InvalidInput,
+            Self::EISDIR => IsADirectory,
+            Self::ELOOP => FilesystemLoop,
+            Self::ENOENT => NotFound,
+            Self::ENOMEM => OutOfMemory,
+            Self::ENOSPC => StorageFull,
+            Self::ENOSYS | Self::EOPNOTSUPP => Unsupported,
+            Self::EMLINK => TooManyLinks,
+            Self::ENAMETOOLONG => InvalidFilename,
+            Self::ENETDOWN => NetworkDown,
+            Self::ENETUNREACH => NetworkUnreachable,
+            Self::ENOTCONN => NotConnected,
+            Self::ENOTDIR => NotADirectory,
+            Self::ENOTEMPTY => DirectoryNotEmpty,
+            Self::EPIPE => BrokenPipe,
+            Self::EROFS => ReadOnlyFilesystem,
+            Self::ESPIPE => NotSeekable,
+            Self::ESTALE => StaleNetworkFileHandle,
+            Self::ETIMEDOUT => TimedOut,
+            Self::ETXTBSY => ExecutableFileBusy,
+            Self::EXDEV => CrossesDevices,
+            Self::EINPROGRESS => InProgress,
+            Self::EACCES | Self::EPERM => PermissionDenied,
+            Self::EAGAIN => WouldBlock,
+            _ => Uncategorized,
+        }
+    }
+
+    /// Returns true if retrying the same syscall may succeed without
+    /// changing anything. This is the case for `EINTR` and `EAGAIN` (or
+    /// `EWOULDBLOCK`).
+    pub const fn is_retryable(&self) -> bool {
+        matches!(*self, Self::EINTR | Self::EAGAIN)
+    }
+
+    /// Returns true if the file descriptor that the syscall operated on is
+    /// unusable, so that retrying or issuing other operations on it is
+    /// pointless. This is the case if it isn't open (`EBADF`), is in a bad
+    /// state (`EBADFD`), refers to a stale file (`ESTALE`), or is a pipe or
+    /// socket whose other end or connection is gone (`EPIPE`, `ESHUTDOWN`,
+    /// `ECONNRESET`, `ECONNABORTED` and `ENETRESET`).
+    pub const fn is_fatal_for_fd(&self) -> bool {
+        matches!(
+            *self,
+            Self::EBADF
+                | Self::EBADFD
+                | Self::ESTALE
+                | Self::EPIPE
+                | Self::ESHUTDOWN
+                | Self::ECONNRESET
+                | Self::ECONNABORTED
+                | Self::ENETRESET
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind() {
+        assert_eq!(Errno::ENOENT.kind(), ErrorKind::NotFound);
+        assert_eq!(Errno::EPERM.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Errno::EACCES.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Errno::EWOULDBLOCK.kind(), ErrorKind::WouldBlock);
+        assert_eq!(Errno::EDEADLK.kind(), ErrorKind::Deadlock);
+        assert_eq!(Errno::ERESTARTSYS.kind(), ErrorKind::Uncategorized);
+        assert_eq!(Errno::new(4095).kind(), ErrorKind::Uncategorized);
+
+        // `EDEADLOCK` has its own code on mips, powerpc and sparc, which
+        // `std` doesn't map to anything.
+        let deadlock = if Errno::EDEADLOCK == Errno::EDEADLK {
+            ErrorKind::Deadlock
+        } else {
+            ErrorKind::Uncategorized
+        };
+        assert_eq!(Errno::EDEADLOCK.kind(), deadlock);
+    }
+
+    /// The categories have the same names as the `std::io::ErrorKind` that
+    /// `std` maps each error code to.
+    #[cfg(feature = "std")]
+    #[test]
+    fn kind_matches_std() {
+        for num in 1..4096 {
+            let std_kind = std::io::Error::from_raw_os_error(num).kind();
+            assert_eq!(
+                format!("{:?}", Errno::new(num).kind()),
+                format!("{std_kind:?}"),
+                "{}",
+                Errno::new(num)
+            );
+        }
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(Errno::EINTR.is_retryable());
+        assert!(Errno::EWOULDBLOCK.is_retryable());
+        assert!(!Errno::ENOENT.is_retryable());
+        assert!(!Errno::EBADF.is_retryable());
+
+        assert!(Errno::EBADF.is_fatal_for_fd());
+        assert!(Errno::EPIPE.is_fatal_for_fd());
+        assert!(!Errno::EINTR.is_fatal_for_fd());
+        assert!(!Errno::ENOENT.is_fatal_for_fd());
+    }
+}
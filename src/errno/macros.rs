@@ -1,36 +1,79 @@
-// Helper for generating the Errno implementation.
-macro_rules! errno_enum {
+// Helper for generating an errno table. The error codes are added to `Errno`
+// as constants only on the architectures that match the `cfg`, so that they
+// have their native values.
+macro_rules! errno_table {
     (
         $(#[$meta:meta])*
-        $vis:vis enum $Name:ident {
+        errors {
             $(
                 $(#[$attrs:meta])*
-                $item:ident($code:expr) = $doc:expr,
+                $item:ident($code:literal) = $doc:expr,
             )*
         }
+
+        aliases {
+            $($alias:ident = $target:ident,)*
+        }
     ) => {
         $(#[$meta])*
-        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-        #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-        $vis struct $Name(pub(super) i32);
-
-        impl $Name {
+        impl super::Errno {
             $(
                 #[doc = $doc]
                 $(#[$attrs])*
-                pub const $item: $Name = $Name($code);
+                pub const $item: Self = Self($code);
             )*
 
-            /// Returns a pair containing the name of the error and a string
-            /// describing the error.
-            pub fn name_and_description(&self) -> Option<(&'static str, &'static str)> {
-                match *self {
-                    $(
-                        $(#[$attrs])*
-                        $Name::$item => Some((stringify!($item), $doc)),
-                    )*
-                    _ => None,
-                }
+            $(
+                #[doc = concat!("Same as [`Errno::", stringify!($target), "`](Self::", stringify!($target), ").")]
+                pub const $alias: Self = Self::$target;
+            )*
+        }
+
+        /// Returns a pair containing the name of the error and a string
+        /// describing the error.
+        pub(super) fn name_and_description(code: i32) -> Option<(&'static str, &'static str)> {
+            match code {
+                $(
+                    $(#[$attrs])*
+                    $code => Some((stringify!($item), $doc)),
+                )*
+                _ => None,
+            }
+        }
+
+        /// Returns the error code with the given name or alias.
+        pub(super) fn from_name(name: &str) -> Option<i32> {
+            match name {
+                $(
+                    $(#[$attrs])*
+                    stringify!($item) => Some($code),
+                )*
+                $(stringify!($alias) => from_name(stringify!($target)),)*
+                _ => None,
+            }
+        }
+    }
+}
+
+// Helper for generating the errors that the man page of each syscall
+// documents.
+macro_rules! documented_errors {
+    (
+        $(
+            $name:ident = [
+                $($errno:ident,)*
+            ];
+        )*
+    ) => {
+        use super::{Errno, ErrnoSet};
+
+        /// Returns the errors that the man page of the syscall documents.
+        pub(crate) fn documented_errors(name: &str) -> ErrnoSet {
+            match name {
+                $(
+                    stringify!($name) => ErrnoSet::new(&[$(Errno::$errno,)*]),
+                )*
+                _ => ErrnoSet::EMPTY,
             }
         }
     }
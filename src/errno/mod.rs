@@ -1,22 +1,44 @@
 #[macro_use]
 mod macros;
 
-mod generated;
+mod documented;
+mod generic;
+mod kind;
+mod mips;
+mod powerpc;
+mod restart;
+mod set;
+mod sparc;
 
 #[cfg(feature = "std")]
 mod last;
 
 use core::fmt;
-
-pub use self::generated::Errno;
+use core::str::FromStr;
+
+use crate::Arch;
+
+pub(crate) use self::documented::documented_errors;
+pub use self::kind::ErrorKind;
+pub use self::restart::Restart;
+pub use self::set::{ErrnoSet, ErrnoSetIter};
+
+/// An error code returned by a syscall.
+///
+/// Error codes differ between some architectures, such as mips and sparc. The
+/// constants have the values of the native architecture. Use
+/// [`Errno::translate`] to convert the error code of another architecture.
+///
+/// With the `serde` feature, an `Errno` is serialized as its number and
+/// deserialized from either its number or its name. Names are only accepted
+/// by human-readable formats, such as JSON, and are parsed like
+/// [`Errno::from_str`](FromStr::from_str). Numbers can be any `i32`, the same
+/// as with [`Errno::new`], so that every `Errno` round-trips. Use
+/// [`Errno::serialize_name`] to serialize it by name instead.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Errno(i32);
 
 impl Errno {
-    /// Operation would block. This is the same as [`Errno::EAGAIN`].
-    pub const EWOULDBLOCK: Self = Self::EAGAIN;
-
-    /// Same as [`Errno::EDEADLK`].
-    pub const EDEADLOCK: Self = Self::EDEADLK;
-
     /// Creates a new `Errno`.
     pub fn new(num: i32) -> Self {
         Self(num)
@@ -64,6 +86,35 @@ impl Errno {
         }
     }
 
+    /// Returns a pair containing the name of the error and a string
+    /// describing the error.
+    pub fn name_and_description(&self) -> Option<(&'static str, &'static str)> {
+        name_and_description(Arch::NATIVE, self.0)
+    }
+
+    /// Translates an error code of the `from` architecture into the error
+    /// code with the same name on the `to` architecture. This is useful for
+    /// decoding the errors returned to a tracee of another architecture.
+    ///
+    /// Returns `None` if the error code is unknown on `from` or doesn't exist
+    /// on `to`, such as `EPROCLIM`, which only exists on sparc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syscalls::{Arch, Errno};
+    ///
+    /// // `EDQUOT` is 1133 on mips and 122 on x86-64.
+    /// assert_eq!(
+    ///     Errno::new(1133).translate(Arch::Mips, Arch::X86_64),
+    ///     Some(Errno::new(122))
+    /// );
+    /// ```
+    pub fn translate(self, from: Arch, to: Arch) -> Option<Self> {
+        let (name, _) = name_and_description(from, self.0)?;
+        from_name(to, name).map(Self)
+    }
+
     /// Returns the name of the error. If the internal error code is unknown or
     /// invalid, `None` is returned.
     pub fn name(&self) -> Option<&'static str> {
@@ -106,6 +157,31 @@ impl fmt::Display for Errno {
     }
 }
 
+impl FromStr for Errno {
+    type Err = ();
+
+    /// Parses the name of an error code (such as `ENOENT`, in any case) or
+    /// its number (such as `2`). Aliases like `EWOULDBLOCK` and `ENOTSUP` are
+    /// accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.bytes().next().is_some_and(|b| b.is_ascii_digit()) {
+            return match s.parse() {
+                Ok(num @ 1..4096) => Ok(Self(num)),
+                _ => Err(()),
+            };
+        }
+
+        // The longest name is `ERESTART_RESTARTBLOCK`.
+        let mut buf = [0u8; 32];
+        let name = buf.get_mut(..s.len()).ok_or(())?;
+        name.copy_from_slice(s.as_bytes());
+        name.make_ascii_uppercase();
+
+        let name = core::str::from_utf8(name).map_err(|_| ())?;
+        from_name(Arch::NATIVE, name).map(Self).ok_or(())
+    }
+}
+
 impl fmt::Debug for Errno {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.name() {
@@ -125,6 +201,133 @@ impl From<Errno> for std::io::Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Errno {}
 
+#[cfg(feature = "serde")]
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+#[cfg(feature = "serde")]
+impl Errno {
+    /// Serializes the error code by name, or by number if it has no name.
+    /// Use this with `#[serde(serialize_with = "Errno::serialize_name")]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use syscalls::Errno;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Fault {
+    ///     #[serde(serialize_with = "Errno::serialize_name")]
+    ///     errno: Errno,
+    /// }
+    ///
+    /// let fault = Fault { errno: Errno::ENOENT };
+    /// assert_eq!(
+    ///     serde_json::to_string(&fault).unwrap(),
+    ///     r#"{"errno":"ENOENT"}"#
+    /// );
+    /// ```
+    pub fn serialize_name<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.name() {
+            Some(name) => serializer.serialize_str(name),
+            None => serializer.serialize_i32(self.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Errno {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Errno {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ErrnoVisitor;
+
+        impl Visitor<'_> for ErrnoVisitor {
+            type Value = Errno;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an errno name or an i32")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                i32::try_from(v).map(Errno).map_err(|_| {
+                    E::invalid_value(de::Unexpected::Signed(v), &self)
+                })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                i32::try_from(v).map(Errno).map_err(|_| {
+                    E::invalid_value(de::Unexpected::Unsigned(v), &self)
+                })
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(|_| {
+                    E::invalid_value(de::Unexpected::Str(v), &self)
+                })
+            }
+        }
+
+        // Only self-describing formats can tell a name from a number.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ErrnoVisitor)
+        } else {
+            deserializer.deserialize_i32(ErrnoVisitor)
+        }
+    }
+}
+
+/// Returns the name and description of an error code of the architecture.
+fn name_and_description(
+    arch: Arch,
+    code: i32,
+) -> Option<(&'static str, &'static str)> {
+    match arch {
+        Arch::Mips | Arch::Mips64 | Arch::MipsN32 => {
+            mips::name_and_description(code)
+        }
+        Arch::PowerPc | Arch::PowerPc64 => powerpc::name_and_description(code),
+        Arch::Sparc | Arch::Sparc64 => sparc::name_and_description(code),
+        _ => generic::name_and_description(code),
+    }
+}
+
+/// Returns the error code of the architecture with the given name or alias.
+fn from_name(arch: Arch, name: &str) -> Option<i32> {
+    match arch {
+        Arch::Mips | Arch::Mips64 | Arch::MipsN32 => mips::from_name(name),
+        Arch::PowerPc | Arch::PowerPc64 => powerpc::from_name(name),
+        Arch::Sparc | Arch::Sparc64 => sparc::from_name(name),
+        _ => generic::from_name(name),
+    }
+}
+
 pub trait ErrnoSentinel: Sized {
     fn sentinel() -> Self;
 }
@@ -159,7 +362,9 @@ impl ErrnoSentinel for usize {
     }
 }
 
+// The tests build with older versions of Rust than these lints suggest.
 #[cfg(test)]
+#[allow(clippy::io_other_error, clippy::manual_c_str_literals)]
 mod test {
     use super::*;
 
@@ -207,6 +412,165 @@ mod test {
         );
     }
 
+    #[test]
+    fn translate() {
+        let edquot = Errno::new(1133);
+        assert_eq!(edquot.translate(Arch::Mips, Arch::Mips64), Some(edquot));
+        assert_eq!(
+            edquot.translate(Arch::Mips, Arch::X86_64),
+            Some(Errno::new(122))
+        );
+        assert_eq!(
+            edquot.translate(Arch::Mips, Arch::Sparc),
+            Some(Errno::new(69))
+        );
+        assert_eq!(
+            Errno::new(122).translate(Arch::Aarch64, Arch::MipsN32),
+            Some(edquot)
+        );
+
+        // `EAGAIN` and the errors below 35 are the same everywhere.
+        for arch in Arch::ALL {
+            assert_eq!(
+                Errno::new(11).translate(Arch::X86, *arch),
+                Some(Errno::new(11))
+            );
+        }
+
+        // `EDEADLOCK` is an alias of `EDEADLK` on most architectures, but
+        // not on mips, powerpc, or sparc.
+        assert_eq!(
+            Errno::new(56).translate(Arch::Mips, Arch::X86_64),
+            Some(Errno::new(35))
+        );
+        assert_eq!(
+            Errno::new(58).translate(Arch::PowerPc, Arch::Sparc64),
+            Some(Errno::new(108))
+        );
+        assert_eq!(
+            Errno::new(35).translate(Arch::PowerPc64, Arch::Sparc),
+            Some(Errno::new(78))
+        );
+
+        // Kernel-internal error codes.
+        assert_eq!(
+            Errno::new(512).translate(Arch::Sparc, Arch::Riscv64),
+            Some(Errno::new(512))
+        );
+
+        // Only on sparc.
+        assert_eq!(Errno::new(67).translate(Arch::Sparc, Arch::X86), None);
+        assert_eq!(Errno::new(1133).translate(Arch::X86, Arch::Mips), None);
+        assert_eq!(Errno::new(0).translate(Arch::X86, Arch::X86), None);
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!(Errno::EWOULDBLOCK, Errno::EAGAIN);
+        assert_eq!(Errno::ENOTSUP, Errno::EOPNOTSUPP);
+        assert_eq!(Errno::EWOULDBLOCK.name(), Some("EAGAIN"));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("ENOENT".parse(), Ok(Errno::ENOENT));
+        assert_eq!("enoent".parse(), Ok(Errno::ENOENT));
+        assert_eq!("ERESTART_RESTARTBLOCK".parse(), Ok(Errno::new(516)));
+        assert_eq!("EWouldBlock".parse(), Ok(Errno::EAGAIN));
+        assert_eq!("ENOTSUP".parse(), Ok(Errno::EOPNOTSUPP));
+        assert_eq!("2".parse(), Ok(Errno::ENOENT));
+        assert_eq!("4095".parse(), Ok(Errno::new(4095)));
+        assert_eq!("0".parse::<Errno>(), Err(()));
+        assert_eq!("4096".parse::<Errno>(), Err(()));
+        assert_eq!("-2".parse::<Errno>(), Err(()));
+        assert_eq!("".parse::<Errno>(), Err(()));
+        assert_eq!("ENOPE".parse::<Errno>(), Err(()));
+        assert_eq!("ENOENT ".parse::<Errno>(), Err(()));
+        assert_eq!("E".repeat(64).parse::<Errno>(), Err(()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&Errno::ENOENT).unwrap(), "2");
+
+        for s in ["2", r#""ENOENT""#, r#""enoent""#, r#""2""#] {
+            assert_eq!(
+                serde_json::from_str::<Errno>(s).unwrap(),
+                Errno::ENOENT
+            );
+        }
+
+        assert!(serde_json::from_str::<Errno>(r#""ENOPE""#).is_err());
+        assert!(serde_json::from_str::<Errno>("4294967296").is_err());
+        assert!(serde_json::from_str::<Errno>(r#""4096""#).is_err());
+
+        // Any number round-trips, not just valid error codes.
+        for errno in [0, -2, 4096, 5000, i32::MIN, i32::MAX].map(Errno::new) {
+            let json = serde_json::to_string(&errno).unwrap();
+            assert_eq!(serde_json::from_str::<Errno>(&json).unwrap(), errno);
+        }
+
+        let mut json = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut json);
+        Errno::ENOENT.serialize_name(&mut serializer).unwrap();
+        Errno::new(4000).serialize_name(&mut serializer).unwrap();
+        assert_eq!(json, br#""ENOENT"4000"#);
+    }
+
+    /// A format that isn't self-describing, like bincode or postcard, which
+    /// only supports `deserialize_i32` for an `Errno`.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compact() {
+        use serde::de::value::Error;
+
+        struct Compact(i32);
+
+        impl<'de> Deserializer<'de> for Compact {
+            type Error = Error;
+
+            fn deserialize_any<V>(self, _: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                Err(de::Error::custom("not self-describing"))
+            }
+
+            fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                visitor.visit_i32(self.0)
+            }
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str
+                string bytes byte_buf option unit unit_struct newtype_struct
+                seq tuple tuple_struct map struct enum identifier ignored_any
+            }
+        }
+
+        assert_eq!(Errno::deserialize(Compact(2)), Ok(Errno::ENOENT));
+        assert_eq!(Errno::deserialize(Compact(0)), Ok(Errno::new(0)));
+        assert_eq!(Errno::deserialize(Compact(5000)), Ok(Errno::new(5000)));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn native() {
+        assert_eq!(Errno::EDQUOT, Errno::new(122));
+        assert_eq!(Errno::EDEADLOCK, Errno::EDEADLK);
+        assert_eq!(
+            Errno::EDQUOT.translate(Arch::NATIVE, Arch::Mips),
+            Some(Errno::new(1133))
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn last_errno() {
@@ -1,7 +1,8 @@
 // This file is automatically generated. Do not edit!
 
-errno_enum! {
-    pub enum Errno {
+errno_table! {
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    errors {
         EPERM(1) = "Operation not permitted",
         ENOENT(2) = "No such file or directory",
         ESRCH(3) = "No such process",
@@ -58,6 +59,7 @@ errno_enum! {
         ENOANO(55) = "No anode",
         EBADRQC(56) = "Invalid request code",
         EBADSLT(57) = "Invalid slot",
+        EDEADLOCK(58) = "File locking deadlock error",
         EBFONT(59) = "Bad font file format",
         ENOSTR(60) = "Device not a stream",
         ENODATA(61) = "No data available",
@@ -153,4 +155,9 @@ errno_enum! {
         ERECALLCONFLICT(530) = "conflict with recalled state",
         ENOGRACE(531) = "NFS file lock reclaim refused",
     }
+
+    aliases {
+        EWOULDBLOCK = EAGAIN,
+        ENOTSUP = EOPNOTSUPP,
+    }
 }
@@ -0,0 +1,138 @@
+//! What userspace sees when a syscall fails with a kernel-internal restart
+//! code. A tracer sees these codes at syscall exit when a signal interrupted
+//! the syscall. The kernel then replaces them before returning to userspace,
+//! depending on whether a signal handler runs. See `arch/x86/kernel/signal.c`.
+
+use super::Errno;
+
+/// What happens to a syscall that was interrupted by a signal. See
+/// [`Errno::restart_behavior`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Restart {
+    /// The syscall is restarted with the same arguments.
+    Restart,
+    /// The syscall fails with `EINTR`.
+    ReturnEINTR,
+    /// The syscall is continued through `restart_syscall`, which resumes it
+    /// with the state that it saved, such as the remaining time of a sleep.
+    RestartBlock,
+}
+
+impl Errno {
+    /// Returns true if this error code is private to the kernel and should
+    /// never be seen by userspace, such as `ERESTARTSYS` or `EIOCBQUEUED`.
+    /// A tracer may still see them at syscall exit.
+    pub fn is_kernel_internal(&self) -> bool {
+        matches!(self.0, 512..=531)
+    }
+
+    /// Returns what happens to a syscall that failed with a restart code,
+    /// or `None` if this isn't one.
+    ///
+    /// `sa_restart` is `None` if no signal handler runs, such as when the
+    /// signal is ignored, stops the thread, or is suppressed by a tracer.
+    /// Otherwise, it is whether the handler was installed with
+    /// `SA_RESTART`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syscalls::{Errno, Restart};
+    ///
+    /// let err = Errno::ERESTARTSYS;
+    /// assert_eq!(err.restart_behavior(Some(true)), Some(Restart::Restart));
+    /// assert_eq!(err.restart_behavior(Some(false)), Some(Restart::ReturnEINTR));
+    /// assert_eq!(Errno::ENOENT.restart_behavior(None), None);
+    /// ```
+    pub fn restart_behavior(
+        &self,
+        sa_restart: Option<bool>,
+    ) -> Option<Restart> {
+        let restart = match (*self, sa_restart) {
+            (Self::ERESTART_RESTARTBLOCK, None) => Restart::RestartBlock,
+            (Self::ERESTART_RESTARTBLOCK | Self::ERESTARTNOHAND, Some(_)) => {
+                Restart::ReturnEINTR
+            }
+            (Self::ERESTARTSYS, Some(false)) => Restart::ReturnEINTR,
+            (
+                Self::ERESTARTSYS | Self::ERESTARTNOHAND | Self::ERESTARTNOINTR,
+                _,
+            ) => Restart::Restart,
+            _ => return None,
+        };
+
+        Some(restart)
+    }
+
+    /// Returns the error that userspace sees instead of this one, or `None`
+    /// if the syscall is restarted and userspace sees no error. Error codes
+    /// other than restart codes are returned as is. See
+    /// [`Errno::restart_behavior`] for `sa_restart`.
+    pub fn user_visible(&self, sa_restart: Option<bool>) -> Option<Self> {
+        match self.restart_behavior(sa_restart) {
+            Some(Restart::ReturnEINTR) => Some(Self::EINTR),
+            Some(Restart::Restart | Restart::RestartBlock) => None,
+            None => Some(*self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernel_internal() {
+        assert!(Errno::ERESTARTSYS.is_kernel_internal());
+        assert!(Errno::EIOCBQUEUED.is_kernel_internal());
+        assert!(Errno::ENOGRACE.is_kernel_internal());
+        assert!(!Errno::EINTR.is_kernel_internal());
+        assert!(!Errno::EHWPOISON.is_kernel_internal());
+        assert!(!Errno::EDQUOT.is_kernel_internal());
+    }
+
+    #[test]
+    fn restart_behavior() {
+        const RESTART: Restart = Restart::Restart;
+        const EINTR: Restart = Restart::ReturnEINTR;
+        const BLOCK: Restart = Restart::RestartBlock;
+
+        // For no handler, a handler with `SA_RESTART` and one without it.
+        let cases = [
+            (Errno::ERESTARTSYS, [RESTART, RESTART, EINTR]),
+            (Errno::ERESTARTNOINTR, [RESTART, RESTART, RESTART]),
+            (Errno::ERESTARTNOHAND, [RESTART, EINTR, EINTR]),
+            (Errno::ERESTART_RESTARTBLOCK, [BLOCK, EINTR, EINTR]),
+        ];
+
+        for (err, expected) in cases {
+            for (sa_restart, expected) in
+                [None, Some(true), Some(false)].into_iter().zip(expected)
+            {
+                assert_eq!(
+                    err.restart_behavior(sa_restart),
+                    Some(expected),
+                    "{err:?} {sa_restart:?}"
+                );
+            }
+        }
+
+        assert_eq!(Errno::EINTR.restart_behavior(None), None);
+        assert_eq!(Errno::EIOCBQUEUED.restart_behavior(Some(true)), None);
+    }
+
+    #[test]
+    fn user_visible() {
+        assert_eq!(Errno::ERESTARTSYS.user_visible(Some(true)), None);
+        assert_eq!(
+            Errno::ERESTARTSYS.user_visible(Some(false)),
+            Some(Errno::EINTR)
+        );
+        assert_eq!(Errno::ERESTART_RESTARTBLOCK.user_visible(None), None);
+        assert_eq!(
+            Errno::ERESTARTNOHAND.user_visible(Some(true)),
+            Some(Errno::EINTR)
+        );
+        assert_eq!(Errno::ENOENT.user_visible(None), Some(Errno::ENOENT));
+    }
+}
@@ -0,0 +1,484 @@
+//! A bitset of error codes.
+
+use super::Errno;
+
+use core::fmt;
+
+/// The number of error codes that an [`ErrnoSet`] can hold. Error codes are
+/// always less than this (see [`Errno::is_valid`]).
+const MAX_ERRNO: usize = 4096;
+
+const WORD_WIDTH: usize = usize::BITS as usize;
+
+/// A set of error codes.
+///
+/// This provides constant-time lookup of error codes `1..4096` within a
+/// bitset, like [`SysnoSet`](crate::SysnoSet) does for syscalls.
+///
+/// # Examples
+///
+/// ```
+/// # use syscalls::{Errno, ErrnoSet};
+/// const RETRY: ErrnoSet = ErrnoSet::new(&[Errno::EINTR, Errno::EAGAIN]);
+/// const _: () = assert!(RETRY.contains(Errno::EINTR));
+///
+/// assert!(!RETRY.contains(Errno::ENOENT));
+/// assert_eq!(RETRY.iter().collect::<Vec<_>>(), [Errno::EINTR, Errno::EAGAIN]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct ErrnoSet {
+    data: [usize; MAX_ERRNO / WORD_WIDTH],
+}
+
+impl Default for ErrnoSet {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl ErrnoSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self {
+        data: [0; MAX_ERRNO / WORD_WIDTH],
+    };
+
+    /// Creates an empty set of error codes.
+    pub const fn empty() -> Self {
+        Self::EMPTY
+    }
+
+    /// Compute the index and mask for the given error code as stored in the
+    /// set data, or `None` if it doesn't fit in the set.
+    #[inline]
+    const fn get_idx_mask(errno: Errno) -> Option<(usize, usize)> {
+        if errno.0 <= 0 || errno.0 as usize >= MAX_ERRNO {
+            return None;
+        }
+
+        let bit = errno.0 as usize;
+        Some((bit / WORD_WIDTH, 1 << (bit % WORD_WIDTH)))
+    }
+
+    /// Initialize the set with the given slice of error codes.
+    ///
+    /// Since this is a `const fn`, this can be used at compile-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an error code isn't in `1..4096`.
+    pub const fn new(errnos: &[Errno]) -> Self {
+        let mut set = Self::EMPTY;
+
+        let mut i = 0;
+        while i < errnos.len() {
+            let Some((idx, mask)) = Self::get_idx_mask(errnos[i]) else {
+                panic!("error code out of range");
+            };
+            set.data[idx] |= mask;
+            i += 1;
+        }
+
+        set
+    }
+
+    /// Returns true if the set contains the given error code.
+    pub const fn contains(&self, errno: Errno) -> bool {
+        match Self::get_idx_mask(errno) {
+            Some((idx, mask)) => self.data[idx] & mask != 0,
+            None => false,
+        }
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&x| x == 0)
+    }
+
+    /// Clears the set, removing all error codes.
+    pub fn clear(&mut self) {
+        self.data = [0; MAX_ERRNO / WORD_WIDTH];
+    }
+
+    /// Returns the number of error codes in the set.
+    pub fn count(&self) -> usize {
+        self.data
+            .iter()
+            .fold(0, |acc, x| acc + x.count_ones() as usize)
+    }
+
+    /// Inserts the given error code into the set. Returns true if the error
+    /// code was not already in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the error code isn't in `1..4096`.
+    pub fn insert(&mut self, errno: Errno) -> bool {
+        let (idx, mask) =
+            Self::get_idx_mask(errno).expect("error code out of range");
+        let old_value = self.data[idx] & mask;
+        self.data[idx] |= mask;
+        old_value == 0
+    }
+
+    /// Removes the given error code from the set. Returns true if the error
+    /// code was in the set.
+    pub fn remove(&mut self, errno: Errno) -> bool {
+        let Some((idx, mask)) = Self::get_idx_mask(errno) else {
+            return false;
+        };
+        let old_value = self.data[idx] & mask;
+        self.data[idx] &= !mask;
+        old_value != 0
+    }
+
+    /// Does a set union with this set and another.
+    #[must_use]
+    pub const fn union(mut self, other: &Self) -> Self {
+        let mut i = 0;
+        let n = self.data.len();
+        while i < n {
+            self.data[i] |= other.data[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Does a set intersection with this set and another.
+    #[must_use]
+    pub const fn intersection(mut self, other: &Self) -> Self {
+        let mut i = 0;
+        let n = self.data.len();
+        while i < n {
+            self.data[i] &= other.data[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Calculates the difference with this set and another. That is, the
+    /// resulting set only includes the error codes that are in `self` but not
+    /// in `other`.
+    #[must_use]
+    pub const fn difference(mut self, other: &Self) -> Self {
+        let mut i = 0;
+        let n = self.data.len();
+        while i < n {
+            self.data[i] &= !other.data[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Calculates the symmetric difference with this set and another. That
+    /// is, the resulting set only includes the error codes that are in `self`
+    /// or in `other`, but not in both.
+    #[must_use]
+    pub const fn symmetric_difference(mut self, other: &Self) -> Self {
+        let mut i = 0;
+        let n = self.data.len();
+        while i < n {
+            self.data[i] ^= other.data[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Returns an iterator over the error codes in the set, in ascending
+    /// order.
+    pub fn iter(&self) -> ErrnoSetIter<'_> {
+        ErrnoSetIter {
+            data: &self.data,
+            index: 0,
+            current: self.data[0],
+        }
+    }
+}
+
+impl fmt::Debug for ErrnoSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl core::ops::BitOr for ErrnoSet {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl core::ops::BitOrAssign<&Self> for ErrnoSet {
+    fn bitor_assign(&mut self, rhs: &Self) {
+        for (left, right) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *left |= right;
+        }
+    }
+}
+
+impl core::ops::BitOrAssign for ErrnoSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self |= &rhs;
+    }
+}
+
+impl core::ops::BitOrAssign<Errno> for ErrnoSet {
+    fn bitor_assign(&mut self, errno: Errno) {
+        self.insert(errno);
+    }
+}
+
+impl FromIterator<Errno> for ErrnoSet {
+    fn from_iter<I: IntoIterator<Item = Errno>>(iter: I) -> Self {
+        let mut set = ErrnoSet::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Errno> for ErrnoSet {
+    fn extend<T: IntoIterator<Item = Errno>>(&mut self, iter: T) {
+        for errno in iter {
+            self.insert(errno);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ErrnoSet {
+    type Item = Errno;
+    type IntoIter = ErrnoSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the error codes contained in an [`ErrnoSet`].
+pub struct ErrnoSetIter<'a> {
+    data: &'a [usize],
+    // The index of the current word.
+    index: usize,
+    // The bits of the current word that haven't been returned yet.
+    current: usize,
+}
+
+impl Iterator for ErrnoSetIter<'_> {
+    type Item = Errno;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.data.get(self.index)?;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+
+        // Mask off the lowest bit that is set.
+        self.current &= self.current - 1;
+
+        Some(Errno((self.index * WORD_WIDTH + bit) as i32))
+    }
+}
+
+#[cfg(feature = "serde")]
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+#[cfg(feature = "serde")]
+impl Serialize for ErrnoSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.count()))?;
+        for errno in self {
+            seq.serialize_element(&errno)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ErrnoSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> Visitor<'de> for SeqVisitor {
+            type Value = ErrnoSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = ErrnoSet::EMPTY;
+
+                while let Some(value) = seq.next_element::<Errno>()? {
+                    if !(1..MAX_ERRNO as i32).contains(&value.0) {
+                        return Err(serde::de::Error::custom(
+                            "error code out of range",
+                        ));
+                    }
+
+                    values.insert(value);
+                }
+
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ErrnoSet::default(), ErrnoSet::EMPTY);
+        assert!(ErrnoSet::empty().is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = ErrnoSet::EMPTY;
+        assert!(set.insert(Errno::ENOENT));
+        assert!(!set.insert(Errno::ENOENT));
+        assert!(set.insert(Errno::new(4095)));
+        assert!(set.insert(Errno::new(1)));
+        assert!(set.contains(Errno::ENOENT));
+        assert_eq!(set.count(), 3);
+
+        assert!(set.remove(Errno::ENOENT));
+        assert!(!set.remove(Errno::ENOENT));
+        assert!(!set.remove(Errno::new(0)));
+        assert!(!set.remove(Errno::new(4096)));
+        assert!(!set.contains(Errno::ENOENT));
+        assert!(!set.contains(Errno::new(-1)));
+        assert_eq!(set.count(), 2);
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic = "error code out of range"]
+    fn test_insert_invalid() {
+        let mut set = ErrnoSet::EMPTY;
+        set.insert(Errno::new(4096));
+    }
+
+    #[test]
+    fn test_iter() {
+        let errnos = [
+            Errno::new(1),
+            Errno::EINTR,
+            Errno::new(63),
+            Errno::new(64),
+            Errno::ERESTARTSYS,
+            Errno::new(4095),
+        ];
+        let set = ErrnoSet::new(&errnos);
+        assert!(set.iter().eq(errnos));
+        assert_eq!(set.iter().count(), set.count());
+        assert_eq!(ErrnoSet::EMPTY.iter().next(), None);
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a = ErrnoSet::new(&[Errno::EINTR, Errno::EAGAIN, Errno::ENOENT]);
+        let b = ErrnoSet::new(&[Errno::EAGAIN, Errno::EBADF]);
+
+        assert_eq!(
+            a.clone().union(&b),
+            ErrnoSet::new(&[
+                Errno::EINTR,
+                Errno::EAGAIN,
+                Errno::ENOENT,
+                Errno::EBADF
+            ])
+        );
+        assert_eq!(a.clone() | b.clone(), a.clone().union(&b));
+        assert_eq!(a.clone().intersection(&b), ErrnoSet::new(&[Errno::EAGAIN]));
+        assert_eq!(
+            a.clone().difference(&b),
+            ErrnoSet::new(&[Errno::EINTR, Errno::ENOENT])
+        );
+        assert_eq!(
+            a.clone().symmetric_difference(&b),
+            ErrnoSet::new(&[Errno::EINTR, Errno::ENOENT, Errno::EBADF])
+        );
+
+        let mut c = ErrnoSet::EMPTY;
+        c |= Errno::EINTR;
+        c.extend([Errno::EAGAIN, Errno::ENOENT]);
+        assert_eq!(c, a);
+        assert_eq!(a.iter().collect::<ErrnoSet>(), a);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        let set = ErrnoSet::new(&[Errno::EAGAIN, Errno::EINTR]);
+        assert_eq!(format!("{set:?}"), "{EINTR, EAGAIN}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let set = ErrnoSet::new(&[Errno::EINTR, Errno::EAGAIN]);
+        let s = serde_json::to_string(&set).unwrap();
+        assert_eq!(s, "[4,11]");
+        assert_eq!(serde_json::from_str::<ErrnoSet>(&s).unwrap(), set);
+        assert_eq!(
+            serde_json::from_str::<ErrnoSet>(r#"["EINTR", "eagain"]"#).unwrap(),
+            set
+        );
+        assert!(serde_json::from_str::<ErrnoSet>("[0]").is_err());
+    }
+
+    #[test]
+    fn test_documented_errors() {
+        use crate::Sysno;
+
+        let errors = Sysno::openat.documented_errors();
+        assert!(errors.contains(Errno::ENOENT));
+        assert!(errors.contains(Errno::EACCES));
+        assert!(!errors.contains(Errno::ECHILD));
+
+        // The page of `wait4` refers to `waitpid`.
+        assert!(Sysno::wait4.documented_errors().contains(Errno::ECHILD));
+
+        // `getpid` always succeeds.
+        assert!(Sysno::getpid.documented_errors().is_empty());
+    }
+
+    #[cfg(any(target_arch = "mips", feature = "mips"))]
+    #[test]
+    fn test_documented_errors_foreign() {
+        // The codes are those of the table's architecture, such as 1133 for
+        // `EDQUOT` on mips.
+        let errors = crate::mips::Sysno::open.documented_errors();
+        assert!(errors.contains(Errno::new(1133)));
+        assert!(errors.contains(Errno::new(2)));
+        assert_eq!(
+            errors.count(),
+            crate::errno::documented_errors("open").count()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+// This file is automatically generated. Do not edit!
+
+errno_table! {
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    errors {
+        EPERM(1) = "Operation not permitted",
+        ENOENT(2) = "No such file or directory",
+        ESRCH(3) = "No such process",
+        EINTR(4) = "Interrupted system call",
+        EIO(5) = "I/O error",
+        ENXIO(6) = "No such device or address",
+        E2BIG(7) = "Argument list too long",
+        ENOEXEC(8) = "Exec format error",
+        EBADF(9) = "Bad file number",
+        ECHILD(10) = "No child processes",
+        EAGAIN(11) = "Try again",
+        ENOMEM(12) = "Out of memory",
+        EACCES(13) = "Permission denied",
+        EFAULT(14) = "Bad address",
+        ENOTBLK(15) = "Block device required",
+        EBUSY(16) = "Device or resource busy",
+        EEXIST(17) = "File exists",
+        EXDEV(18) = "Cross-device link",
+        ENODEV(19) = "No such device",
+        ENOTDIR(20) = "Not a directory",
+        EISDIR(21) = "Is a directory",
+        EINVAL(22) = "Invalid argument",
+        ENFILE(23) = "File table overflow",
+        EMFILE(24) = "Too many open files",
+        ENOTTY(25) = "Not a typewriter",
+        ETXTBSY(26) = "Text file busy",
+        EFBIG(27) = "File too large",
+        ENOSPC(28) = "No space left on device",
+        ESPIPE(29) = "Illegal seek",
+        EROFS(30) = "Read-only file system",
+        EMLINK(31) = "Too many links",
+        EPIPE(32) = "Broken pipe",
+        EDOM(33) = "Math argument out of domain of func",
+        ERANGE(34) = "Math result not representable",
+        EINPROGRESS(36) = "Operation now in progress",
+        EALREADY(37) = "Operation already in progress",
+        ENOTSOCK(38) = "Socket operation on non-socket",
+        EDESTADDRREQ(39) = "Destination address required",
+        EMSGSIZE(40) = "Message too long",
+        EPROTOTYPE(41) = "Protocol wrong type for socket",
+        ENOPROTOOPT(42) = "Protocol not available",
+        EPROTONOSUPPORT(43) = "Protocol not supported",
+        ESOCKTNOSUPPORT(44) = "Socket type not supported",
+        EOPNOTSUPP(45) = "Op not supported on transport endpoint",
+        EPFNOSUPPORT(46) = "Protocol family not supported",
+        EAFNOSUPPORT(47) = "Address family not supported by protocol",
+        EADDRINUSE(48) = "Address already in use",
+        EADDRNOTAVAIL(49) = "Cannot assign requested address",
+        ENETDOWN(50) = "Network is down",
+        ENETUNREACH(51) = "Network is unreachable",
+        ENETRESET(52) = "Net dropped connection because of reset",
+        ECONNABORTED(53) = "Software caused connection abort",
+        ECONNRESET(54) = "Connection reset by peer",
+        ENOBUFS(55) = "No buffer space available",
+        EISCONN(56) = "Transport endpoint is already connected",
+        ENOTCONN(57) = "Transport endpoint is not connected",
+        ESHUTDOWN(58) = "No send after transport endpoint shutdown",
+        ETOOMANYREFS(59) = "Too many references: cannot splice",
+        ETIMEDOUT(60) = "Connection timed out",
+        ECONNREFUSED(61) = "Connection refused",
+        ELOOP(62) = "Too many symbolic links encountered",
+        ENAMETOOLONG(63) = "File name too long",
+        EHOSTDOWN(64) = "Host is down",
+        EHOSTUNREACH(65) = "No route to host",
+        ENOTEMPTY(66) = "Directory not empty",
+        EPROCLIM(67) = "SUNOS: Too many processes",
+        EUSERS(68) = "Too many users",
+        EDQUOT(69) = "Quota exceeded",
+        ESTALE(70) = "Stale file handle",
+        EREMOTE(71) = "Object is remote",
+        ENOSTR(72) = "Device not a stream",
+        ETIME(73) = "Timer expired",
+        ENOSR(74) = "Out of streams resources",
+        ENOMSG(75) = "No message of desired type",
+        EBADMSG(76) = "Not a data message",
+        EIDRM(77) = "Identifier removed",
+        EDEADLK(78) = "Resource deadlock would occur",
+        ENOLCK(79) = "No record locks available",
+        ENONET(80) = "Machine is not on the network",
+        ERREMOTE(81) = "SunOS: Too many lvls of remote in path",
+        ENOLINK(82) = "Link has been severed",
+        EADV(83) = "Advertise error",
+        ESRMNT(84) = "Srmount error",
+        ECOMM(85) = "Communication error on send",
+        EPROTO(86) = "Protocol error",
+        EMULTIHOP(87) = "Multihop attempted",
+        EDOTDOT(88) = "RFS specific error",
+        EREMCHG(89) = "Remote address changed",
+        ENOSYS(90) = "Function not implemented",
+        ESTRPIPE(91) = "Streams pipe error",
+        EOVERFLOW(92) = "Value too large for defined data type",
+        EBADFD(93) = "File descriptor in bad state",
+        ECHRNG(94) = "Channel number out of range",
+        EL2NSYNC(95) = "Level 2 not synchronized",
+        EL3HLT(96) = "Level 3 halted",
+        EL3RST(97) = "Level 3 reset",
+        ELNRNG(98) = "Link number out of range",
+        EUNATCH(99) = "Protocol driver not attached",
+        ENOCSI(100) = "No CSI structure available",
+        EL2HLT(101) = "Level 2 halted",
+        EBADE(102) = "Invalid exchange",
+        EBADR(103) = "Invalid request descriptor",
+        EXFULL(104) = "Exchange full",
+        ENOANO(105) = "No anode",
+        EBADRQC(106) = "Invalid request code",
+        EBADSLT(107) = "Invalid slot",
+        EDEADLOCK(108) = "File locking deadlock error",
+        EBFONT(109) = "Bad font file format",
+        ELIBEXEC(110) = "Cannot exec a shared library directly",
+        ENODATA(111) = "No data available",
+        ELIBBAD(112) = "Accessing a corrupted shared library",
+        ENOPKG(113) = "Package not installed",
+        ELIBACC(114) = "Can not access a needed shared library",
+        ENOTUNIQ(115) = "Name not unique on network",
+        ERESTART(116) = "Interrupted syscall should be restarted",
+        EUCLEAN(117) = "Structure needs cleaning",
+        ENOTNAM(118) = "Not a XENIX named type file",
+        ENAVAIL(119) = "No XENIX semaphores available",
+        EISNAM(120) = "Is a named type file",
+        EREMOTEIO(121) = "Remote I/O error",
+        EILSEQ(122) = "Illegal byte sequence",
+        ELIBMAX(123) = "Atmpt to link in too many shared libs",
+        ELIBSCN(124) = ".lib section in a.out corrupted",
+        ENOMEDIUM(125) = "No medium found",
+        EMEDIUMTYPE(126) = "Wrong medium type",
+        ECANCELED(127) = "Operation Cancelled",
+        ENOKEY(128) = "Required key not available",
+        EKEYEXPIRED(129) = "Key has expired",
+        EKEYREVOKED(130) = "Key has been revoked",
+        EKEYREJECTED(131) = "Key was rejected by service",
+        EOWNERDEAD(132) = "Owner died",
+        ENOTRECOVERABLE(133) = "State not recoverable",
+        ERFKILL(134) = "Operation not possible due to RF-kill",
+        EHWPOISON(135) = "Memory page has hardware error",
+        ERESTARTSYS(512) = "Restart syscall",
+        ERESTARTNOINTR(513) = "Restart if no interrupt",
+        ERESTARTNOHAND(514) = "restart if no handler..",
+        ENOIOCTLCMD(515) = "No ioctl command",
+        ERESTART_RESTARTBLOCK(516) = "restart by calling sys_restart_syscall",
+        EPROBE_DEFER(517) = "Driver requests probe retry",
+        EOPENSTALE(518) = "open found a stale dentry",
+        ENOPARAM(519) = "Parameter not supported",
+        EBADHANDLE(521) = "Illegal NFS file handle",
+        ENOTSYNC(522) = "Update synchronization mismatch",
+        EBADCOOKIE(523) = "Cookie is stale",
+        ENOTSUPP(524) = "Operation is not supported",
+        ETOOSMALL(525) = "Buffer or request is too small",
+        ESERVERFAULT(526) = "An untranslatable error occurred",
+        EBADTYPE(527) = "Type not supported by server",
+        EJUKEBOX(528) = "Request initiated, but will not complete before timeout",
+        EIOCBQUEUED(529) = "iocb queued, will get completion event",
+        ERECALLCONFLICT(530) = "conflict with recalled state",
+        ENOGRACE(531) = "NFS file lock reclaim refused",
+    }
+
+    aliases {
+        EWOULDBLOCK = EAGAIN,
+        ENOTSUP = EOPNOTSUPP,
+    }
+}
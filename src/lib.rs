@@ -21,6 +21,8 @@
         target_arch = "s390x",
         target_arch = "powerpc",
         target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
     ),
     feature(asm_experimental_arch)
 )]
@@ -32,14 +34,30 @@ mod arch;
 mod args;
 mod errno;
 mod map;
+pub mod multiplex;
+#[cfg(feature = "std")]
+pub mod probe;
+mod regs;
+#[cfg(feature = "std")]
+pub mod seccomp;
 mod set;
+mod signature;
 mod syscall;
+#[cfg(feature = "std")]
+pub mod trace;
+pub mod translate;
+mod version;
 
 pub use arch::*;
 pub use args::SyscallArgs;
-pub use errno::{Errno, ErrnoSentinel};
+pub use errno::{
+    Errno, ErrnoSentinel, ErrnoSet, ErrnoSetIter, ErrorKind, Restart,
+};
 pub use map::*;
+pub use regs::SyscallRegs;
 pub use set::*;
+pub use signature::{ArgKind, SyscallArg};
+pub use version::KernelVersion;
 
 pub mod raw {
     //! Exposes raw syscalls that simply return a `usize` instead of a `Result`.
@@ -180,7 +198,9 @@ pub unsafe fn syscall(nr: Sysno, args: &SyscallArgs) -> Result<usize, Errno> {
     )
 }
 
+// The tests build with older versions of Rust than these lints suggest.
 #[cfg(test)]
+#[allow(clippy::manual_c_str_literals)]
 mod tests {
     use super::*;
 
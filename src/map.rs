@@ -1,14 +1,17 @@
 use super::Sysno;
+use crate::arch::{sysno_index, MAX_TABLE_SIZE};
 use crate::set::SysnoSetIter;
-use crate::SysnoSet;
+use crate::{ArchSysno, SysnoSet};
 use core::fmt;
 use core::mem::MaybeUninit;
 
-type DataArray<T> = [MaybeUninit<T>; Sysno::table_size()];
+type DataArray<T> = [MaybeUninit<T>; MAX_TABLE_SIZE];
 
 /// A map of syscalls to a type `T`.
 ///
-/// This provides constant-time lookup of syscalls within a static array.
+/// This provides constant-time lookup of syscalls within a static array. Like
+/// [`SysnoSet`], this can hold the syscalls of any enabled architecture module
+/// and is sized for the largest enabled syscall table.
 ///
 /// # Examples
 ///
@@ -48,34 +51,34 @@ type DataArray<T> = [MaybeUninit<T>; Sysno::table_size()];
 /// assert_eq!(syscalls.remove(Sysno::close), Some(11));
 /// assert!(syscalls.is_empty());
 /// ```
-pub struct SysnoMap<T> {
-    is_set: SysnoSet,
+pub struct SysnoMap<T, S: ArchSysno = Sysno> {
+    is_set: SysnoSet<S>,
     data: DataArray<T>,
 }
 
 /// Get internal data index based on sysno value
 #[inline]
-const fn get_idx(sysno: Sysno) -> usize {
-    (sysno.id() as usize) - (Sysno::first().id() as usize)
+const fn get_idx<S: ArchSysno>(sysno: S) -> usize {
+    sysno_index(&sysno)
 }
 
-impl<T> Default for SysnoMap<T> {
+impl<T, S: ArchSysno> Default for SysnoMap<T, S> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T> SysnoMap<T> {
+impl<T, S: ArchSysno> SysnoMap<T, S> {
     /// Initializes an empty syscall map.
     pub const fn new() -> Self {
         Self {
-            is_set: SysnoSet::empty(),
+            is_set: SysnoSet::EMPTY,
             data: unsafe { MaybeUninit::uninit().assume_init() },
         }
     }
 
     /// Returns true if the map contains the given syscall.
-    pub const fn contains_key(&self, sysno: Sysno) -> bool {
+    pub const fn contains_key(&self, sysno: S) -> bool {
         self.is_set.contains(sysno)
     }
 
@@ -105,7 +108,7 @@ impl<T> SysnoMap<T> {
 
     /// Inserts the given syscall into the map. Returns true if the syscall was
     /// not already in the map.
-    pub fn insert(&mut self, sysno: Sysno, value: T) -> Option<T> {
+    pub fn insert(&mut self, sysno: S, value: T) -> Option<T> {
         let uninit = &mut self.data[get_idx(sysno)];
         if self.is_set.insert(sysno) {
             // Was not already in the set.
@@ -120,7 +123,7 @@ impl<T> SysnoMap<T> {
 
     /// Removes the given syscall from the map. Returns old value if the syscall
     /// was in the map.
-    pub fn remove(&mut self, sysno: Sysno) -> Option<T> {
+    pub fn remove(&mut self, sysno: S) -> Option<T> {
         if self.is_set.remove(sysno) {
             let old = core::mem::replace(
                 &mut self.data[get_idx(sysno)],
@@ -134,7 +137,7 @@ impl<T> SysnoMap<T> {
 
     /// Returns a reference to the value corresponding to `sysno`. Returns
     /// `None` if the syscall is not in the map.
-    pub fn get(&self, sysno: Sysno) -> Option<&T> {
+    pub fn get(&self, sysno: S) -> Option<&T> {
         if self.is_set.contains(sysno) {
             Some(unsafe { self.data[get_idx(sysno)].assume_init_ref() })
         } else {
@@ -144,7 +147,7 @@ impl<T> SysnoMap<T> {
 
     /// Returns a mutable reference to the value corresponding to `sysno`.
     /// Returns `None` if the syscall is not in the map.
-    pub fn get_mut(&mut self, sysno: Sysno) -> Option<&mut T> {
+    pub fn get_mut(&mut self, sysno: S) -> Option<&mut T> {
         if self.is_set.contains(sysno) {
             Some(unsafe { self.data[get_idx(sysno)].assume_init_mut() })
         } else {
@@ -153,7 +156,7 @@ impl<T> SysnoMap<T> {
     }
 
     /// Returns an iterator that iterates over the syscalls contained in the map.
-    pub fn iter(&self) -> SysnoMapIter<'_, T> {
+    pub fn iter(&self) -> SysnoMapIter<'_, T, S> {
         SysnoMapIter {
             iter: self.is_set.iter(),
             data: &self.data,
@@ -162,12 +165,12 @@ impl<T> SysnoMap<T> {
 
     /// Returns an iterator that iterates over all enabled values contained in
     /// the map.
-    pub fn values(&self) -> SysnoMapValues<'_, T> {
+    pub fn values(&self) -> SysnoMapValues<'_, T, S> {
         SysnoMapValues(self.is_set.iter(), &self.data)
     }
 }
 
-impl<T: Copy> SysnoMap<T> {
+impl<T: Copy, S: ArchSysno> SysnoMap<T, S> {
     /// Initialize a syscall map from the given slice. Note that `T` must be
     /// `Copy` due to `const fn` limitations.
     ///
@@ -191,11 +194,11 @@ impl<T: Copy> SysnoMap<T> {
     /// assert_eq!(CALLBACKS[Sysno::openat](), 42);
     /// assert_eq!(DESCRIPTIONS[Sysno::close], "close a file descriptor");
     /// ```
-    pub const fn from_slice(slice: &[(Sysno, T)]) -> Self {
+    pub const fn from_slice(slice: &[(S, T)]) -> Self {
         let mut data: DataArray<T> =
             unsafe { MaybeUninit::uninit().assume_init() };
 
-        let mut is_set = SysnoSet::empty();
+        let mut is_set = SysnoSet::EMPTY;
 
         // Use while-loop because for-loops are not yet allowed in const-fns.
         // https://github.com/rust-lang/rust/issues/87575
@@ -212,18 +215,18 @@ impl<T: Copy> SysnoMap<T> {
     }
 }
 
-impl<T: Clone> SysnoMap<T> {
+impl<T: Clone, S: ArchSysno> SysnoMap<T, S> {
     /// Initializes all possible syscalls in the map with the given default
     /// value.
     pub fn init_all(default: &T) -> Self {
-        SysnoSet::all()
+        SysnoSet::<S>::ALL
             .iter()
             .map(|v| (v, default.clone()))
             .collect()
     }
 }
 
-impl<T> Drop for SysnoMap<T> {
+impl<T, S: ArchSysno> Drop for SysnoMap<T, S> {
     fn drop(&mut self) {
         self.clear();
     }
@@ -231,13 +234,13 @@ impl<T> Drop for SysnoMap<T> {
 
 /// An iterator over the syscall (number, value) pairs contained in a
 /// [`SysnoMap`].
-pub struct SysnoMapIter<'a, T> {
-    iter: SysnoSetIter<'a>,
+pub struct SysnoMapIter<'a, T, S = Sysno> {
+    iter: SysnoSetIter<'a, S>,
     data: &'a DataArray<T>,
 }
 
-impl<'a, T> Iterator for SysnoMapIter<'a, T> {
-    type Item = (Sysno, &'a T);
+impl<'a, T, S: ArchSysno> Iterator for SysnoMapIter<'a, T, S> {
+    type Item = (S, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|sysno| {
@@ -248,9 +251,12 @@ impl<'a, T> Iterator for SysnoMapIter<'a, T> {
 }
 
 /// An iterator over the syscall values contained in a [`SysnoMap`].
-pub struct SysnoMapValues<'a, T>(SysnoSetIter<'a>, &'a DataArray<T>);
+pub struct SysnoMapValues<'a, T, S = Sysno>(
+    SysnoSetIter<'a, S>,
+    &'a DataArray<T>,
+);
 
-impl<'a, T> Iterator for SysnoMapValues<'a, T> {
+impl<'a, T, S: ArchSysno> Iterator for SysnoMapValues<'a, T, S> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -260,24 +266,24 @@ impl<'a, T> Iterator for SysnoMapValues<'a, T> {
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for SysnoMap<T> {
+impl<T: fmt::Debug, S: ArchSysno> fmt::Debug for SysnoMap<T, S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_map().entries(self.iter()).finish()
     }
 }
 
-impl<T> Extend<(Sysno, T)> for SysnoMap<T> {
-    fn extend<I: IntoIterator<Item = (Sysno, T)>>(&mut self, iter: I) {
+impl<T, S: ArchSysno> Extend<(S, T)> for SysnoMap<T, S> {
+    fn extend<I: IntoIterator<Item = (S, T)>>(&mut self, iter: I) {
         for (sysno, value) in iter {
             self.insert(sysno, value);
         }
     }
 }
 
-impl<T> FromIterator<(Sysno, T)> for SysnoMap<T> {
+impl<T, S: ArchSysno> FromIterator<(S, T)> for SysnoMap<T, S> {
     fn from_iter<I>(iter: I) -> Self
     where
-        I: IntoIterator<Item = (Sysno, T)>,
+        I: IntoIterator<Item = (S, T)>,
     {
         let mut map = SysnoMap::new();
         map.extend(iter);
@@ -285,25 +291,25 @@ impl<T> FromIterator<(Sysno, T)> for SysnoMap<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a SysnoMap<T> {
-    type Item = (Sysno, &'a T);
-    type IntoIter = SysnoMapIter<'a, T>;
+impl<'a, T, S: ArchSysno> IntoIterator for &'a SysnoMap<T, S> {
+    type Item = (S, &'a T);
+    type IntoIter = SysnoMapIter<'a, T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<T> core::ops::Index<Sysno> for SysnoMap<T> {
+impl<T, S: ArchSysno> core::ops::Index<S> for SysnoMap<T, S> {
     type Output = T;
 
-    fn index(&self, sysno: Sysno) -> &T {
+    fn index(&self, sysno: S) -> &T {
         self.get(sysno).expect("no entry found for key")
     }
 }
 
-impl<T> core::ops::IndexMut<Sysno> for SysnoMap<T> {
-    fn index_mut(&mut self, sysno: Sysno) -> &mut T {
+impl<T, S: ArchSysno> core::ops::IndexMut<S> for SysnoMap<T, S> {
+    fn index_mut(&mut self, sysno: S) -> &mut T {
         self.get_mut(sysno).expect("no entry found for key")
     }
 }
@@ -414,4 +420,39 @@ mod tests {
         assert_eq!(map.get(Sysno::openat), Some(&42));
         assert_eq!(map.get(Sysno::close), Some(&42));
     }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_x86() {
+        use crate::x86;
+
+        static NAMES: SysnoMap<&str, x86::Sysno> = SysnoMap::from_slice(&[
+            (x86::Sysno::socketcall, "socketcall"),
+            (x86::Sysno::ipc, "ipc"),
+        ]);
+
+        assert_eq!(NAMES.count(), 2);
+        assert_eq!(NAMES[x86::Sysno::ipc], "ipc");
+        assert_eq!(NAMES.get(x86::Sysno::read), None);
+    }
+
+    #[cfg(feature = "arm")]
+    #[test]
+    fn test_arm_private() {
+        use crate::arm;
+
+        let mut map = SysnoMap::<u32, arm::Sysno>::new();
+        assert_eq!(map.insert(arm::Sysno::cacheflush, 1), None);
+        assert_eq!(map.insert(arm::Sysno::get_tls, 2), None);
+        assert_eq!(map.insert(arm::Sysno::restart_syscall, 3), None);
+
+        assert_eq!(map.get(arm::Sysno::cacheflush), Some(&1));
+        assert_eq!(map.get(arm::Sysno::get_tls), Some(&2));
+        assert_eq!(map.get(arm::Sysno::set_tls), None);
+        assert!(map.iter().map(|(sysno, _)| sysno).eq([
+            arm::Sysno::restart_syscall,
+            arm::Sysno::cacheflush,
+            arm::Sysno::get_tls,
+        ]));
+    }
 }
@@ -0,0 +1,631 @@
+//! Decoding of the multiplexed `socketcall(2)` and `ipc(2)` syscalls.
+//!
+//! Some architectures, such as x86, s390x and powerpc, have a single syscall
+//! for all socket operations and another one for all System V IPC operations.
+//! The first argument selects the operation, such as `SYS_CONNECT` or
+//! `SHMAT`. C libraries may use these even if the architecture also has the
+//! direct syscalls, so seccomp policies and tracers need to handle both.
+//!
+//! [`decode`] finds the operation of such a call and the direct syscall that
+//! does the same thing, if the architecture has one. The arguments of
+//! `socketcall` are behind a pointer, so computing the arguments of the direct
+//! syscall needs the memory of the process. See [`Decoded::memory`] and
+//! [`Decoded::args`].
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(any(target_arch = "x86", feature = "x86"))] {
+//! use syscalls::multiplex::{self, Multiplexed, SocketCall};
+//! use syscalls::{x86, SyscallArgs};
+//!
+//! // connect(3, 0x1000, 16) made through socketcall.
+//! let args = SyscallArgs::new(3, 0x2000, 0, 0, 0, 0);
+//! let decoded = multiplex::decode(x86::Sysno::socketcall, &args).unwrap();
+//! assert_eq!(decoded.call(), Multiplexed::Socket(SocketCall::Connect));
+//! assert_eq!(decoded.sysno(), Some(x86::Sysno::connect));
+//!
+//! // The arguments are at address 0x2000 in the process.
+//! assert_eq!(decoded.memory(&args), Some((0x2000, 3)));
+//! assert_eq!(
+//!     decoded.args(&args, &[3, 0x1000, 16]),
+//!     Some(SyscallArgs::new(3, 0x1000, 16, 0, 0, 0))
+//! );
+//! # }
+//! ```
+
+use core::fmt;
+
+use crate::{Arch, ArchSysno, SyscallArch, SyscallArgs, Sysno};
+
+/// An operation of `socketcall(2)`, as passed in its first argument. These
+/// are the `SYS_*` constants in `linux/net.h`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(usize)]
+pub enum SocketCall {
+    /// `SYS_SOCKET`
+    Socket = 1,
+    /// `SYS_BIND`
+    Bind = 2,
+    /// `SYS_CONNECT`
+    Connect = 3,
+    /// `SYS_LISTEN`
+    Listen = 4,
+    /// `SYS_ACCEPT`
+    Accept = 5,
+    /// `SYS_GETSOCKNAME`
+    GetSockName = 6,
+    /// `SYS_GETPEERNAME`
+    GetPeerName = 7,
+    /// `SYS_SOCKETPAIR`
+    SocketPair = 8,
+    /// `SYS_SEND`
+    Send = 9,
+    /// `SYS_RECV`
+    Recv = 10,
+    /// `SYS_SENDTO`
+    SendTo = 11,
+    /// `SYS_RECVFROM`
+    RecvFrom = 12,
+    /// `SYS_SHUTDOWN`
+    Shutdown = 13,
+    /// `SYS_SETSOCKOPT`
+    SetSockOpt = 14,
+    /// `SYS_GETSOCKOPT`
+    GetSockOpt = 15,
+    /// `SYS_SENDMSG`
+    SendMsg = 16,
+    /// `SYS_RECVMSG`
+    RecvMsg = 17,
+    /// `SYS_ACCEPT4`
+    Accept4 = 18,
+    /// `SYS_RECVMMSG`
+    RecvMMsg = 19,
+    /// `SYS_SENDMMSG`
+    SendMMsg = 20,
+}
+
+impl SocketCall {
+    /// All operations, ordered by number.
+    pub const ALL: &'static [Self] = &[
+        Self::Socket,
+        Self::Bind,
+        Self::Connect,
+        Self::Listen,
+        Self::Accept,
+        Self::GetSockName,
+        Self::GetPeerName,
+        Self::SocketPair,
+        Self::Send,
+        Self::Recv,
+        Self::SendTo,
+        Self::RecvFrom,
+        Self::Shutdown,
+        Self::SetSockOpt,
+        Self::GetSockOpt,
+        Self::SendMsg,
+        Self::RecvMsg,
+        Self::Accept4,
+        Self::RecvMMsg,
+        Self::SendMMsg,
+    ];
+
+    /// Returns the operation with the given number, or `None` if the kernel
+    /// doesn't know it.
+    pub const fn new(call: usize) -> Option<Self> {
+        if call >= 1 && call <= Self::ALL.len() {
+            Some(Self::ALL[call - 1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of the operation.
+    pub const fn id(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the name of the syscall that the operation corresponds to,
+    /// such as `connect` for [`SocketCall::Connect`].
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Socket => "socket",
+            Self::Bind => "bind",
+            Self::Connect => "connect",
+            Self::Listen => "listen",
+            Self::Accept => "accept",
+            Self::GetSockName => "getsockname",
+            Self::GetPeerName => "getpeername",
+            Self::SocketPair => "socketpair",
+            Self::Send => "send",
+            Self::Recv => "recv",
+            Self::SendTo => "sendto",
+            Self::RecvFrom => "recvfrom",
+            Self::Shutdown => "shutdown",
+            Self::SetSockOpt => "setsockopt",
+            Self::GetSockOpt => "getsockopt",
+            Self::SendMsg => "sendmsg",
+            Self::RecvMsg => "recvmsg",
+            Self::Accept4 => "accept4",
+            Self::RecvMMsg => "recvmmsg",
+            Self::SendMMsg => "sendmmsg",
+        }
+    }
+
+    /// Returns the number of arguments that the kernel reads from the pointer
+    /// in the second argument of `socketcall`.
+    pub const fn arg_count(&self) -> usize {
+        match self {
+            Self::Listen | Self::Shutdown => 2,
+            Self::Socket
+            | Self::Bind
+            | Self::Connect
+            | Self::Accept
+            | Self::GetSockName
+            | Self::GetPeerName
+            | Self::SendMsg
+            | Self::RecvMsg => 3,
+            Self::SocketPair
+            | Self::Send
+            | Self::Recv
+            | Self::Accept4
+            | Self::SendMMsg => 4,
+            Self::SetSockOpt | Self::GetSockOpt | Self::RecvMMsg => 5,
+            Self::SendTo | Self::RecvFrom => 6,
+        }
+    }
+
+    /// Names of the direct syscalls, tried in order. `send` and `recv` are
+    /// `sendto` and `recvfrom` without an address and `accept` is `accept4`
+    /// without flags, which is what the kernel does too.
+    const fn direct(&self) -> &'static [&'static str] {
+        match self {
+            Self::Send => &["send", "sendto"],
+            Self::Recv => &["recv", "recvfrom"],
+            Self::Socket => &["socket"],
+            Self::Bind => &["bind"],
+            Self::Connect => &["connect"],
+            Self::Listen => &["listen"],
+            Self::Accept => &["accept", "accept4"],
+            Self::GetSockName => &["getsockname"],
+            Self::GetPeerName => &["getpeername"],
+            Self::SocketPair => &["socketpair"],
+            Self::SendTo => &["sendto"],
+            Self::RecvFrom => &["recvfrom"],
+            Self::Shutdown => &["shutdown"],
+            Self::SetSockOpt => &["setsockopt"],
+            Self::GetSockOpt => &["getsockopt"],
+            Self::SendMsg => &["sendmsg"],
+            Self::RecvMsg => &["recvmsg"],
+            Self::Accept4 => &["accept4"],
+            Self::RecvMMsg => &["recvmmsg"],
+            Self::SendMMsg => &["sendmmsg"],
+        }
+    }
+}
+
+impl fmt::Display for SocketCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An operation of `ipc(2)`, as passed in the lower 16 bits of its first
+/// argument. These are the constants in `linux/ipc.h`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(usize)]
+pub enum IpcCall {
+    /// `SEMOP`
+    SemOp = 1,
+    /// `SEMGET`
+    SemGet = 2,
+    /// `SEMCTL`
+    SemCtl = 3,
+    /// `SEMTIMEDOP`
+    SemTimedOp = 4,
+    /// `MSGSND`
+    MsgSnd = 11,
+    /// `MSGRCV`
+    MsgRcv = 12,
+    /// `MSGGET`
+    MsgGet = 13,
+    /// `MSGCTL`
+    MsgCtl = 14,
+    /// `SHMAT`
+    ShmAt = 21,
+    /// `SHMDT`
+    ShmDt = 22,
+    /// `SHMGET`
+    ShmGet = 23,
+    /// `SHMCTL`
+    ShmCtl = 24,
+}
+
+impl IpcCall {
+    /// All operations, ordered by number.
+    pub const ALL: &'static [Self] = &[
+        Self::SemOp,
+        Self::SemGet,
+        Self::SemCtl,
+        Self::SemTimedOp,
+        Self::MsgSnd,
+        Self::MsgRcv,
+        Self::MsgGet,
+        Self::MsgCtl,
+        Self::ShmAt,
+        Self::ShmDt,
+        Self::ShmGet,
+        Self::ShmCtl,
+    ];
+
+    /// Returns the operation with the given number, or `None` if the kernel
+    /// doesn't know it. The upper 16 bits, which hold the version of the
+    /// calling convention, are ignored.
+    pub const fn new(call: usize) -> Option<Self> {
+        match call & 0xffff {
+            1 => Some(Self::SemOp),
+            2 => Some(Self::SemGet),
+            3 => Some(Self::SemCtl),
+            4 => Some(Self::SemTimedOp),
+            11 => Some(Self::MsgSnd),
+            12 => Some(Self::MsgRcv),
+            13 => Some(Self::MsgGet),
+            14 => Some(Self::MsgCtl),
+            21 => Some(Self::ShmAt),
+            22 => Some(Self::ShmDt),
+            23 => Some(Self::ShmGet),
+            24 => Some(Self::ShmCtl),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the operation.
+    pub const fn id(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the name of the syscall that the operation corresponds to,
+    /// such as `shmat` for [`IpcCall::ShmAt`].
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::SemOp => "semop",
+            Self::SemGet => "semget",
+            Self::SemCtl => "semctl",
+            Self::SemTimedOp => "semtimedop",
+            Self::MsgSnd => "msgsnd",
+            Self::MsgRcv => "msgrcv",
+            Self::MsgGet => "msgget",
+            Self::MsgCtl => "msgctl",
+            Self::ShmAt => "shmat",
+            Self::ShmDt => "shmdt",
+            Self::ShmGet => "shmget",
+            Self::ShmCtl => "shmctl",
+        }
+    }
+
+    /// Names of the direct syscalls, tried in order. `semop` is `semtimedop`
+    /// without a timeout, so the size of `time_t` doesn't matter.
+    const fn direct(&self) -> &'static [&'static str] {
+        match self {
+            Self::SemOp => &["semop", "semtimedop", "semtimedop_time64"],
+            Self::SemGet => &["semget"],
+            Self::SemCtl => &["semctl"],
+            Self::SemTimedOp => &["semtimedop"],
+            Self::MsgSnd => &["msgsnd"],
+            Self::MsgRcv => &["msgrcv"],
+            Self::MsgGet => &["msgget"],
+            Self::MsgCtl => &["msgctl"],
+            Self::ShmAt => &["shmat"],
+            Self::ShmDt => &["shmdt"],
+            Self::ShmGet => &["shmget"],
+            Self::ShmCtl => &["shmctl"],
+        }
+    }
+}
+
+impl fmt::Display for IpcCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An operation of a multiplexed syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Multiplexed {
+    /// An operation of `socketcall(2)`.
+    Socket(SocketCall),
+    /// An operation of `ipc(2)`.
+    Ipc(IpcCall),
+}
+
+impl Multiplexed {
+    /// Returns the name of the syscall that the operation corresponds to.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Socket(call) => call.name(),
+            Self::Ipc(call) => call.name(),
+        }
+    }
+}
+
+impl fmt::Display for Multiplexed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A decoded call of `socketcall(2)` or `ipc(2)`. See [`decode`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Decoded<S: ArchSysno = Sysno> {
+    call: Multiplexed,
+    version: usize,
+    sysno: Option<S>,
+}
+
+impl<S: ArchSysno> Decoded<S> {
+    /// Returns the operation.
+    pub const fn call(&self) -> Multiplexed {
+        self.call
+    }
+
+    /// Returns the direct syscall that does the same thing, or `None` if the
+    /// architecture doesn't have one.
+    ///
+    /// For [`SocketCall::Send`], [`SocketCall::Recv`] and
+    /// [`SocketCall::Accept`], this may be `sendto`, `recvfrom` and `accept4`.
+    /// For [`IpcCall::SemOp`], this may be `semtimedop`.
+    pub const fn sysno(&self) -> Option<S> {
+        self.sysno
+    }
+
+    /// Returns the address and the number of words that the kernel reads
+    /// from the memory of the process to get the arguments of the operation,
+    /// or `None` if all arguments are in registers. A word has the size of a
+    /// pointer on the architecture of the syscall.
+    pub fn memory(&self, args: &SyscallArgs) -> Option<(usize, usize)> {
+        match self.call {
+            Multiplexed::Socket(call) => Some((args.arg1, call.arg_count())),
+            Multiplexed::Ipc(IpcCall::SemCtl) => Some((args.arg4, 1)),
+            Multiplexed::Ipc(IpcCall::MsgRcv) if self.version == 0 => {
+                Some((args.arg4, 2))
+            }
+            Multiplexed::Ipc(_) => None,
+        }
+    }
+
+    /// Computes the arguments of the direct syscall from the arguments of the
+    /// multiplexed syscall and the words read from memory, as described by
+    /// [`Decoded::memory`].
+    ///
+    /// Returns `None` if there is no direct syscall, if too few words are
+    /// given, or if the operation can't be expressed as a direct syscall. The
+    /// latter is the case for version 1 of `SHMAT`, which the kernel
+    /// rejects.
+    ///
+    /// Note that `SHMAT` stores the address of the segment at the pointer in
+    /// the fourth argument of `ipc` instead of returning it like `shmat`.
+    pub fn args(
+        &self,
+        args: &SyscallArgs,
+        memory: &[usize],
+    ) -> Option<SyscallArgs> {
+        self.sysno?;
+
+        match self.call {
+            Multiplexed::Socket(call) => {
+                let words = memory.get(..call.arg_count())?;
+                let mut to = [0; 6];
+                to[..words.len()].copy_from_slice(words);
+                Some(SyscallArgs::from(&to))
+            }
+            Multiplexed::Ipc(call) => self.ipc_args(call, args, memory),
+        }
+    }
+
+    fn ipc_args(
+        &self,
+        call: IpcCall,
+        args: &SyscallArgs,
+        memory: &[usize],
+    ) -> Option<SyscallArgs> {
+        let SyscallArgs {
+            arg1: first,
+            arg2: second,
+            arg3: third,
+            arg4: ptr,
+            arg5: fifth,
+            ..
+        } = *args;
+
+        let to = match call {
+            IpcCall::SemOp => [first, ptr, second, 0, 0, 0],
+            // The timeout is in the fifth argument, except on s390x where
+            // `ipc` only has five arguments.
+            IpcCall::SemTimedOp if S::Arch::ARCH == Arch::S390x => {
+                [first, ptr, second, third, 0, 0]
+            }
+            IpcCall::SemTimedOp => [first, ptr, second, fifth, 0, 0],
+            IpcCall::SemGet | IpcCall::ShmGet => {
+                [first, second, third, 0, 0, 0]
+            }
+            IpcCall::SemCtl => [first, second, third, *memory.first()?, 0, 0],
+            IpcCall::MsgSnd => [first, ptr, second, third, 0, 0],
+            IpcCall::MsgRcv if self.version == 0 => {
+                let kludge = memory.get(..2)?;
+                [first, kludge[0], second, kludge[1], third, 0]
+            }
+            IpcCall::MsgRcv => [first, ptr, second, fifth, third, 0],
+            IpcCall::MsgGet => [first, second, 0, 0, 0, 0],
+            IpcCall::MsgCtl | IpcCall::ShmCtl => [first, second, ptr, 0, 0, 0],
+            IpcCall::ShmAt if self.version != 1 => {
+                [first, ptr, second, 0, 0, 0]
+            }
+            IpcCall::ShmAt => return None,
+            IpcCall::ShmDt => [ptr, 0, 0, 0, 0, 0],
+        };
+
+        Some(SyscallArgs::from(&to))
+    }
+}
+
+/// Decodes a call of `socketcall(2)` or `ipc(2)`. Returns `None` if the
+/// syscall is neither of them or if the operation isn't known.
+///
+/// This works for the syscalls of any architecture, such as
+/// `x86::Sysno::socketcall` when tracing an i386 process on x86-64.
+pub fn decode<S: ArchSysno>(
+    sysno: S,
+    args: &SyscallArgs,
+) -> Option<Decoded<S>> {
+    let (call, direct) = if Ok(sysno) == "socketcall".parse() {
+        let call = SocketCall::new(args.arg0)?;
+        (Multiplexed::Socket(call), call.direct())
+    } else if Ok(sysno) == "ipc".parse() {
+        let call = IpcCall::new(args.arg0)?;
+        (Multiplexed::Ipc(call), call.direct())
+    } else {
+        return None;
+    };
+
+    Some(Decoded {
+        call,
+        version: args.arg0 >> 16,
+        sysno: direct.iter().find_map(|name| name.parse().ok()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        for (i, call) in SocketCall::ALL.iter().enumerate() {
+            assert_eq!(SocketCall::new(i + 1), Some(*call));
+            assert_eq!(call.id(), i + 1);
+        }
+        assert_eq!(SocketCall::new(0), None);
+        assert_eq!(SocketCall::new(21), None);
+
+        for call in IpcCall::ALL {
+            assert_eq!(IpcCall::new(call.id()), Some(*call));
+            assert_eq!(IpcCall::new(call.id() | 1 << 16), Some(*call));
+        }
+        assert_eq!(IpcCall::new(0), None);
+        assert_eq!(IpcCall::new(5), None);
+    }
+
+    #[test]
+    fn test_not_multiplexed() {
+        let args = SyscallArgs::new(1, 0, 0, 0, 0, 0);
+        assert_eq!(decode(Sysno::read, &args), None);
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_x86_socketcall() {
+        use crate::x86;
+
+        // Every operation has a direct syscall on x86.
+        for call in SocketCall::ALL {
+            let args = SyscallArgs::new(call.id(), 0x1000, 0, 0, 0, 0);
+            let decoded = decode(x86::Sysno::socketcall, &args).unwrap();
+            assert_eq!(decoded.call(), Multiplexed::Socket(*call));
+            assert!(decoded.sysno().is_some(), "{call}");
+            assert_eq!(decoded.memory(&args), Some((0x1000, call.arg_count())));
+        }
+
+        // x86 doesn't have `send`, but `sendto` without an address is the
+        // same thing.
+        let args = SyscallArgs::new(SocketCall::Send.id(), 0x1000, 0, 0, 0, 0);
+        let decoded = decode(x86::Sysno::socketcall, &args).unwrap();
+        assert_eq!(decoded.sysno(), Some(x86::Sysno::sendto));
+        assert_eq!(
+            decoded.args(&args, &[3, 0x2000, 10, 0]),
+            Some(SyscallArgs::new(3, 0x2000, 10, 0, 0, 0))
+        );
+        assert_eq!(decoded.args(&args, &[3, 0x2000]), None);
+
+        let args = SyscallArgs::new(0, 0x1000, 0, 0, 0, 0);
+        assert_eq!(decode(x86::Sysno::socketcall, &args), None);
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_x86_ipc() {
+        use crate::x86;
+
+        let ipc = |call: IpcCall, version: usize| {
+            let args =
+                SyscallArgs::new(call.id() | version << 16, 1, 2, 3, 0x1000, 5);
+            (decode(x86::Sysno::ipc, &args).unwrap(), args)
+        };
+
+        let (decoded, args) = ipc(IpcCall::ShmAt, 0);
+        assert_eq!(decoded.sysno(), Some(x86::Sysno::shmat));
+        assert_eq!(decoded.memory(&args), None);
+        assert_eq!(
+            decoded.args(&args, &[]),
+            Some(SyscallArgs::new(1, 0x1000, 2, 0, 0, 0))
+        );
+        let (decoded, args) = ipc(IpcCall::ShmAt, 1);
+        assert_eq!(decoded.args(&args, &[]), None);
+
+        // `semop` is `semtimedop` without a timeout.
+        let (decoded, args) = ipc(IpcCall::SemOp, 0);
+        assert_eq!(decoded.sysno(), Some(x86::Sysno::semtimedop_time64));
+        assert_eq!(
+            decoded.args(&args, &[]),
+            Some(SyscallArgs::new(1, 0x1000, 2, 0, 0, 0))
+        );
+
+        // The 32-bit `semtimedop` doesn't exist on x86.
+        let (decoded, args) = ipc(IpcCall::SemTimedOp, 0);
+        assert_eq!(decoded.sysno(), None);
+        assert_eq!(decoded.args(&args, &[]), None);
+
+        let (decoded, args) = ipc(IpcCall::SemCtl, 0);
+        assert_eq!(decoded.memory(&args), Some((0x1000, 1)));
+        assert_eq!(
+            decoded.args(&args, &[42]),
+            Some(SyscallArgs::new(1, 2, 3, 42, 0, 0))
+        );
+
+        let (decoded, args) = ipc(IpcCall::MsgRcv, 0);
+        assert_eq!(decoded.memory(&args), Some((0x1000, 2)));
+        assert_eq!(
+            decoded.args(&args, &[0x2000, 7]),
+            Some(SyscallArgs::new(1, 0x2000, 2, 7, 3, 0))
+        );
+        let (decoded, args) = ipc(IpcCall::MsgRcv, 1);
+        assert_eq!(decoded.memory(&args), None);
+        assert_eq!(
+            decoded.args(&args, &[]),
+            Some(SyscallArgs::new(1, 0x1000, 2, 5, 3, 0))
+        );
+    }
+
+    #[cfg(feature = "s390x")]
+    #[test]
+    fn test_s390x_semtimedop() {
+        use crate::s390x;
+
+        let args = SyscallArgs::new(IpcCall::SemTimedOp.id(), 1, 2, 3, 4, 5);
+        let decoded = decode(s390x::Sysno::ipc, &args).unwrap();
+        assert_eq!(decoded.sysno(), Some(s390x::Sysno::semtimedop));
+        assert_eq!(
+            decoded.args(&args, &[]),
+            Some(SyscallArgs::new(1, 4, 2, 3, 0, 0))
+        );
+    }
+
+    #[cfg(feature = "powerpc")]
+    #[test]
+    fn test_powerpc_send() {
+        use crate::powerpc;
+
+        let args = SyscallArgs::new(SocketCall::Send.id(), 0, 0, 0, 0, 0);
+        let decoded = decode(powerpc::Sysno::socketcall, &args).unwrap();
+        assert_eq!(decoded.sysno(), Some(powerpc::Sysno::send));
+    }
+}
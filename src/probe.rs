@@ -0,0 +1,237 @@
+//! Probing which syscalls the running kernel supports.
+//!
+//! A syscall is probed by invoking it with arguments that the kernel rejects
+//! before doing anything, such as unknown flags or a size of zero. If the
+//! kernel doesn't know the syscall, it fails with `ENOSYS` instead. If a
+//! seccomp filter (or an LSM) denies it, it usually fails with `EPERM` or
+//! `EACCES`.
+//!
+//! Only the syscalls in a curated list are probed, because most syscalls can't
+//! be invoked safely. See [`probed`] for the list. Syscalls whose arguments
+//! differ between architectures (such as `clone`) or that check for privileges
+//! before checking their arguments (such as `bpf`) aren't in it.
+//!
+//! Note that a seccomp filter may also deny a syscall with `ENOSYS` (which is
+//! reported as [`Support::Missing`]), or by killing the process or raising
+//! `SIGSYS`, which can't be detected. Don't probe in a process whose seccomp
+//! filter kills it for unknown syscalls.
+//!
+//! # Example
+//!
+//! ```
+//! use syscalls::{Sysno, SysnoSet};
+//!
+//! let supported = SysnoSet::probe_supported();
+//!
+//! let sysno = if supported.contains(Sysno::openat2) {
+//!     Sysno::openat2
+//! } else {
+//!     Sysno::openat
+//! };
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::{Errno, SyscallArgs, Sysno, SysnoMap, SysnoSet};
+
+/// The result of probing a syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Support {
+    /// The kernel supports the syscall.
+    Supported,
+    /// The kernel doesn't know the syscall. It failed with `ENOSYS`.
+    Missing,
+    /// The syscall was denied, most likely by a seccomp filter. It failed with
+    /// `EPERM` or `EACCES`.
+    Denied,
+}
+
+/// All bits set. This is an invalid flag argument for every syscall below, and
+/// `-1` as a file descriptor or a pid.
+const INVALID: usize = usize::MAX;
+
+/// A syscall and the arguments that it is probed with. Syscalls that don't
+/// exist on the native architecture are skipped.
+struct Probe {
+    name: &'static str,
+    args: [usize; 6],
+}
+
+const fn probe(name: &'static str, args: [usize; 6]) -> Probe {
+    Probe { name, args }
+}
+
+/// The probes. Each of these must fail with an error other than `ENOSYS`,
+/// `EPERM` or `EACCES` without any side effect on every kernel that has the
+/// syscall.
+const PROBES: &[Probe] = &[
+    // Unknown flags.
+    probe("accept4", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("cachestat", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("copy_file_range", [INVALID, 0, INVALID, 0, 0, INVALID]),
+    probe("dup3", [INVALID, INVALID, INVALID, 0, 0, 0]),
+    probe("epoll_create1", [INVALID, 0, 0, 0, 0, 0]),
+    probe("eventfd2", [0, INVALID, 0, 0, 0, 0]),
+    probe("faccessat2", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("fchmodat2", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("file_getattr", [INVALID, 0, 0, 0, INVALID, 0]),
+    probe("file_setattr", [INVALID, 0, 0, 0, INVALID, 0]),
+    probe("fstatat", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("fstatat64", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("futex_waitv", [0, 0, INVALID, 0, 0, 0]),
+    probe("futex_wake", [0, 0, 0, INVALID, 0, 0]),
+    probe("futex_wait", [0, 0, 0, INVALID, 0, 0]),
+    probe("getrandom", [0, 0, INVALID, 0, 0, 0]),
+    probe("getxattrat", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("inotify_init1", [INVALID, 0, 0, 0, 0, 0]),
+    probe("landlock_create_ruleset", [0, 0, INVALID, 0, 0, 0]),
+    probe("listmount", [0, 0, 0, INVALID, 0, 0]),
+    probe("listxattrat", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("lsm_list_modules", [0, 0, INVALID, 0, 0, 0]),
+    probe("membarrier", [INVALID, INVALID, 0, 0, 0, 0]),
+    probe("memfd_create", [0, INVALID, 0, 0, 0, 0]),
+    probe("memfd_secret", [INVALID, 0, 0, 0, 0, 0]),
+    probe("mlock2", [0, 0, INVALID, 0, 0, 0]),
+    probe("mount_setattr", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("mseal", [0, 0, INVALID, 0, 0, 0]),
+    probe("newfstatat", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("open_tree", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("open_tree_attr", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("pidfd_getfd", [INVALID, INVALID, INVALID, 0, 0, 0]),
+    probe("pidfd_open", [0, INVALID, 0, 0, 0, 0]),
+    probe("pidfd_send_signal", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("pipe2", [0, INVALID, 0, 0, 0, 0]),
+    probe("pkey_alloc", [INVALID, 0, 0, 0, 0, 0]),
+    probe("process_madvise", [INVALID, 0, 0, 0, INVALID, 0]),
+    probe("process_vm_readv", [0, 0, 0, 0, 0, INVALID]),
+    probe("removexattrat", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("renameat2", [INVALID, 0, INVALID, 0, INVALID, 0]),
+    probe("rseq", [0, 0, INVALID, 0, 0, 0]),
+    probe("sched_getattr", [0, 0, 0, INVALID, 0, 0]),
+    probe("sched_setattr", [0, 0, INVALID, 0, 0, 0]),
+    probe("seccomp", [INVALID, 0, 0, 0, 0, 0]),
+    probe("set_mempolicy_home_node", [0, 0, 0, INVALID, 0, 0]),
+    probe("setxattrat", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("signalfd4", [INVALID, 0, 0, INVALID, 0, 0]),
+    probe("statmount", [0, 0, 0, INVALID, 0, 0]),
+    probe("statx", [INVALID, 0, INVALID, 0, 0, 0]),
+    probe("timerfd_create", [0, INVALID, 0, 0, 0, 0]),
+    // A size that is too small.
+    probe("clone3", [0, 0, 0, 0, 0, 0]),
+    probe("openat2", [INVALID, 0, 0, 0, 0, 0]),
+    // No events.
+    probe("epoll_pwait", [INVALID, 0, 0, 0, 0, 0]),
+    probe("epoll_pwait2", [INVALID, 0, 0, 0, 0, 0]),
+    // A bad file descriptor.
+    probe("recvmmsg", [INVALID, 0, 0, 0, 0, 0]),
+    probe("sendmmsg", [INVALID, 0, 0, 0, 0, 0]),
+    probe("setns", [INVALID, 0, 0, 0, 0, 0]),
+    // A null path.
+    probe("openat", [INVALID, 0, 0, 0, 0, 0]),
+    // No parameters.
+    probe("io_uring_setup", [0, 0, 0, 0, 0, 0]),
+];
+
+/// Probes all syscalls once and returns the results.
+fn results() -> &'static SysnoMap<Support> {
+    static RESULTS: OnceLock<SysnoMap<Support>> = OnceLock::new();
+
+    RESULTS.get_or_init(|| {
+        PROBES
+            .iter()
+            .filter_map(|probe| {
+                let sysno = probe.name.parse::<Sysno>().ok()?;
+                let args = SyscallArgs::from(&probe.args);
+
+                // SAFETY: The arguments are invalid, so the kernel rejects
+                // them without doing anything.
+                let support = match unsafe { crate::syscall(sysno, &args) } {
+                    Err(Errno::ENOSYS) => Support::Missing,
+                    Err(Errno::EPERM | Errno::EACCES) => Support::Denied,
+                    _ => Support::Supported,
+                };
+
+                Some((sysno, support))
+            })
+            .collect()
+    })
+}
+
+/// Returns the result of probing the syscall, or `None` if it isn't probed.
+/// The syscalls are probed the first time this (or
+/// [`SysnoSet::probe_supported`]) is called.
+pub fn probe_syscall(sysno: Sysno) -> Option<Support> {
+    results().get(sysno).copied()
+}
+
+/// Returns the syscalls that are probed on this architecture.
+pub fn probed() -> SysnoSet {
+    PROBES
+        .iter()
+        .filter_map(|probe| probe.name.parse().ok())
+        .collect()
+}
+
+impl SysnoSet {
+    /// Probes which syscalls the running kernel supports and which aren't
+    /// denied by a seccomp filter. The result is cached, so this is cheap
+    /// after the first call.
+    ///
+    /// Only the syscalls in [`probe::probed`](crate::probe::probed) are
+    /// probed. Other syscalls aren't in the set. See the [`probe`](crate::probe)
+    /// module for the caveats.
+    pub fn probe_supported() -> Self {
+        results()
+            .iter()
+            .filter(|(_, support)| **support == Support::Supported)
+            .map(|(sysno, _)| sysno)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probes() {
+        for probe in PROBES {
+            assert_eq!(
+                PROBES.iter().filter(|p| p.name == probe.name).count(),
+                1,
+                "{} is probed twice",
+                probe.name
+            );
+        }
+
+        let probed = probed();
+        assert!(probed.contains(Sysno::openat));
+        assert!(!probed.contains(Sysno::read));
+
+        for sysno in Sysno::ALL {
+            assert_eq!(
+                probe_syscall(*sysno).is_some(),
+                probed.contains(*sysno),
+                "{sysno}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_probe_supported() {
+        let supported = SysnoSet::probe_supported();
+        assert_eq!(supported.clone().difference(&probed()), SysnoSet::EMPTY);
+
+        for sysno in probed().iter() {
+            assert_eq!(
+                supported.contains(sysno),
+                probe_syscall(sysno) == Some(Support::Supported),
+                "{sysno}"
+            );
+        }
+
+        // `openat` is as old as the tables and test processes aren't
+        // sandboxed.
+        assert_eq!(probe_syscall(Sysno::openat), Some(Support::Supported));
+    }
+}
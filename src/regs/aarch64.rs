@@ -0,0 +1,56 @@
+use super::{bad_arg, into_ret, SyscallRegs};
+use crate::aarch64::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_pt_regs` for AArch64.
+///
+/// Note that changing the syscall number at syscall entry requires the
+/// `NT_ARM_SYSTEM_CALL` register set. Writing `x8` has no effect.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    /// `x0` through `x30`.
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    fn nr(&self) -> usize {
+        self.regs[8] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.regs[8] = nr as u64;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0..=5 => self.regs[index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0..=5 => self.regs[index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        Errno::from_ret(self.regs[0] as usize)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.regs[0] = into_ret(ret) as u64;
+    }
+}
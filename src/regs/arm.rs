@@ -0,0 +1,60 @@
+use super::{bad_arg, from_ret32, into_ret, SyscallRegs};
+use crate::arm::Sysno;
+use crate::Errno;
+
+/// The index of `ARM_ORIG_r0` in [`Regs::uregs`].
+const ORIG_R0: usize = 17;
+
+/// The kernel's `struct pt_regs` for ARM (EABI).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    /// `r0` through `r15`, `cpsr`, and `orig_r0`.
+    pub uregs: [u32; 18],
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    fn nr(&self) -> usize {
+        self.uregs[7] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.uregs[7] = nr as u32;
+    }
+
+    // The first argument is saved in `orig_r0`, since `r0` is replaced by the
+    // return value.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0 => self.uregs[ORIG_R0] as usize,
+            1..=5 => self.uregs[index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0 => {
+                self.uregs[0] = value as u32;
+                self.uregs[ORIG_R0] = value as u32;
+            }
+            1..=5 => self.uregs[index] = value as u32,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        from_ret32(self.uregs[0])
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.uregs[0] = into_ret(ret) as u32;
+    }
+}
@@ -0,0 +1,60 @@
+use super::{bad_arg, into_ret, SyscallRegs};
+use crate::loongarch64::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_pt_regs` for LoongArch.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    /// `r0` through `r31`.
+    pub regs: [u64; 32],
+    pub orig_a0: u64,
+    pub csr_era: u64,
+    pub csr_badv: u64,
+    pub reserved: [u64; 10],
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `a7`.
+    fn nr(&self) -> usize {
+        self.regs[11] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.regs[11] = nr as u64;
+    }
+
+    // The arguments are in `a0` through `a5`, which are `r4` through `r9`.
+    // The kernel passes `orig_a0` as the first argument, since `a0` is
+    // replaced by the return value.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0 => self.orig_a0 as usize,
+            1..=5 => self.regs[4 + index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0 => self.orig_a0 = value as u64,
+            1..=5 => self.regs[4 + index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        Errno::from_ret(self.regs[4] as usize)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.regs[4] = into_ret(ret) as u64;
+    }
+}
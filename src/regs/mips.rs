@@ -0,0 +1,79 @@
+use super::{bad_arg, SyscallRegs};
+use crate::mips::Sysno;
+use crate::Errno;
+
+/// The registers of a 32-bit MIPS thread, as laid out by the kernel's
+/// `EF_*` indices.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub pad0: [u32; 6],
+    pub regs: [u32; 32],
+    pub lo: u32,
+    pub hi: u32,
+    pub cp0_epc: u32,
+    pub cp0_badvaddr: u32,
+    pub cp0_status: u32,
+    pub cp0_cause: u32,
+    pub unused0: u32,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The fifth and sixth arguments are on the stack at `sp + 16` and
+    // `sp + 20`.
+    const REG_ARGS: usize = 4;
+
+    // The syscall number is in `v0`.
+    fn nr(&self) -> usize {
+        self.regs[2] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.regs[2] = nr as u32;
+    }
+
+    // The first four arguments are in `a0` through `a3`, which are `r4`
+    // through `r7`.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0..=3 => self.regs[4 + index] as usize,
+            4 | 5 => 0,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0..=3 => self.regs[4 + index] = value as u32,
+            4 | 5 => {}
+            _ => bad_arg(index),
+        }
+    }
+
+    // The result is in `v0`. Errors are returned as positive values with
+    // `a3` set to 1.
+    fn ret(&self) -> Result<usize, Errno> {
+        if self.regs[7] != 0 {
+            Err(Errno::new(self.regs[2] as i32))
+        } else {
+            Ok(self.regs[2] as usize)
+        }
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        let (value, error) = match ret {
+            Ok(value) => (value as u32, 0),
+            Err(err) => (err.into_raw() as u32, 1),
+        };
+
+        self.regs[2] = value;
+        self.regs[7] = error;
+    }
+}
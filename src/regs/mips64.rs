@@ -0,0 +1,70 @@
+use super::{bad_arg, SyscallRegs};
+use crate::mips64::Sysno;
+use crate::Errno;
+
+/// The registers of a 64-bit MIPS thread, as laid out by the kernel's
+/// `EF_*` indices.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub regs: [u64; 32],
+    pub lo: u64,
+    pub hi: u64,
+    pub cp0_epc: u64,
+    pub cp0_badvaddr: u64,
+    pub cp0_status: u64,
+    pub cp0_cause: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `v0`.
+    fn nr(&self) -> usize {
+        self.regs[2] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.regs[2] = nr as u64;
+    }
+
+    // The arguments are in `a0` through `a5`, which are `r4` through `r9`.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0..=5 => self.regs[4 + index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0..=5 => self.regs[4 + index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    // The result is in `v0`. Errors are returned as positive values with
+    // `a3` set to 1.
+    fn ret(&self) -> Result<usize, Errno> {
+        if self.regs[7] != 0 {
+            Err(Errno::new(self.regs[2] as i32))
+        } else {
+            Ok(self.regs[2] as usize)
+        }
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        let (value, error) = match ret {
+            Ok(value) => (value as u64, 0),
+            Err(err) => (err.into_raw() as u64, 1),
+        };
+
+        self.regs[2] = value;
+        self.regs[7] = error;
+    }
+}
@@ -0,0 +1,14 @@
+//! The n32 ABI uses the same registers as mips64. Only the syscall numbers
+//! differ.
+
+pub use super::mips64::Regs;
+use super::SyscallRegs;
+use crate::mipsn32::Sysno;
+
+impl Regs {
+    /// Returns the syscall of an n32 process, if it's in the n32 syscall
+    /// table.
+    pub fn mipsn32_sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
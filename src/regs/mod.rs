@@ -0,0 +1,181 @@
+//! Register sets of stopped threads, as returned by `PTRACE_GETREGSET` with
+//! `NT_PRSTATUS`.
+//!
+//! Each architecture module has a `Regs` type with the same layout as the
+//! kernel's register set for that architecture. The registers that carry the
+//! syscall number, arguments, and return value are described by the
+//! [`SyscallRegs`] trait, which follows the calling conventions documented in
+//! `src/syscall/*.rs`.
+
+#[cfg(any(target_arch = "aarch64", feature = "aarch64"))]
+pub(crate) mod aarch64;
+#[cfg(any(target_arch = "arm", feature = "arm"))]
+pub(crate) mod arm;
+#[cfg(any(target_arch = "loongarch64", feature = "loongarch64"))]
+pub(crate) mod loongarch64;
+#[cfg(any(target_arch = "mips", feature = "mips"))]
+pub(crate) mod mips;
+#[cfg(any(target_arch = "mips64", feature = "mips64"))]
+pub(crate) mod mips64;
+#[cfg(any(
+    all(target_arch = "mips64", target_abi = "abin32"),
+    feature = "mipsn32"
+))]
+pub(crate) mod mipsn32;
+#[cfg(any(target_arch = "powerpc", feature = "powerpc"))]
+pub(crate) mod powerpc;
+#[cfg(any(target_arch = "powerpc64", feature = "powerpc64"))]
+pub(crate) mod powerpc64;
+#[cfg(any(target_arch = "riscv32", feature = "riscv32"))]
+pub(crate) mod riscv32;
+#[cfg(any(target_arch = "riscv64", feature = "riscv64"))]
+pub(crate) mod riscv64;
+#[cfg(any(target_arch = "s390x", feature = "s390x"))]
+pub(crate) mod s390x;
+#[cfg(any(target_arch = "sparc", feature = "sparc"))]
+pub(crate) mod sparc;
+#[cfg(any(target_arch = "sparc64", feature = "sparc64"))]
+pub(crate) mod sparc64;
+#[cfg(any(all(target_arch = "x86_64", target_abi = "x32"), feature = "x32"))]
+pub(crate) mod x32;
+#[cfg(any(target_arch = "x86", feature = "x86"))]
+pub(crate) mod x86;
+#[cfg(any(target_arch = "x86_64", feature = "x86_64"))]
+pub(crate) mod x86_64;
+
+use crate::Errno;
+
+/// A register set that carries a syscall.
+///
+/// The syscall number and arguments are only meaningful when the thread is
+/// stopped at syscall entry. The return value is only meaningful at syscall
+/// exit.
+///
+/// Use [`SyscallArgs::from_regs`] and [`SyscallArgs::write_to_regs`] to
+/// read or write all arguments at once.
+///
+/// [`SyscallArgs::from_regs`]: crate::SyscallArgs::from_regs
+/// [`SyscallArgs::write_to_regs`]: crate::SyscallArgs::write_to_regs
+pub trait SyscallRegs {
+    /// The number of arguments that are passed in registers. The remaining
+    /// arguments are passed on the stack and read as zero.
+    const REG_ARGS: usize = 6;
+
+    /// Returns the raw syscall number.
+    fn nr(&self) -> usize;
+
+    /// Sets the raw syscall number.
+    fn set_nr(&mut self, nr: usize);
+
+    /// Returns the argument with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 6.
+    fn arg(&self, index: usize) -> usize;
+
+    /// Sets the argument with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 6.
+    fn set_arg(&mut self, index: usize, value: usize);
+
+    /// Returns the return value of the syscall.
+    fn ret(&self) -> Result<usize, Errno>;
+
+    /// Sets the return value of the syscall.
+    fn set_return(&mut self, ret: Result<usize, Errno>);
+}
+
+/// Converts a result into the value that most architectures return in a
+/// single register.
+pub(crate) fn into_ret(ret: Result<usize, Errno>) -> usize {
+    match ret {
+        Ok(value) => value,
+        Err(err) => -(err.into_raw() as isize) as usize,
+    }
+}
+
+/// Same as [`Errno::from_ret`], but for the 32-bit registers of a 32-bit
+/// architecture, which may not match the host's word size.
+#[allow(dead_code)] // Unused if no 32-bit architecture is enabled.
+pub(crate) fn from_ret32(value: u32) -> Result<usize, Errno> {
+    if value > -4096i32 as u32 {
+        Err(Errno::new(-(value as i32)))
+    } else {
+        Ok(value as usize)
+    }
+}
+
+/// Panics for an argument index that is out of range.
+#[cold]
+pub(crate) fn bad_arg(index: usize) -> ! {
+    panic!("syscall argument index out of range: {index}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SyscallArgs;
+
+    fn check<R: SyscallRegs + Default>(size: usize) {
+        assert_eq!(core::mem::size_of::<R>(), size);
+
+        let mut regs = R::default();
+        let args = SyscallArgs::new(1, 2, 3, 4, 5, 6);
+
+        regs.set_nr(42);
+        args.write_to_regs(&mut regs);
+        assert_eq!(regs.nr(), 42);
+
+        let expected =
+            [1, 2, 3, 4, 5, 6].map(
+                |arg| {
+                    if arg <= R::REG_ARGS {
+                        arg
+                    } else {
+                        0
+                    }
+                },
+            );
+        assert_eq!(SyscallArgs::from_regs(&regs), SyscallArgs::from(&expected));
+
+        for ret in [Ok(0), Ok(1234), Err(Errno::ENOENT), Err(Errno::EPERM)] {
+            regs.set_return(ret);
+            assert_eq!(regs.ret(), ret);
+        }
+    }
+
+    #[test]
+    fn test_regs() {
+        #[cfg(any(target_arch = "aarch64", feature = "aarch64"))]
+        check::<crate::aarch64::Regs>(272);
+        #[cfg(any(target_arch = "arm", feature = "arm"))]
+        check::<crate::arm::Regs>(72);
+        #[cfg(any(target_arch = "loongarch64", feature = "loongarch64"))]
+        check::<crate::loongarch64::Regs>(360);
+        #[cfg(any(target_arch = "mips", feature = "mips"))]
+        check::<crate::mips::Regs>(180);
+        #[cfg(any(target_arch = "mips64", feature = "mips64"))]
+        check::<crate::mips64::Regs>(304);
+        #[cfg(any(target_arch = "powerpc", feature = "powerpc"))]
+        check::<crate::powerpc::Regs>(176);
+        #[cfg(any(target_arch = "powerpc64", feature = "powerpc64"))]
+        check::<crate::powerpc64::Regs>(352);
+        #[cfg(any(target_arch = "riscv32", feature = "riscv32"))]
+        check::<crate::riscv32::Regs>(128);
+        #[cfg(any(target_arch = "riscv64", feature = "riscv64"))]
+        check::<crate::riscv64::Regs>(256);
+        #[cfg(any(target_arch = "s390x", feature = "s390x"))]
+        check::<crate::s390x::Regs>(216);
+        #[cfg(any(target_arch = "sparc", feature = "sparc"))]
+        check::<crate::sparc::Regs>(152);
+        #[cfg(any(target_arch = "sparc64", feature = "sparc64"))]
+        check::<crate::sparc64::Regs>(288);
+        #[cfg(any(target_arch = "x86", feature = "x86"))]
+        check::<crate::x86::Regs>(68);
+        #[cfg(any(target_arch = "x86_64", feature = "x86_64"))]
+        check::<crate::x86_64::Regs>(216);
+    }
+}
@@ -0,0 +1,85 @@
+use super::{bad_arg, SyscallRegs};
+use crate::powerpc64::Sysno;
+use crate::Errno;
+
+/// The summary overflow bit of `cr0`, which is set if the syscall failed.
+const CR0_SO: u64 = 0x1000_0000;
+
+/// The kernel's `struct pt_regs` for PowerPC.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub gpr: [u64; 32],
+    pub nip: u64,
+    pub msr: u64,
+    pub orig_gpr3: u64,
+    pub ctr: u64,
+    pub link: u64,
+    pub xer: u64,
+    pub ccr: u64,
+    pub softe: u64,
+    pub trap: u64,
+    pub dar: u64,
+    pub dsisr: u64,
+    pub result: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    fn nr(&self) -> usize {
+        self.gpr[0] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.gpr[0] = nr as u64;
+    }
+
+    // The arguments are in `r3` through `r8`. The first argument is saved
+    // in `orig_gpr3`, since `r3` is replaced by the return value.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0 => self.orig_gpr3 as usize,
+            1..=5 => self.gpr[3 + index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0 => {
+                self.gpr[3] = value as u64;
+                self.orig_gpr3 = value as u64;
+            }
+            1..=5 => self.gpr[3 + index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    // Errors are returned as positive values with `CR0_SO` set.
+    fn ret(&self) -> Result<usize, Errno> {
+        if self.ccr & CR0_SO != 0 {
+            Err(Errno::new(self.gpr[3] as i32))
+        } else {
+            Ok(self.gpr[3] as usize)
+        }
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        match ret {
+            Ok(value) => {
+                self.gpr[3] = value as u64;
+                self.ccr &= !CR0_SO;
+            }
+            Err(err) => {
+                self.gpr[3] = err.into_raw() as u64;
+                self.ccr |= CR0_SO;
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::{bad_arg, from_ret32, into_ret, SyscallRegs};
+use crate::riscv32::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_regs_struct` for RISC-V.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub pc: u32,
+    pub ra: u32,
+    pub sp: u32,
+    pub gp: u32,
+    pub tp: u32,
+    pub t0: u32,
+    pub t1: u32,
+    pub t2: u32,
+    pub s0: u32,
+    pub s1: u32,
+    pub a0: u32,
+    pub a1: u32,
+    pub a2: u32,
+    pub a3: u32,
+    pub a4: u32,
+    pub a5: u32,
+    pub a6: u32,
+    pub a7: u32,
+    pub s2: u32,
+    pub s3: u32,
+    pub s4: u32,
+    pub s5: u32,
+    pub s6: u32,
+    pub s7: u32,
+    pub s8: u32,
+    pub s9: u32,
+    pub s10: u32,
+    pub s11: u32,
+    pub t3: u32,
+    pub t4: u32,
+    pub t5: u32,
+    pub t6: u32,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    fn nr(&self) -> usize {
+        self.a7 as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.a7 = nr as u32;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        (match index {
+            0 => self.a0,
+            1 => self.a1,
+            2 => self.a2,
+            3 => self.a3,
+            4 => self.a4,
+            5 => self.a5,
+            _ => bad_arg(index),
+        }) as usize
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        let value = value as u32;
+
+        match index {
+            0 => self.a0 = value,
+            1 => self.a1 = value,
+            2 => self.a2 = value,
+            3 => self.a3 = value,
+            4 => self.a4 = value,
+            5 => self.a5 = value,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        from_ret32(self.a0)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.a0 = into_ret(ret) as u32;
+    }
+}
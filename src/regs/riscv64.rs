@@ -0,0 +1,92 @@
+use super::{bad_arg, into_ret, SyscallRegs};
+use crate::riscv64::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_regs_struct` for RISC-V.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    fn nr(&self) -> usize {
+        self.a7 as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.a7 = nr as u64;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        (match index {
+            0 => self.a0,
+            1 => self.a1,
+            2 => self.a2,
+            3 => self.a3,
+            4 => self.a4,
+            5 => self.a5,
+            _ => bad_arg(index),
+        }) as usize
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        let value = value as u64;
+
+        match index {
+            0 => self.a0 = value,
+            1 => self.a1 = value,
+            2 => self.a2 = value,
+            3 => self.a3 = value,
+            4 => self.a4 = value,
+            5 => self.a5 = value,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        Errno::from_ret(self.a0 as usize)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.a0 = into_ret(ret) as u64;
+    }
+}
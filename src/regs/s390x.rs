@@ -0,0 +1,57 @@
+use super::{bad_arg, into_ret, SyscallRegs};
+use crate::s390x::Sysno;
+use crate::Errno;
+
+/// The kernel's `s390_regs` for s390x.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub psw_mask: u64,
+    pub psw_addr: u64,
+    pub gprs: [u64; 16],
+    pub acrs: [u32; 16],
+    pub orig_gpr2: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The kernel moves the syscall number into `r2` for the tracer and keeps
+    // the first argument in `orig_gpr2`.
+    fn nr(&self) -> usize {
+        self.gprs[2] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.gprs[2] = nr as u64;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0 => self.orig_gpr2 as usize,
+            1..=5 => self.gprs[2 + index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0 => self.orig_gpr2 = value as u64,
+            1..=5 => self.gprs[2 + index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        Errno::from_ret(self.gprs[2] as usize)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.gprs[2] = into_ret(ret) as u64;
+    }
+}
@@ -0,0 +1,82 @@
+use super::{bad_arg, SyscallRegs};
+use crate::sparc::Sysno;
+use crate::Errno;
+
+/// The carry bit of `psr`, which are set if the syscall failed.
+const CARRY: u32 = 0x0010_0000;
+
+/// The registers of a 32-bit SPARC thread, as returned by `PTRACE_GETREGSET`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    /// The global registers.
+    pub g: [u32; 8],
+    /// The out registers.
+    pub o: [u32; 8],
+    /// The local registers.
+    pub l: [u32; 8],
+    /// The in registers.
+    pub i: [u32; 8],
+    pub psr: u32,
+    pub pc: u32,
+    pub npc: u32,
+    pub y: u32,
+    pub wim: u32,
+    pub tbr: u32,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `g1`.
+    fn nr(&self) -> usize {
+        self.g[1] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.g[1] = nr as u32;
+    }
+
+    // The arguments are in `o0` through `o5`.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0..=5 => self.o[index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0..=5 => self.o[index] = value as u32,
+            _ => bad_arg(index),
+        }
+    }
+
+    // The result is in `o0`. Errors are returned as positive values with
+    // the carry set.
+    fn ret(&self) -> Result<usize, Errno> {
+        if self.psr & CARRY != 0 {
+            Err(Errno::new(self.o[0] as i32))
+        } else {
+            Ok(self.o[0] as usize)
+        }
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        match ret {
+            Ok(value) => {
+                self.o[0] = value as u32;
+                self.psr &= !CARRY;
+            }
+            Err(err) => {
+                self.o[0] = err.into_raw() as u32;
+                self.psr |= CARRY;
+            }
+        }
+    }
+}
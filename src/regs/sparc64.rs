@@ -0,0 +1,80 @@
+use super::{bad_arg, SyscallRegs};
+use crate::sparc64::Sysno;
+use crate::Errno;
+
+/// The integer and extended carry bits of `tstate`, which are set if the syscall failed.
+const CARRY: u64 = (1 << 32) | (1 << 36);
+
+/// The registers of a 64-bit SPARC thread, as returned by `PTRACE_GETREGSET`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    /// The global registers.
+    pub g: [u64; 8],
+    /// The out registers.
+    pub o: [u64; 8],
+    /// The local registers.
+    pub l: [u64; 8],
+    /// The in registers.
+    pub i: [u64; 8],
+    pub tstate: u64,
+    pub tpc: u64,
+    pub tnpc: u64,
+    pub y: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `g1`.
+    fn nr(&self) -> usize {
+        self.g[1] as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.g[1] = nr as u64;
+    }
+
+    // The arguments are in `o0` through `o5`.
+    fn arg(&self, index: usize) -> usize {
+        match index {
+            0..=5 => self.o[index] as usize,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        match index {
+            0..=5 => self.o[index] = value as u64,
+            _ => bad_arg(index),
+        }
+    }
+
+    // The result is in `o0`. Errors are returned as positive values with
+    // the carry set.
+    fn ret(&self) -> Result<usize, Errno> {
+        if self.tstate & CARRY != 0 {
+            Err(Errno::new(self.o[0] as i32))
+        } else {
+            Ok(self.o[0] as usize)
+        }
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        match ret {
+            Ok(value) => {
+                self.o[0] = value as u64;
+                self.tstate &= !CARRY;
+            }
+            Err(err) => {
+                self.o[0] = err.into_raw() as u64;
+                self.tstate |= CARRY;
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! The x32 ABI uses the same registers as x86-64. Only the syscall numbers
+//! differ, since they have [`X32_SYSCALL_BIT`] set.
+//!
+//! [`X32_SYSCALL_BIT`]: crate::seccomp::X32_SYSCALL_BIT
+
+pub use super::x86_64::Regs;
+use super::SyscallRegs;
+use crate::x32::Sysno;
+
+impl Regs {
+    /// Returns the syscall of an x32 process, if it's in the x32 syscall
+    /// table.
+    pub fn x32_sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
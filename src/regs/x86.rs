@@ -0,0 +1,79 @@
+use super::{bad_arg, from_ret32, into_ret, SyscallRegs};
+use crate::x86::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_regs_struct` for i386.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `eax` on entry, but the kernel saves it in
+    // `orig_eax` before replacing `eax` with `-ENOSYS`.
+    fn nr(&self) -> usize {
+        self.orig_eax as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.orig_eax = nr as u32;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        (match index {
+            0 => self.ebx,
+            1 => self.ecx,
+            2 => self.edx,
+            3 => self.esi,
+            4 => self.edi,
+            5 => self.ebp,
+            _ => bad_arg(index),
+        }) as usize
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        let value = value as u32;
+
+        match index {
+            0 => self.ebx = value,
+            1 => self.ecx = value,
+            2 => self.edx = value,
+            3 => self.esi = value,
+            4 => self.edi = value,
+            5 => self.ebp = value,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        from_ret32(self.eax)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.eax = into_ret(ret) as u32;
+    }
+}
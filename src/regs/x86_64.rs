@@ -0,0 +1,89 @@
+use super::{bad_arg, into_ret, SyscallRegs};
+use crate::x86_64::Sysno;
+use crate::Errno;
+
+/// The kernel's `struct user_regs_struct` for x86-64.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Regs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+impl Regs {
+    /// Returns the syscall, if it's in the syscall table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        Sysno::new(self.nr())
+    }
+}
+
+impl SyscallRegs for Regs {
+    // The syscall number is in `rax` on entry, but the kernel saves it in
+    // `orig_rax` before replacing `rax` with `-ENOSYS`.
+    fn nr(&self) -> usize {
+        self.orig_rax as usize
+    }
+
+    fn set_nr(&mut self, nr: usize) {
+        self.orig_rax = nr as u64;
+    }
+
+    fn arg(&self, index: usize) -> usize {
+        (match index {
+            0 => self.rdi,
+            1 => self.rsi,
+            2 => self.rdx,
+            3 => self.r10,
+            4 => self.r8,
+            5 => self.r9,
+            _ => bad_arg(index),
+        }) as usize
+    }
+
+    fn set_arg(&mut self, index: usize, value: usize) {
+        let value = value as u64;
+
+        match index {
+            0 => self.rdi = value,
+            1 => self.rsi = value,
+            2 => self.rdx = value,
+            3 => self.r10 = value,
+            4 => self.r8 = value,
+            5 => self.r9 = value,
+            _ => bad_arg(index),
+        }
+    }
+
+    fn ret(&self) -> Result<usize, Errno> {
+        Errno::from_ret(self.rax as usize)
+    }
+
+    fn set_return(&mut self, ret: Result<usize, Errno>) {
+        self.rax = into_ret(ret) as u64;
+    }
+}
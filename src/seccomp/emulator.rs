@@ -0,0 +1,454 @@
+use core::mem::size_of;
+
+use super::bpf::*;
+use super::SeccompData;
+use crate::Errno;
+
+/// The bit of an `AUDIT_ARCH_*` value that is set for little-endian
+/// architectures.
+const AUDIT_ARCH_LE: u32 = 0x4000_0000;
+
+/// Runs a seccomp BPF program against the given syscall data and returns the
+/// resulting `SECCOMP_RET_*` value. Use [`Action::from_ret`] to decode it.
+///
+/// This doesn't load the program into the kernel, so it can be used to test a
+/// filter on any host architecture. The program is validated first with the
+/// same rules that the kernel uses when a filter is installed. If the program
+/// is invalid, this returns `EINVAL`, just like the kernel would.
+///
+/// The syscall data can describe a syscall from any architecture, such as
+/// `syscalls::aarch64::Sysno::openat.id()` with [`AUDIT_ARCH_AARCH64`]. The
+/// halves of the 64-bit instruction pointer and arguments are loaded in the
+/// byte order of that architecture, as given by its `AUDIT_ARCH_*` value,
+/// rather than the byte order of the host.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::seccomp::{self, Action, SeccompData, AUDIT_ARCH};
+/// use syscalls::{Errno, Sysno, SysnoMap, SyscallArgs};
+///
+/// let rules = SysnoMap::from_iter([(Sysno::openat, Action::Errno(Errno::EACCES))]);
+/// let program = seccomp::compile(&rules, Action::Allow);
+///
+/// let args = SyscallArgs::from(&[0, 0, 0]);
+/// let data = SeccompData::new(Sysno::openat.id(), AUDIT_ARCH, &args);
+/// let ret = seccomp::emulate(&program, &data).unwrap();
+/// assert_eq!(Action::from_ret(ret), Some(Action::Errno(Errno::EACCES)));
+/// ```
+///
+/// [`Action::from_ret`]: super::Action::from_ret
+/// [`AUDIT_ARCH_AARCH64`]: super::AUDIT_ARCH_AARCH64
+pub fn emulate(
+    program: &[SockFilter],
+    data: &SeccompData,
+) -> Result<u32, Errno> {
+    validate(program)?;
+
+    let mut a: u32 = 0;
+    let mut x: u32 = 0;
+    let mut mem = [0u32; BPF_MEMWORDS];
+    let mut pc = 0;
+
+    // Validation guarantees that every path ends in a `ret` and that jumps
+    // stay in bounds, so this always terminates.
+    loop {
+        let insn = program[pc];
+        let k = insn.k;
+        pc += 1;
+
+        match insn.code {
+            code if code == BPF_LD | BPF_W | BPF_ABS => a = load(data, k),
+            code if code == BPF_LD | BPF_W | BPF_LEN => {
+                a = size_of::<SeccompData>() as u32;
+            }
+            code if code == BPF_LDX | BPF_W | BPF_LEN => {
+                x = size_of::<SeccompData>() as u32;
+            }
+            code if code == BPF_LD | BPF_IMM => a = k,
+            code if code == BPF_LDX | BPF_IMM => x = k,
+            code if code == BPF_LD | BPF_MEM => a = mem[k as usize],
+            code if code == BPF_LDX | BPF_MEM => x = mem[k as usize],
+            BPF_ST => mem[k as usize] = a,
+            BPF_STX => mem[k as usize] = x,
+            code if code == BPF_MISC | BPF_TAX => x = a,
+            code if code == BPF_MISC | BPF_TXA => a = x,
+            code if code == BPF_RET | BPF_K => return Ok(k),
+            code if code == BPF_RET | BPF_A => return Ok(a),
+            code if code == BPF_JMP | BPF_JA => pc += k as usize,
+            code if code & 0x07 == BPF_ALU => {
+                let operand = if code & BPF_X != 0 { x } else { k };
+
+                a = match code & 0xf0 {
+                    BPF_ADD => a.wrapping_add(operand),
+                    BPF_SUB => a.wrapping_sub(operand),
+                    BPF_MUL => a.wrapping_mul(operand),
+                    BPF_DIV if operand == 0 => return Ok(0),
+                    BPF_DIV => a / operand,
+                    BPF_MOD if operand == 0 => return Ok(0),
+                    BPF_MOD => a % operand,
+                    BPF_OR => a | operand,
+                    BPF_AND => a & operand,
+                    BPF_LSH => a.wrapping_shl(operand),
+                    BPF_RSH => a.wrapping_shr(operand),
+                    BPF_NEG => a.wrapping_neg(),
+                    BPF_XOR => a ^ operand,
+                    _ => unreachable!(),
+                };
+            }
+            code => {
+                // Only conditional jumps are left after validation.
+                let operand = if code & BPF_X != 0 { x } else { k };
+
+                let cond = match code & 0xf0 {
+                    BPF_JEQ => a == operand,
+                    BPF_JGT => a > operand,
+                    BPF_JGE => a >= operand,
+                    BPF_JSET => a & operand != 0,
+                    _ => unreachable!(),
+                };
+
+                pc += usize::from(if cond { insn.jt } else { insn.jf });
+            }
+        }
+    }
+}
+
+/// Loads a 32-bit word from the seccomp data at the given byte offset. The
+/// offset must already be validated.
+fn load(data: &SeccompData, offset: u32) -> u32 {
+    // The kernel copies the instruction pointer and the arguments as 64-bit
+    // values in the byte order of the architecture that made the syscall.
+    let little_endian = data.arch & AUDIT_ARCH_LE != 0;
+    let half = |value: u64| {
+        if offset.is_multiple_of(8) == little_endian {
+            value as u32
+        } else {
+            (value >> 32) as u32
+        }
+    };
+
+    match offset {
+        0 => data.nr as u32,
+        4 => data.arch,
+        8 | 12 => half(data.instruction_pointer),
+        _ => half(data.args[(offset as usize - 16) / 8]),
+    }
+}
+
+/// Checks that the program is one the kernel would accept as a seccomp filter.
+/// This includes the check that every scratch memory word is stored on all
+/// paths before it is loaded.
+fn validate(program: &[SockFilter]) -> Result<(), Errno> {
+    if program.is_empty() || program.len() > BPF_MAXINSNS {
+        return Err(Errno::EINVAL);
+    }
+
+    for (pc, insn) in program.iter().enumerate() {
+        // Number of instructions remaining after this one.
+        let remaining = program.len() - pc - 1;
+        let k = insn.k;
+
+        let valid = match insn.code {
+            code if code == BPF_LD | BPF_W | BPF_ABS => {
+                k % 4 == 0 && (k as usize) < size_of::<SeccompData>()
+            }
+            code if code == BPF_LD | BPF_W | BPF_LEN
+                || code == BPF_LDX | BPF_W | BPF_LEN
+                || code == BPF_LD | BPF_IMM
+                || code == BPF_LDX | BPF_IMM
+                || code == BPF_MISC | BPF_TAX
+                || code == BPF_MISC | BPF_TXA
+                || code == BPF_RET | BPF_K
+                || code == BPF_RET | BPF_A =>
+            {
+                true
+            }
+            code if code == BPF_LD | BPF_MEM
+                || code == BPF_LDX | BPF_MEM
+                || code == BPF_ST
+                || code == BPF_STX =>
+            {
+                (k as usize) < BPF_MEMWORDS
+            }
+            code if code & 0x07 == BPF_ALU => match code & !BPF_X & !0x07 {
+                BPF_ADD | BPF_SUB | BPF_MUL | BPF_OR | BPF_AND | BPF_XOR => {
+                    true
+                }
+                BPF_DIV | BPF_MOD => code & BPF_X != 0 || k != 0,
+                BPF_LSH | BPF_RSH => code & BPF_X != 0 || k < 32,
+                BPF_NEG => code & BPF_X == 0,
+                _ => false,
+            },
+            code if code == BPF_JMP | BPF_JA => (k as usize) < remaining,
+            code if code & 0x07 == BPF_JMP => {
+                matches!(
+                    code & !BPF_X & !0x07,
+                    BPF_JEQ | BPF_JGT | BPF_JGE | BPF_JSET
+                ) && usize::from(insn.jt) < remaining
+                    && usize::from(insn.jf) < remaining
+            }
+            _ => false,
+        };
+
+        if !valid {
+            return Err(Errno::EINVAL);
+        }
+    }
+
+    // The program must end with a `ret`. Otherwise, it could fall off the
+    // end.
+    match program[program.len() - 1].code {
+        code if code == BPF_RET | BPF_K || code == BPF_RET | BPF_A => {}
+        _ => return Err(Errno::EINVAL),
+    }
+
+    validate_memory(program)
+}
+
+/// Checks that no scratch memory word can be loaded before it is stored, the
+/// same way as `check_load_and_stores` in the kernel. Since jumps only go
+/// forward, a single pass is enough. The jumps must already be validated.
+fn validate_memory(program: &[SockFilter]) -> Result<(), Errno> {
+    // The words that are stored on every path to each instruction. Each bit
+    // is one word.
+    let mut masks = vec![u16::MAX; program.len()];
+    let mut valid: u16 = 0;
+
+    for (pc, insn) in program.iter().enumerate() {
+        valid &= masks[pc];
+        let k = insn.k;
+
+        match insn.code {
+            BPF_ST | BPF_STX => valid |= 1 << k,
+            code if (code == BPF_LD | BPF_MEM || code == BPF_LDX | BPF_MEM)
+                && valid & (1 << k) == 0 =>
+            {
+                return Err(Errno::EINVAL);
+            }
+            code if code == BPF_JMP | BPF_JA => {
+                masks[pc + 1 + k as usize] &= valid;
+                valid = u16::MAX;
+            }
+            code if code & 0x07 == BPF_JMP => {
+                masks[pc + 1 + usize::from(insn.jt)] &= valid;
+                masks[pc + 1 + usize::from(insn.jf)] &= valid;
+                valid = u16::MAX;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seccomp::{
+        compile, Action, AUDIT_ARCH, AUDIT_ARCH_I386, AUDIT_ARCH_X86_64,
+        SECCOMP_RET_ALLOW, SECCOMP_RET_KILL_PROCESS,
+    };
+    use crate::{SyscallArgs, Sysno, SysnoMap};
+
+    fn data(sysno: Sysno, args: &[usize; 6]) -> SeccompData {
+        SeccompData::new(sysno.id(), AUDIT_ARCH, &SyscallArgs::from(args))
+    }
+
+    #[test]
+    fn test_emulate_compiled() {
+        let rules = SysnoMap::from_iter([
+            (Sysno::read, Action::Allow),
+            (Sysno::write, Action::Allow),
+            (Sysno::close, Action::Errno(Errno::EPERM)),
+            (Sysno::exit_group, Action::Trap(1)),
+        ]);
+
+        let program = compile(&rules, Action::KillThread);
+
+        let run = |sysno| {
+            Action::from_ret(emulate(&program, &data(sysno, &[0; 6])).unwrap())
+        };
+
+        assert_eq!(run(Sysno::read), Some(Action::Allow));
+        assert_eq!(run(Sysno::write), Some(Action::Allow));
+        assert_eq!(run(Sysno::close), Some(Action::Errno(Errno::EPERM)));
+        assert_eq!(run(Sysno::exit_group), Some(Action::Trap(1)));
+        assert_eq!(run(Sysno::openat), Some(Action::KillThread));
+    }
+
+    #[test]
+    fn test_emulate_wrong_arch() {
+        let program = compile(&SysnoMap::<Action>::new(), Action::Allow);
+
+        let mut data = data(Sysno::read, &[0; 6]);
+        assert_eq!(emulate(&program, &data), Ok(SECCOMP_RET_ALLOW));
+
+        data.arch = if AUDIT_ARCH == AUDIT_ARCH_I386 {
+            AUDIT_ARCH_X86_64
+        } else {
+            AUDIT_ARCH_I386
+        };
+        assert_eq!(emulate(&program, &data), Ok(SECCOMP_RET_KILL_PROCESS));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_emulate_other_x86_64_abi() {
+        use crate::seccomp::X32_SYSCALL_BIT;
+
+        let program = compile(&SysnoMap::<Action>::new(), Action::Allow);
+
+        // The x86-64 and x32 ABIs can only be told apart by the syscall
+        // number.
+        let mut data = data(Sysno::read, &[0; 6]);
+        data.nr ^= X32_SYSCALL_BIT as i32;
+        assert_eq!(emulate(&program, &data), Ok(SECCOMP_RET_KILL_PROCESS));
+    }
+
+    #[test]
+    fn test_emulate_args() {
+        // Allows `write` only to stderr.
+        let program = [
+            SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, 0),
+            SockFilter::jump(
+                BPF_JMP | BPF_JEQ | BPF_K,
+                Sysno::write.id() as u32,
+                0,
+                3,
+            ),
+            SockFilter::stmt(
+                BPF_LD | BPF_W | BPF_ABS,
+                if cfg!(target_endian = "little") {
+                    16
+                } else {
+                    20
+                },
+            ),
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, 2, 0, 1),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+        ];
+
+        assert_eq!(
+            emulate(&program, &data(Sysno::write, &[2, 0, 0, 0, 0, 0])),
+            Ok(SECCOMP_RET_ALLOW)
+        );
+        assert_eq!(
+            emulate(&program, &data(Sysno::write, &[1, 0, 0, 0, 0, 0])),
+            Ok(SECCOMP_RET_KILL_PROCESS)
+        );
+    }
+
+    #[test]
+    fn test_emulate_alu() {
+        let program = [
+            SockFilter::stmt(BPF_LD | BPF_IMM, 6),
+            SockFilter::stmt(BPF_LDX | BPF_IMM, 7),
+            SockFilter::stmt(BPF_ALU | BPF_MUL | BPF_X, 0),
+            SockFilter::stmt(BPF_ST, 3),
+            SockFilter::stmt(BPF_LD | BPF_IMM, 0),
+            SockFilter::stmt(BPF_LDX | BPF_MEM, 3),
+            SockFilter::stmt(BPF_MISC | BPF_TXA, 0),
+            SockFilter::stmt(BPF_ALU | BPF_SUB | BPF_K, 2),
+            SockFilter::stmt(BPF_RET | BPF_A, 0),
+        ];
+
+        assert_eq!(emulate(&program, &data(Sysno::read, &[0; 6])), Ok(40));
+    }
+
+    #[test]
+    fn test_emulate_invalid() {
+        let data = data(Sysno::read, &[0; 6]);
+        let ret = SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW);
+
+        // Empty program.
+        assert_eq!(emulate(&[], &data), Err(Errno::EINVAL));
+
+        // Doesn't end with a `ret`.
+        assert_eq!(
+            emulate(&[SockFilter::stmt(BPF_LD | BPF_IMM, 0)], &data),
+            Err(Errno::EINVAL)
+        );
+
+        // Out of bounds or unaligned loads.
+        for k in [64, 2] {
+            let program = [SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, k), ret];
+            assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+        }
+
+        // Jump out of bounds.
+        let program =
+            [SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, 0, 1, 0), ret];
+        assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+
+        // Division by zero.
+        let program = [SockFilter::stmt(BPF_ALU | BPF_DIV | BPF_K, 0), ret];
+        assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+
+        // Packet loads aren't allowed in seccomp filters.
+        let program = [SockFilter::stmt(BPF_LD | BPF_B | BPF_ABS, 0), ret];
+        assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+
+        // Scratch memory that was never stored.
+        let program = [SockFilter::stmt(BPF_LD | BPF_MEM, 0), ret];
+        assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+    }
+
+    #[test]
+    fn test_emulate_uninitialized_memory() {
+        let data = data(Sysno::read, &[0; 6]);
+        let ret = SockFilter::stmt(BPF_RET | BPF_A, 0);
+
+        // The word is only stored if the jump isn't taken.
+        let program = [
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, 0, 1, 0),
+            SockFilter::stmt(BPF_ST, 1),
+            SockFilter::stmt(BPF_LD | BPF_MEM, 1),
+            ret,
+        ];
+        assert_eq!(emulate(&program, &data), Err(Errno::EINVAL));
+
+        // Stored on both paths.
+        let program = [
+            SockFilter::stmt(BPF_LD | BPF_IMM, 7),
+            SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, 0, 2, 0),
+            SockFilter::stmt(BPF_ST, 1),
+            SockFilter::stmt(BPF_JMP | BPF_JA, 1),
+            SockFilter::stmt(BPF_ST, 1),
+            SockFilter::stmt(BPF_LD | BPF_MEM, 1),
+            ret,
+        ];
+        assert_eq!(emulate(&program, &data), Ok(7));
+    }
+
+    #[test]
+    fn test_emulate_foreign_endian() {
+        use crate::seccomp::{AUDIT_ARCH_AARCH64, AUDIT_ARCH_S390X};
+
+        // Returns the word at the given offset.
+        let program = |offset| {
+            [
+                SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, offset),
+                SockFilter::stmt(BPF_RET | BPF_A, 0),
+            ]
+        };
+
+        let args = SyscallArgs::from(&[0; 6]);
+        let mut data = SeccompData::new(0, AUDIT_ARCH_S390X, &args);
+        data.instruction_pointer = 0x5555_6666_7777_8888;
+        data.args[0] = 0x1111_2222_3333_4444;
+
+        // The byte order comes from the arch, not from the host.
+        assert_eq!(emulate(&program(8), &data), Ok(0x5555_6666));
+        assert_eq!(emulate(&program(12), &data), Ok(0x7777_8888));
+        assert_eq!(emulate(&program(16), &data), Ok(0x1111_2222));
+        assert_eq!(emulate(&program(20), &data), Ok(0x3333_4444));
+
+        data.arch = AUDIT_ARCH_AARCH64;
+        assert_eq!(emulate(&program(8), &data), Ok(0x7777_8888));
+        assert_eq!(emulate(&program(12), &data), Ok(0x5555_6666));
+        assert_eq!(emulate(&program(16), &data), Ok(0x3333_4444));
+        assert_eq!(emulate(&program(20), &data), Ok(0x1111_2222));
+    }
+}
@@ -0,0 +1,284 @@
+use core::mem::offset_of;
+
+use super::bpf::*;
+use super::{Action, SeccompData, SECCOMP_RET_KILL_PROCESS, X32_SYSCALL_BIT};
+use crate::{Arch, ArchSysno, Errno, SyscallArch, Sysno, SysnoMap};
+
+const PR_SET_SECCOMP: usize = 22;
+const PR_SET_NO_NEW_PRIVS: usize = 38;
+const SECCOMP_MODE_FILTER: usize = 2;
+const SECCOMP_SET_MODE_FILTER: usize = 1;
+
+/// Compiles a map of syscalls to actions into a BPF program that can be
+/// installed as a seccomp filter. Syscalls that aren't in the map get the
+/// `default` action.
+///
+/// The program first checks that the syscall was made with the architecture of
+/// the syscall table (see [`SyscallArch::AUDIT_ARCH`]) and kills the process if
+/// it wasn't. Otherwise, a syscall with a different architecture could bypass
+/// the filter by reusing a syscall number that is allowed. To filter the
+/// syscalls of a compat ABI, such as i386 on x86-64, compile a map of its table
+/// (e.g. `SysnoMap<Action, x86::Sysno>`).
+///
+/// Consecutive syscalls with the same action are checked as a single range, so
+/// the size of the program is at most linear in the number of rules.
+pub fn compile<S: ArchSysno>(
+    rules: &SysnoMap<Action, S>,
+    default: Action,
+) -> Vec<SockFilter> {
+    let mut program = vec![
+        SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            offset_of!(SeccompData, arch) as u32,
+        ),
+        SockFilter::jump(
+            BPF_JMP | BPF_JEQ | BPF_K,
+            <S::Arch as SyscallArch>::AUDIT_ARCH,
+            1,
+            0,
+        ),
+        SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+        SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            offset_of!(SeccompData, nr) as u32,
+        ),
+    ];
+
+    // x86-64 and x32 syscalls share `AUDIT_ARCH_X86_64`, so the other ABI
+    // has to be rejected by its syscall number.
+    match <S::Arch as SyscallArch>::ARCH {
+        Arch::X86_64 => program.extend([
+            SockFilter::jump(BPF_JMP | BPF_JGE | BPF_K, X32_SYSCALL_BIT, 0, 1),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+        ]),
+        Arch::X32 => program.extend([
+            SockFilter::jump(BPF_JMP | BPF_JGE | BPF_K, X32_SYSCALL_BIT, 1, 0),
+            SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS),
+        ]),
+        _ => {}
+    }
+
+    for (first, last, action) in ranges(rules, default) {
+        let ret = SockFilter::stmt(BPF_RET | BPF_K, action.to_ret());
+
+        if first == last {
+            program.extend([
+                SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, first, 0, 1),
+                ret,
+            ]);
+        } else {
+            program.extend([
+                SockFilter::jump(BPF_JMP | BPF_JGT | BPF_K, last, 2, 0),
+                SockFilter::jump(BPF_JMP | BPF_JGE | BPF_K, first, 0, 1),
+                ret,
+            ]);
+        }
+    }
+
+    program.push(SockFilter::stmt(BPF_RET | BPF_K, default.to_ret()));
+
+    program
+}
+
+/// Groups consecutive syscall numbers with the same action into inclusive
+/// ranges. Syscalls with the default action are left out.
+fn ranges<S: ArchSysno>(
+    rules: &SysnoMap<Action, S>,
+    default: Action,
+) -> Vec<(u32, u32, Action)> {
+    let mut ranges: Vec<(u32, u32, Action)> = Vec::new();
+
+    for (sysno, &action) in rules {
+        if action == default {
+            continue;
+        }
+
+        let id = sysno.id() as u32;
+
+        match ranges.last_mut() {
+            Some((_, last, prev)) if *last + 1 == id && *prev == action => {
+                *last = id;
+            }
+            _ => ranges.push((id, id, action)),
+        }
+    }
+
+    ranges
+}
+
+/// Installs a seccomp filter for the calling thread. This also sets
+/// `PR_SET_NO_NEW_PRIVS`, which is required for unprivileged processes to
+/// install a filter.
+///
+/// Once installed, a filter cannot be removed.
+pub fn install(program: &[SockFilter]) -> Result<(), Errno> {
+    install_with_flags(program, 0).map(drop)
+}
+
+/// Installs a seccomp filter with the given `SECCOMP_FILTER_FLAG_*` flags. See
+/// [`install`] for more info.
+///
+/// Returns the value returned by `seccomp(2)`. For example, this is a file
+/// descriptor if [`SECCOMP_FILTER_FLAG_NEW_LISTENER`] is used.
+///
+/// [`SECCOMP_FILTER_FLAG_NEW_LISTENER`]: super::SECCOMP_FILTER_FLAG_NEW_LISTENER
+pub fn install_with_flags(
+    program: &[SockFilter],
+    flags: u32,
+) -> Result<usize, Errno> {
+    if program.len() > BPF_MAXINSNS {
+        return Err(Errno::EINVAL);
+    }
+
+    let prog = SockFprog {
+        len: program.len() as u16,
+        filter: program.as_ptr(),
+    };
+
+    // SAFETY: `prog` points to a valid program that outlives the syscalls.
+    // The kernel copies the program, so it doesn't need to live any longer.
+    unsafe {
+        syscall!(Sysno::prctl, PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0)?;
+
+        match syscall!(
+            Sysno::seccomp,
+            SECCOMP_SET_MODE_FILTER,
+            flags,
+            &prog as *const SockFprog
+        ) {
+            // Kernels older than 3.17 don't have seccomp(2), but can still
+            // install a filter without any flags through prctl(2).
+            Err(Errno::ENOSYS) if flags == 0 => syscall!(
+                Sysno::prctl,
+                PR_SET_SECCOMP,
+                SECCOMP_MODE_FILTER,
+                &prog as *const SockFprog
+            ),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seccomp::{AUDIT_ARCH, SECCOMP_RET_ALLOW, SECCOMP_RET_ERRNO};
+
+    #[test]
+    fn test_ranges() {
+        let rules = SysnoMap::from_iter([
+            (Sysno::read, Action::Allow),
+            (Sysno::write, Action::Allow),
+            (Sysno::close, Action::Errno(Errno::EPERM)),
+            (Sysno::getpid, Action::KillProcess),
+        ]);
+
+        let ranges = ranges(&rules, Action::KillProcess);
+
+        // `read` and `write` are next to each other on all architectures.
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(
+            ranges[0],
+            (
+                Sysno::read.id() as u32,
+                Sysno::write.id() as u32,
+                Action::Allow
+            )
+        );
+        assert!(ranges.contains(&(
+            Sysno::close.id() as u32,
+            Sysno::close.id() as u32,
+            Action::Errno(Errno::EPERM)
+        )));
+    }
+
+    #[test]
+    fn test_compile() {
+        let rules = SysnoMap::from_iter([
+            (Sysno::getpid, Action::Errno(Errno::EPERM)),
+            (Sysno::close, Action::Allow),
+        ]);
+
+        let program = compile(&rules, Action::Allow);
+
+        // The arch check comes first.
+        assert_eq!(program[1].k, AUDIT_ARCH);
+        assert_eq!(program[2].k, SECCOMP_RET_KILL_PROCESS);
+
+        // Syscalls with the default action don't need to be checked.
+        assert!(!program.iter().any(|insn| {
+            insn.code == BPF_JMP | BPF_JEQ | BPF_K
+                && insn.k == Sysno::close.id() as u32
+        }));
+
+        assert!(program.contains(&SockFilter::jump(
+            BPF_JMP | BPF_JEQ | BPF_K,
+            Sysno::getpid.id() as u32,
+            0,
+            1
+        )));
+        assert!(program.contains(&SockFilter::stmt(
+            BPF_RET | BPF_K,
+            SECCOMP_RET_ERRNO | 1
+        )));
+
+        assert_eq!(
+            program.last(),
+            Some(&SockFilter::stmt(BPF_RET | BPF_K, SECCOMP_RET_ALLOW))
+        );
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_compile_x86() {
+        use crate::seccomp::{emulate, AUDIT_ARCH_I386};
+        use crate::x86;
+
+        let rules =
+            SysnoMap::from_iter([(x86::Sysno::socketcall, Action::Allow)]);
+        let program = compile(&rules, Action::Errno(Errno::EPERM));
+
+        // The arch check uses the arch of the table, not the native one, and
+        // there is no x32 check.
+        assert_eq!(program[1].k, AUDIT_ARCH_I386);
+        assert!(!program.iter().any(|insn| insn.k == X32_SYSCALL_BIT));
+
+        let run = |nr: i32, arch| {
+            let args = crate::SyscallArgs::from(&[0; 6]);
+            emulate(&program, &SeccompData::new(nr, arch, &args)).unwrap()
+        };
+
+        let socketcall = x86::Sysno::socketcall.id();
+        assert_eq!(run(socketcall, AUDIT_ARCH_I386), SECCOMP_RET_ALLOW);
+        assert_eq!(run(1, AUDIT_ARCH_I386), SECCOMP_RET_ERRNO | 1);
+        if AUDIT_ARCH != AUDIT_ARCH_I386 {
+            assert_eq!(run(socketcall, AUDIT_ARCH), SECCOMP_RET_KILL_PROCESS);
+        }
+    }
+
+    #[cfg(feature = "x32")]
+    #[test]
+    fn test_compile_x32() {
+        use crate::seccomp::{emulate, AUDIT_ARCH_X86_64};
+        use crate::{x32, x86_64};
+
+        let program = compile(
+            &SysnoMap::<_, x32::Sysno>::from_iter([(
+                x32::Sysno::read,
+                Action::Errno(Errno::EPERM),
+            )]),
+            Action::Allow,
+        );
+
+        let run = |nr: i32| {
+            let args = crate::SyscallArgs::from(&[0; 6]);
+            let data = SeccompData::new(nr, AUDIT_ARCH_X86_64, &args);
+            emulate(&program, &data).unwrap()
+        };
+
+        // x86-64 syscalls are rejected even though they share the arch.
+        assert_eq!(run(x32::Sysno::read.id()), SECCOMP_RET_ERRNO | 1);
+        assert_eq!(run(x32::Sysno::write.id()), SECCOMP_RET_ALLOW);
+        assert_eq!(run(x86_64::Sysno::write.id()), SECCOMP_RET_KILL_PROCESS);
+    }
+}
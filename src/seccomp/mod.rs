@@ -0,0 +1,211 @@
+//! Building and installing seccomp filters.
+//!
+//! A filter is compiled from a [`SysnoMap`] of [`Action`]s. Syscalls that
+//! aren't in the map get the default action.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use syscalls::seccomp::{self, Action};
+//! use syscalls::{Errno, Sysno, SysnoMap};
+//!
+//! let rules = SysnoMap::from_iter([
+//!     (Sysno::read, Action::Allow),
+//!     (Sysno::write, Action::Allow),
+//!     (Sysno::openat, Action::Errno(Errno::EACCES)),
+//!     (Sysno::exit_group, Action::Allow),
+//! ]);
+//!
+//! let program = seccomp::compile(&rules, Action::KillProcess);
+//! seccomp::install(&program).unwrap();
+//! ```
+//!
+//! [`SysnoMap`]: crate::SysnoMap
+
+mod bpf;
+mod emulator;
+mod filter;
+mod notify;
+#[cfg(feature = "serde")]
+pub mod oci;
+
+pub use bpf::*;
+pub use emulator::*;
+pub use filter::*;
+pub use notify::*;
+
+pub use crate::arch::audit::*;
+
+use crate::{Errno, SyscallArgs};
+
+/// Kills the whole process.
+pub const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+/// Kills the thread that made the syscall.
+pub const SECCOMP_RET_KILL_THREAD: u32 = 0x0000_0000;
+/// Same as [`SECCOMP_RET_KILL_THREAD`].
+pub const SECCOMP_RET_KILL: u32 = SECCOMP_RET_KILL_THREAD;
+/// Sends `SIGSYS` to the thread that made the syscall.
+pub const SECCOMP_RET_TRAP: u32 = 0x0003_0000;
+/// Returns an errno to the caller without executing the syscall.
+pub const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+/// Notifies a userspace supervisor.
+pub const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+/// Notifies a ptrace tracer.
+pub const SECCOMP_RET_TRACE: u32 = 0x7ff0_0000;
+/// Allows the syscall after logging it.
+pub const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+/// Allows the syscall.
+pub const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+/// Mask for the full action of a return value.
+pub const SECCOMP_RET_ACTION_FULL: u32 = 0xffff_0000;
+/// Mask for the action of a return value. Kept for compatibility.
+pub const SECCOMP_RET_ACTION: u32 = 0x7fff_0000;
+/// Mask for the data of a return value.
+pub const SECCOMP_RET_DATA: u32 = 0x0000_ffff;
+
+/// Synchronizes the filter with all other threads of the process.
+pub const SECCOMP_FILTER_FLAG_TSYNC: u32 = 1 << 0;
+/// Logs all actions except for `SECCOMP_RET_ALLOW`.
+pub const SECCOMP_FILTER_FLAG_LOG: u32 = 1 << 1;
+/// Disables the speculative store bypass mitigation.
+pub const SECCOMP_FILTER_FLAG_SPEC_ALLOW: u32 = 1 << 2;
+/// Returns a file descriptor for receiving user notifications.
+pub const SECCOMP_FILTER_FLAG_NEW_LISTENER: u32 = 1 << 3;
+/// Returns `ESRCH` instead of a thread ID if thread synchronization fails.
+pub const SECCOMP_FILTER_FLAG_TSYNC_ESRCH: u32 = 1 << 4;
+/// Puts notifying tasks into a killable wait.
+pub const SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV: u32 = 1 << 5;
+
+/// The data that a seccomp filter operates on. This has the same layout as
+/// the kernel's `struct seccomp_data`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SeccompData {
+    /// The syscall number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value of the syscall.
+    pub arch: u32,
+    /// The instruction pointer at the time of the syscall.
+    pub instruction_pointer: u64,
+    /// The syscall arguments.
+    pub args: [u64; 6],
+}
+
+impl SeccompData {
+    /// Creates the data for a syscall with the given number, `AUDIT_ARCH_*`
+    /// value, and arguments. The instruction pointer is set to zero.
+    pub fn new(nr: i32, arch: u32, args: &SyscallArgs) -> Self {
+        Self {
+            nr,
+            arch,
+            instruction_pointer: 0,
+            args: [
+                args.arg0 as u64,
+                args.arg1 as u64,
+                args.arg2 as u64,
+                args.arg3 as u64,
+                args.arg4 as u64,
+                args.arg5 as u64,
+            ],
+        }
+    }
+}
+
+/// The action to take when a seccomp filter matches a syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Action {
+    /// Kills the whole process.
+    KillProcess,
+    /// Kills the thread that made the syscall.
+    KillThread,
+    /// Sends `SIGSYS` to the thread. The value is passed along in `si_errno`.
+    Trap(u16),
+    /// Returns the given error without executing the syscall.
+    Errno(Errno),
+    /// Notifies a userspace supervisor listening on the file descriptor
+    /// returned when installing the filter.
+    UserNotif,
+    /// Notifies a ptrace tracer. The value is available via
+    /// `PTRACE_GETEVENTMSG`.
+    Trace(u16),
+    /// Allows the syscall after logging it.
+    Log,
+    /// Allows the syscall.
+    Allow,
+}
+
+impl Action {
+    /// Converts a raw `SECCOMP_RET_*` value into an action. Returns `None` if
+    /// the action isn't known.
+    pub fn from_ret(ret: u32) -> Option<Self> {
+        let data = (ret & SECCOMP_RET_DATA) as u16;
+
+        Some(match ret & SECCOMP_RET_ACTION_FULL {
+            SECCOMP_RET_KILL_PROCESS => Self::KillProcess,
+            SECCOMP_RET_KILL_THREAD => Self::KillThread,
+            SECCOMP_RET_TRAP => Self::Trap(data),
+            SECCOMP_RET_ERRNO => Self::Errno(Errno::new(data.into())),
+            SECCOMP_RET_USER_NOTIF => Self::UserNotif,
+            SECCOMP_RET_TRACE => Self::Trace(data),
+            SECCOMP_RET_LOG => Self::Log,
+            SECCOMP_RET_ALLOW => Self::Allow,
+            _ => return None,
+        })
+    }
+
+    /// Converts the action into its raw `SECCOMP_RET_*` value.
+    pub fn to_ret(self) -> u32 {
+        match self {
+            Self::KillProcess => SECCOMP_RET_KILL_PROCESS,
+            Self::KillThread => SECCOMP_RET_KILL_THREAD,
+            Self::Trap(data) => SECCOMP_RET_TRAP | u32::from(data),
+            Self::Errno(err) => {
+                SECCOMP_RET_ERRNO | (err.into_raw() as u32 & SECCOMP_RET_DATA)
+            }
+            Self::UserNotif => SECCOMP_RET_USER_NOTIF,
+            Self::Trace(data) => SECCOMP_RET_TRACE | u32::from(data),
+            Self::Log => SECCOMP_RET_LOG,
+            Self::Allow => SECCOMP_RET_ALLOW,
+        }
+    }
+}
+
+impl From<Action> for u32 {
+    fn from(action: Action) -> Self {
+        action.to_ret()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_roundtrip() {
+        let actions = [
+            Action::KillProcess,
+            Action::KillThread,
+            Action::Trap(42),
+            Action::Errno(Errno::EPERM),
+            Action::UserNotif,
+            Action::Trace(1),
+            Action::Log,
+            Action::Allow,
+        ];
+
+        for action in actions {
+            assert_eq!(Action::from_ret(action.to_ret()), Some(action));
+        }
+
+        assert_eq!(Action::Errno(Errno::EPERM).to_ret(), 0x0005_0001);
+        assert_eq!(Action::from_ret(0x1234_0000), None);
+    }
+
+    #[test]
+    fn test_seccomp_data_layout() {
+        assert_eq!(core::mem::size_of::<SeccompData>(), 64);
+        assert_eq!(core::mem::offset_of!(SeccompData, arch), 4);
+        assert_eq!(core::mem::offset_of!(SeccompData, args), 16);
+    }
+}
@@ -0,0 +1,362 @@
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+
+use super::bpf::SockFilter;
+use super::{
+    install_with_flags, SeccompData, AUDIT_ARCH,
+    SECCOMP_FILTER_FLAG_NEW_LISTENER,
+};
+use crate::{Errno, RawSysno, SyscallArgs, Sysno};
+
+/// Tells the kernel to execute the syscall as if the filter had allowed it.
+pub const SECCOMP_USER_NOTIF_FLAG_CONTINUE: u32 = 1 << 0;
+
+/// Installs the file descriptor at the given number in the target process.
+pub const SECCOMP_ADDFD_FLAG_SETFD: u32 = 1 << 0;
+/// Installs the file descriptor and returns it from the syscall atomically.
+pub const SECCOMP_ADDFD_FLAG_SEND: u32 = 1 << 1;
+
+// The ioctl request numbers are encoded differently on some architectures.
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+))]
+mod ioc {
+    pub const SIZEBITS: u32 = 13;
+    pub const WRITE: u32 = 4;
+    pub const READ: u32 = 2;
+}
+
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+mod ioc {
+    pub const SIZEBITS: u32 = 14;
+    pub const WRITE: u32 = 1;
+    pub const READ: u32 = 2;
+}
+
+/// Same as the `_IOC` macro in C with `SECCOMP_IOC_MAGIC` as the type.
+const fn seccomp_ioc(dir: u32, nr: u32, size: usize) -> usize {
+    const MAGIC: u32 = b'!' as u32;
+    ((dir << (16 + ioc::SIZEBITS)) | ((size as u32) << 16) | (MAGIC << 8) | nr)
+        as usize
+}
+
+const SECCOMP_IOCTL_NOTIF_RECV: usize = seccomp_ioc(
+    ioc::READ | ioc::WRITE,
+    0,
+    core::mem::size_of::<SeccompNotif>(),
+);
+const SECCOMP_IOCTL_NOTIF_SEND: usize = seccomp_ioc(
+    ioc::READ | ioc::WRITE,
+    1,
+    core::mem::size_of::<SeccompNotifResp>(),
+);
+const SECCOMP_IOCTL_NOTIF_ID_VALID: usize =
+    seccomp_ioc(ioc::WRITE, 2, core::mem::size_of::<u64>());
+const SECCOMP_IOCTL_NOTIF_ADDFD: usize =
+    seccomp_ioc(ioc::WRITE, 3, core::mem::size_of::<SeccompNotifAddfd>());
+
+/// The kernel's `struct seccomp_notif`.
+#[repr(C)]
+struct SeccompNotif {
+    id: u64,
+    pid: u32,
+    flags: u32,
+    data: SeccompData,
+}
+
+/// The kernel's `struct seccomp_notif_resp`.
+#[repr(C)]
+struct SeccompNotifResp {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+/// The kernel's `struct seccomp_notif_addfd`.
+#[repr(C)]
+struct SeccompNotifAddfd {
+    id: u64,
+    flags: u32,
+    srcfd: u32,
+    newfd: u32,
+    newfd_flags: u32,
+}
+
+/// A syscall that was intercepted by a filter returning
+/// [`Action::UserNotif`](super::Action::UserNotif).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Notification {
+    /// The ID of the notification. This is needed to respond to it.
+    pub id: u64,
+    /// The thread ID of the process that made the syscall. Note that this is
+    /// from the PID namespace of the listener.
+    pub pid: u32,
+    /// The raw syscall data.
+    pub data: SeccompData,
+}
+
+impl Notification {
+    /// Returns the syscall that was made. Returns `None` if the syscall wasn't
+    /// made with the native architecture or if the syscall number is unknown.
+    pub fn sysno(&self) -> Option<Sysno> {
+        if self.data.arch != AUDIT_ARCH {
+            return None;
+        }
+
+        Sysno::new(self.data.nr as usize)
+    }
+
+    /// Like [`Notification::sysno`], but also returns syscall numbers that
+    /// aren't in the syscall table.
+    pub fn raw_sysno(&self) -> Option<RawSysno> {
+        if self.data.arch != AUDIT_ARCH {
+            return None;
+        }
+
+        Some(RawSysno::new(self.data.nr as usize))
+    }
+
+    /// Returns the arguments of the syscall.
+    pub fn args(&self) -> SyscallArgs {
+        let args = self.data.args;
+
+        SyscallArgs::new(
+            args[0] as usize,
+            args[1] as usize,
+            args[2] as usize,
+            args[3] as usize,
+            args[4] as usize,
+            args[5] as usize,
+        )
+    }
+}
+
+/// The response to a [`Notification`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Response {
+    /// Fails the syscall with the given error.
+    Errno(Errno),
+    /// Returns the given value from the syscall without executing it.
+    Value(i64),
+    /// Executes the syscall as if the filter had allowed it.
+    ///
+    /// Note that the target can change the syscall arguments in memory after
+    /// the supervisor has inspected them, so this must not be used to
+    /// implement a security policy.
+    Continue,
+}
+
+/// The supervisor side of a seccomp filter that uses
+/// [`Action::UserNotif`](super::Action::UserNotif).
+///
+/// Every syscall that the filter intercepts must be answered with
+/// [`Listener::respond`]. Until then, the thread that made the syscall is
+/// blocked. If the listener is closed, all pending and future syscalls fail
+/// with `ENOSYS`.
+#[derive(Debug)]
+pub struct Listener {
+    fd: OwnedFd,
+}
+
+impl Listener {
+    /// Installs a seccomp filter for the calling thread and returns the
+    /// listener for it. See [`install`](super::install) for more info.
+    ///
+    /// The listener is usually passed to another process or thread, since the
+    /// calling thread can't answer its own notifications.
+    pub fn install(program: &[SockFilter]) -> Result<Self, Errno> {
+        let fd = install_with_flags(program, SECCOMP_FILTER_FLAG_NEW_LISTENER)?;
+
+        // SAFETY: The kernel returned a new file descriptor that we now own.
+        Ok(unsafe { Self::from_raw_fd(fd as RawFd) })
+    }
+
+    /// Waits for the next notification.
+    pub fn recv(&self) -> Result<Notification, Errno> {
+        // The kernel requires this to be zeroed.
+        let mut notif = SeccompNotif {
+            id: 0,
+            pid: 0,
+            flags: 0,
+            data: SeccompData {
+                nr: 0,
+                arch: 0,
+                instruction_pointer: 0,
+                args: [0; 6],
+            },
+        };
+
+        self.ioctl(SECCOMP_IOCTL_NOTIF_RECV, &mut notif as *mut _ as usize)?;
+
+        Ok(Notification {
+            id: notif.id,
+            pid: notif.pid,
+            data: notif.data,
+        })
+    }
+
+    /// Responds to the notification with the given ID.
+    ///
+    /// Returns `ENOENT` if the notification is no longer valid, which happens
+    /// when the target was interrupted by a signal or killed.
+    pub fn respond(&self, id: u64, response: Response) -> Result<(), Errno> {
+        let mut resp = SeccompNotifResp {
+            id,
+            val: 0,
+            error: 0,
+            flags: 0,
+        };
+
+        match response {
+            Response::Errno(err) => resp.error = -err.into_raw(),
+            Response::Value(val) => resp.val = val,
+            Response::Continue => {
+                resp.flags = SECCOMP_USER_NOTIF_FLAG_CONTINUE;
+            }
+        }
+
+        self.ioctl(SECCOMP_IOCTL_NOTIF_SEND, &mut resp as *mut _ as usize)
+            .map(drop)
+    }
+
+    /// Checks if the notification with the given ID is still valid. This should
+    /// be done after reading the memory of the target, since the target may
+    /// have been killed and its PID reused in the meantime.
+    pub fn id_valid(&self, id: u64) -> Result<bool, Errno> {
+        let mut id = id;
+
+        match self
+            .ioctl(SECCOMP_IOCTL_NOTIF_ID_VALID, &mut id as *mut _ as usize)
+        {
+            Ok(_) => Ok(true),
+            Err(Errno::ENOENT) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Duplicates `fd` into the target of the notification with the given ID.
+    /// If `newfd` is given, the file descriptor is installed at that number,
+    /// replacing any file descriptor that is already there. `newfd_flags` may
+    /// be `O_CLOEXEC`.
+    ///
+    /// Returns the file descriptor number in the target. The notification
+    /// still needs a response.
+    pub fn add_fd(
+        &self,
+        id: u64,
+        fd: BorrowedFd<'_>,
+        newfd: Option<RawFd>,
+        newfd_flags: u32,
+    ) -> Result<RawFd, Errno> {
+        let flags = if newfd.is_some() {
+            SECCOMP_ADDFD_FLAG_SETFD
+        } else {
+            0
+        };
+
+        self.addfd(id, fd, newfd.unwrap_or(0), flags, newfd_flags)
+    }
+
+    /// Duplicates `fd` into the target of the notification with the given ID
+    /// and responds to the notification with the new file descriptor number.
+    /// This is done atomically, which is useful for emulating syscalls like
+    /// `openat`.
+    pub fn add_fd_and_send(
+        &self,
+        id: u64,
+        fd: BorrowedFd<'_>,
+        newfd_flags: u32,
+    ) -> Result<RawFd, Errno> {
+        self.addfd(id, fd, 0, SECCOMP_ADDFD_FLAG_SEND, newfd_flags)
+    }
+
+    fn addfd(
+        &self,
+        id: u64,
+        fd: BorrowedFd<'_>,
+        newfd: RawFd,
+        flags: u32,
+        newfd_flags: u32,
+    ) -> Result<RawFd, Errno> {
+        let mut addfd = SeccompNotifAddfd {
+            id,
+            flags,
+            srcfd: fd.as_raw_fd() as u32,
+            newfd: newfd as u32,
+            newfd_flags,
+        };
+
+        self.ioctl(SECCOMP_IOCTL_NOTIF_ADDFD, &mut addfd as *mut _ as usize)
+            .map(|fd| fd as RawFd)
+    }
+
+    fn ioctl(&self, request: usize, arg: usize) -> Result<usize, Errno> {
+        // SAFETY: All requests take a pointer to a struct that lives for the
+        // duration of the syscall.
+        unsafe { syscall!(Sysno::ioctl, self.fd.as_raw_fd(), request, arg) }
+    }
+}
+
+impl AsFd for Listener {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl AsRawFd for Listener {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+impl FromRawFd for Listener {
+    unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Self {
+            fd: OwnedFd::from_raw_fd(fd),
+        }
+    }
+}
+
+impl From<OwnedFd> for Listener {
+    fn from(fd: OwnedFd) -> Self {
+        Self { fd }
+    }
+}
+
+impl From<Listener> for OwnedFd {
+    fn from(listener: Listener) -> Self {
+        listener.fd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ioctl_numbers() {
+        assert_eq!(core::mem::size_of::<SeccompNotif>(), 80);
+        assert_eq!(core::mem::size_of::<SeccompNotifResp>(), 24);
+        assert_eq!(core::mem::size_of::<SeccompNotifAddfd>(), 24);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            assert_eq!(SECCOMP_IOCTL_NOTIF_RECV, 0xc050_2100);
+            assert_eq!(SECCOMP_IOCTL_NOTIF_SEND, 0xc018_2101);
+            assert_eq!(SECCOMP_IOCTL_NOTIF_ID_VALID, 0x4008_2102);
+            assert_eq!(SECCOMP_IOCTL_NOTIF_ADDFD, 0x4018_2103);
+        }
+    }
+}
@@ -0,0 +1,848 @@
+//! Seccomp profiles in the JSON format used by the [OCI runtime spec] and
+//! Docker.
+//!
+//! [OCI runtime spec]: https://github.com/opencontainers/runtime-spec/blob/main/config-linux.md#seccomp
+
+use serde::{Deserialize, Serialize};
+
+use super::Action;
+use crate::{
+    Arch, ArchSysno, Errno, KernelVersion, SyscallArch, Sysno, SysnoMap,
+    SysnoSet,
+};
+
+/// The `SCMP_ARCH_*` name of the native architecture, if libseccomp supports
+/// it.
+#[cfg(target_arch = "aarch64")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_AARCH64");
+#[cfg(all(target_arch = "arm", target_endian = "little"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_ARM");
+#[cfg(all(target_arch = "arm", target_endian = "big"))]
+pub const SCMP_ARCH: Option<&str> = None;
+#[cfg(target_arch = "loongarch64")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_LOONGARCH64");
+#[cfg(all(target_arch = "mips", target_endian = "big"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPS");
+#[cfg(all(target_arch = "mips", target_endian = "little"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPSEL");
+#[cfg(all(
+    target_arch = "mips64",
+    not(target_abi = "abin32"),
+    target_endian = "big"
+))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPS64");
+#[cfg(all(
+    target_arch = "mips64",
+    not(target_abi = "abin32"),
+    target_endian = "little"
+))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPSEL64");
+#[cfg(all(
+    target_arch = "mips64",
+    target_abi = "abin32",
+    target_endian = "big"
+))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPS64N32");
+#[cfg(all(
+    target_arch = "mips64",
+    target_abi = "abin32",
+    target_endian = "little"
+))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_MIPSEL64N32");
+#[cfg(target_arch = "powerpc")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_PPC");
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_PPC64");
+#[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_PPC64LE");
+#[cfg(target_arch = "riscv32")]
+pub const SCMP_ARCH: Option<&str> = None;
+#[cfg(target_arch = "riscv64")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_RISCV64");
+#[cfg(target_arch = "s390x")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_S390X");
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+pub const SCMP_ARCH: Option<&str> = None;
+#[cfg(target_arch = "x86")]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_X86");
+#[cfg(all(target_arch = "x86_64", not(target_abi = "x32")))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_X86_64");
+#[cfg(all(target_arch = "x86_64", target_abi = "x32"))]
+pub const SCMP_ARCH: Option<&str> = Some("SCMP_ARCH_X32");
+
+/// The syscall table of each `SCMP_ARCH_*` name. Both byte orders of a
+/// bi-endian architecture use the same table.
+const SCMP_ARCHES: &[(&str, Arch)] = &[
+    ("SCMP_ARCH_AARCH64", Arch::Aarch64),
+    ("SCMP_ARCH_ARM", Arch::Arm),
+    ("SCMP_ARCH_LOONGARCH64", Arch::LoongArch64),
+    ("SCMP_ARCH_MIPS", Arch::Mips),
+    ("SCMP_ARCH_MIPSEL", Arch::Mips),
+    ("SCMP_ARCH_MIPS64", Arch::Mips64),
+    ("SCMP_ARCH_MIPSEL64", Arch::Mips64),
+    ("SCMP_ARCH_MIPS64N32", Arch::MipsN32),
+    ("SCMP_ARCH_MIPSEL64N32", Arch::MipsN32),
+    ("SCMP_ARCH_PPC", Arch::PowerPc),
+    ("SCMP_ARCH_PPC64", Arch::PowerPc64),
+    ("SCMP_ARCH_PPC64LE", Arch::PowerPc64),
+    ("SCMP_ARCH_RISCV64", Arch::Riscv64),
+    ("SCMP_ARCH_S390X", Arch::S390x),
+    ("SCMP_ARCH_X86", Arch::X86),
+    ("SCMP_ARCH_X86_64", Arch::X86_64),
+    ("SCMP_ARCH_X32", Arch::X32),
+];
+
+/// Returns the `SCMP_ARCH_*` names of a syscall table. This is [`SCMP_ARCH`]
+/// for the native table, whose byte order is known, and every name of the
+/// table otherwise.
+fn scmp_arches(arch: Arch) -> Vec<String> {
+    if arch == Arch::NATIVE {
+        return SCMP_ARCH.iter().map(ToString::to_string).collect();
+    }
+
+    SCMP_ARCHES
+        .iter()
+        .filter(|&&(_, a)| a == arch)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Returns the syscall table of an `SCMP_ARCH_*` name, or `None` if there is
+/// no table for it. Both byte orders of a bi-endian architecture, such as
+/// `SCMP_ARCH_MIPS` and `SCMP_ARCH_MIPSEL`, map to the same table.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::seccomp::oci::arch_from_scmp;
+/// use syscalls::Arch;
+///
+/// assert_eq!(arch_from_scmp("SCMP_ARCH_X86"), Some(Arch::X86));
+/// assert_eq!(arch_from_scmp("SCMP_ARCH_PPC64LE"), Some(Arch::PowerPc64));
+/// assert_eq!(arch_from_scmp("SCMP_ARCH_PARISC"), None);
+/// ```
+pub fn arch_from_scmp(name: &str) -> Option<Arch> {
+    SCMP_ARCHES
+        .iter()
+        .find(|(scmp, _)| *scmp == name)
+        .map(|&(_, arch)| arch)
+}
+
+/// The syscall table of each architecture name that Docker uses in the
+/// `arches` of `includes` and `excludes`. These are Go's `GOARCH` names, along
+/// with `x86` and `x32`.
+const GO_ARCHES: &[(&str, Arch)] = &[
+    ("386", Arch::X86),
+    ("x86", Arch::X86),
+    ("amd64", Arch::X86_64),
+    ("x32", Arch::X32),
+    ("arm", Arch::Arm),
+    ("armbe", Arch::Arm),
+    ("arm64", Arch::Aarch64),
+    ("arm64be", Arch::Aarch64),
+    ("loong64", Arch::LoongArch64),
+    ("mips", Arch::Mips),
+    ("mipsle", Arch::Mips),
+    ("mips64", Arch::Mips64),
+    ("mips64le", Arch::Mips64),
+    ("mips64p32", Arch::MipsN32),
+    ("mips64p32le", Arch::MipsN32),
+    ("ppc", Arch::PowerPc),
+    ("ppc64", Arch::PowerPc64),
+    ("ppc64le", Arch::PowerPc64),
+    ("riscv", Arch::Riscv32),
+    ("riscv64", Arch::Riscv64),
+    ("s390x", Arch::S390x),
+    ("sparc", Arch::Sparc),
+    ("sparc64", Arch::Sparc64),
+];
+
+/// Returns the syscall table of an architecture name in Docker's `includes`
+/// and `excludes` conditions, or `None` if there is no table for it. These are
+/// Go's `GOARCH` names, such as `amd64` and `arm64`, along with `x86` and
+/// `x32`.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::seccomp::oci::arch_from_go;
+/// use syscalls::Arch;
+///
+/// assert_eq!(arch_from_go("amd64"), Some(Arch::X86_64));
+/// assert_eq!(arch_from_go("x86"), Some(Arch::X86));
+/// assert_eq!(arch_from_go("ppc64le"), Some(Arch::PowerPc64));
+/// assert_eq!(arch_from_go("s390"), None);
+/// ```
+pub fn arch_from_go(name: &str) -> Option<Arch> {
+    GO_ARCHES
+        .iter()
+        .find(|(go, _)| *go == name)
+        .map(|&(_, arch)| arch)
+}
+
+/// The errno that runtimes use when `SCMP_ACT_ERRNO` has no `errnoRet`.
+const DEFAULT_ERRNO: Errno = Errno::EPERM;
+
+/// A seccomp profile. This is the `linux.seccomp` object of the OCI runtime
+/// spec, which is also the format of Docker's seccomp profiles.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::seccomp::oci::Profile;
+/// use syscalls::seccomp::Action;
+/// use syscalls::{Errno, KernelVersion, Sysno};
+///
+/// let profile: Profile = serde_json::from_str(r#"{
+///     "defaultAction": "SCMP_ACT_ERRNO",
+///     "syscalls": [
+///         {
+///             "names": ["read", "write", "not_a_syscall"],
+///             "action": "SCMP_ACT_ALLOW"
+///         }
+///     ]
+/// }"#).unwrap();
+///
+/// let policy = profile.to_policy::<Sysno>(KernelVersion(6, 8)).unwrap();
+/// assert_eq!(policy.default, Action::Errno(Errno::EPERM));
+/// assert_eq!(policy.rules.get(Sysno::read), Some(&Action::Allow));
+/// assert_eq!(policy.unknown, ["not_a_syscall"]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// The action for syscalls that don't match any rule.
+    pub default_action: OciAction,
+    /// The errno for `SCMP_ACT_ERRNO` when used as the default action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_errno_ret: Option<u32>,
+    /// The `SCMP_ARCH_*` architectures the filter applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub architectures: Vec<String>,
+    /// The `SECCOMP_FILTER_FLAG_*` flags to use when installing the filter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    /// The path of the socket to send the listener to for `SCMP_ACT_NOTIFY`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listener_path: Option<String>,
+    /// Opaque data to send along with the listener.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listener_metadata: Option<String>,
+    /// The rules, in order.
+    #[serde(default)]
+    pub syscalls: Vec<SyscallRule>,
+}
+
+/// A rule that applies an action to a list of syscalls.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyscallRule {
+    /// The names of the syscalls.
+    pub names: Vec<String>,
+    /// The action for the syscalls.
+    pub action: OciAction,
+    /// The errno for `SCMP_ACT_ERRNO` or the message for `SCMP_ACT_TRACE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errno_ret: Option<u32>,
+    /// Conditions on the syscall arguments. The rule only applies if all of
+    /// them match.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<ArgCondition>,
+    /// Conditions under which the rule applies. This is Docker-specific.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub includes: Option<RuleFilter>,
+    /// Conditions under which the rule doesn't apply. This is
+    /// Docker-specific.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excludes: Option<RuleFilter>,
+}
+
+/// A condition on a syscall argument.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArgCondition {
+    /// The index of the argument.
+    pub index: u32,
+    /// The value to compare against.
+    pub value: u64,
+    /// The second value for `SCMP_CMP_MASKED_EQ`.
+    #[serde(default)]
+    pub value_two: u64,
+    /// The comparison operator (e.g., `SCMP_CMP_EQ`).
+    pub op: String,
+}
+
+/// Docker's conditions for including or excluding a rule.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleFilter {
+    /// Architectures, as Go's `GOARCH` names (e.g., `amd64`). See
+    /// [`arch_from_go`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arches: Vec<String>,
+    /// Capabilities (e.g., `CAP_SYS_ADMIN`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub caps: Vec<String>,
+    /// The minimum kernel version (e.g., `4.8`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_kernel: Option<String>,
+}
+
+/// An action, as named by libseccomp.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum OciAction {
+    /// Same as `SCMP_ACT_KILL_THREAD`.
+    #[serde(rename = "SCMP_ACT_KILL")]
+    Kill,
+    #[serde(rename = "SCMP_ACT_KILL_PROCESS")]
+    KillProcess,
+    #[serde(rename = "SCMP_ACT_KILL_THREAD")]
+    KillThread,
+    #[serde(rename = "SCMP_ACT_TRAP")]
+    Trap,
+    #[serde(rename = "SCMP_ACT_ERRNO")]
+    Errno,
+    #[serde(rename = "SCMP_ACT_TRACE")]
+    Trace,
+    #[serde(rename = "SCMP_ACT_ALLOW")]
+    Allow,
+    #[serde(rename = "SCMP_ACT_LOG")]
+    Log,
+    #[serde(rename = "SCMP_ACT_NOTIFY")]
+    Notify,
+}
+
+impl OciAction {
+    /// Converts this into an [`Action`] using the given `errnoRet`.
+    pub fn to_action(self, errno_ret: Option<u32>) -> Action {
+        match self {
+            Self::Kill | Self::KillThread => Action::KillThread,
+            Self::KillProcess => Action::KillProcess,
+            Self::Trap => Action::Trap(0),
+            Self::Errno => Action::Errno(
+                errno_ret.map_or(DEFAULT_ERRNO, |err| Errno::new(err as i32)),
+            ),
+            Self::Trace => Action::Trace(errno_ret.unwrap_or(0) as u16),
+            Self::Allow => Action::Allow,
+            Self::Log => Action::Log,
+            Self::Notify => Action::UserNotif,
+        }
+    }
+
+    /// Converts an [`Action`] into this and its `errnoRet`. Note that the data
+    /// of [`Action::Trap`] is lost, since it can't be represented.
+    pub fn from_action(action: Action) -> (Self, Option<u32>) {
+        match action {
+            Action::KillProcess => (Self::KillProcess, None),
+            Action::KillThread => (Self::KillThread, None),
+            Action::Trap(_) => (Self::Trap, None),
+            Action::Errno(err) => (Self::Errno, Some(err.into_raw() as u32)),
+            Action::UserNotif => (Self::Notify, None),
+            Action::Trace(data) => (Self::Trace, Some(data.into())),
+            Action::Log => (Self::Log, None),
+            Action::Allow => (Self::Allow, None),
+        }
+    }
+}
+
+/// A [`Profile`] converted for one syscall table, which is the native one by
+/// default.
+#[derive(Debug)]
+pub struct Policy<S: ArchSysno = Sysno> {
+    /// The action for syscalls that aren't in `rules`.
+    pub default: Action,
+    /// The action for each syscall.
+    pub rules: SysnoMap<Action, S>,
+    /// Syscall names that don't exist in the syscall table.
+    pub unknown: Vec<String>,
+    /// Syscall names whose rule has argument conditions. A [`SysnoMap`]
+    /// can't express these, so they're left out of `rules`.
+    pub conditional: Vec<String>,
+}
+
+impl Profile {
+    /// Creates a profile from a map of syscalls to actions for the syscall
+    /// table `S`. Syscalls with the same action are grouped into the same
+    /// rule. The `architectures` are the `SCMP_ARCH_*` names of the table,
+    /// which is [`SCMP_ARCH`] for the native table and the names of both byte
+    /// orders of a bi-endian architecture otherwise.
+    pub fn from_map<S: ArchSysno>(
+        rules: &SysnoMap<Action, S>,
+        default: Action,
+    ) -> Self {
+        let mut groups: Vec<(Action, Vec<String>)> = Vec::new();
+
+        for (sysno, &action) in rules {
+            if action == default {
+                continue;
+            }
+
+            let name = sysno.to_string();
+
+            match groups.iter_mut().find(|(a, _)| *a == action) {
+                Some((_, names)) => names.push(name),
+                None => groups.push((action, vec![name])),
+            }
+        }
+
+        let (default_action, default_errno_ret) =
+            OciAction::from_action(default);
+
+        Self {
+            default_action,
+            default_errno_ret,
+            architectures: scmp_arches(<S::Arch as SyscallArch>::ARCH),
+            flags: Vec::new(),
+            listener_path: None,
+            listener_metadata: None,
+            syscalls: groups
+                .into_iter()
+                .map(|(action, names)| {
+                    let (action, errno_ret) = OciAction::from_action(action);
+                    SyscallRule {
+                        names,
+                        action,
+                        errno_ret,
+                        args: Vec::new(),
+                        includes: None,
+                        excludes: None,
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates a profile that applies `action` to all syscalls in the set and
+    /// `default` to all others. See [`Profile::from_map`].
+    pub fn from_set<S: ArchSysno>(
+        set: &SysnoSet<S>,
+        action: Action,
+        default: Action,
+    ) -> Self {
+        let rules: SysnoMap<Action, S> =
+            set.iter().map(|s| (s, action)).collect();
+        Self::from_map(&rules, default)
+    }
+
+    /// Returns the syscall tables of the `SCMP_ARCH_*` names in
+    /// [`Profile::architectures`], without duplicates. A filter needs a
+    /// [`Policy`] for each of them. Names without a table are left out.
+    pub fn arches(&self) -> Vec<Arch> {
+        let mut arches = Vec::new();
+
+        for arch in self.architectures.iter().filter_map(|a| arch_from_scmp(a))
+        {
+            if !arches.contains(&arch) {
+                arches.push(arch);
+            }
+        }
+
+        arches
+    }
+
+    /// Converts the profile into a map of actions for the syscall table `S`,
+    /// such as [`Sysno`] or `x86::Sysno`. Returns `None` if
+    /// [`Profile::architectures`] isn't empty and doesn't name the
+    /// architecture of the table. Use [`Profile::arches`] to find the tables
+    /// that the profile applies to.
+    ///
+    /// Rules are applied in order, so a later rule overrides an earlier one
+    /// for the same syscall. Docker's `includes` and `excludes` conditions
+    /// are evaluated for the architecture of the table and the given kernel
+    /// version, assuming that no capabilities are held. A `minKernel` that
+    /// can't be parsed never matches. Rules with argument conditions are
+    /// left out and reported in [`Policy::conditional`].
+    pub fn to_policy<S: ArchSysno>(
+        &self,
+        kernel: KernelVersion,
+    ) -> Option<Policy<S>> {
+        let arch = <S::Arch as SyscallArch>::ARCH;
+
+        if !self.architectures.is_empty() && !self.arches().contains(&arch) {
+            return None;
+        }
+
+        let mut policy = Policy {
+            default: self.default_action.to_action(self.default_errno_ret),
+            rules: SysnoMap::new(),
+            unknown: Vec::new(),
+            conditional: Vec::new(),
+        };
+
+        for rule in self.syscalls.iter().filter(|r| r.applies(arch, kernel)) {
+            let action = rule.action.to_action(rule.errno_ret);
+
+            for name in &rule.names {
+                match name.parse::<S>() {
+                    Ok(_) if !rule.args.is_empty() => {
+                        policy.conditional.push(name.clone());
+                    }
+                    Ok(sysno) => {
+                        policy.rules.insert(sysno, action);
+                    }
+                    Err(()) => policy.unknown.push(name.clone()),
+                }
+            }
+        }
+
+        Some(policy)
+    }
+}
+
+impl SyscallRule {
+    /// Returns true if the rule applies to the given architecture and kernel
+    /// version when no capabilities are held.
+    fn applies(&self, arch: Arch, kernel: KernelVersion) -> bool {
+        let arch_matches = |filter: &RuleFilter| {
+            filter.arches.iter().any(|a| arch_from_go(a) == Some(arch))
+        };
+
+        // `None` if there is no minimum version.
+        let kernel_matches = |filter: &RuleFilter| {
+            filter.min_kernel.as_ref().map(|min| {
+                min.parse::<KernelVersion>()
+                    .is_ok_and(|min| kernel.is_at_least(min))
+            })
+        };
+
+        if let Some(includes) = &self.includes {
+            if !includes.arches.is_empty() && !arch_matches(includes) {
+                return false;
+            }
+
+            if !includes.caps.is_empty() {
+                return false;
+            }
+
+            if kernel_matches(includes) == Some(false) {
+                return false;
+            }
+        }
+
+        if let Some(excludes) = &self.excludes {
+            if arch_matches(excludes) || kernel_matches(excludes) == Some(true)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: &str = r#"{
+        "defaultAction": "SCMP_ACT_ERRNO",
+        "defaultErrnoRet": 38,
+        "architectures": ["SCMP_ARCH_X86_64", "SCMP_ARCH_X86", "SCMP_ARCH_X32"],
+        "syscalls": [
+            {
+                "names": ["read", "write", "close", "bogus"],
+                "action": "SCMP_ACT_ALLOW"
+            },
+            {
+                "names": ["openat"],
+                "action": "SCMP_ACT_ERRNO",
+                "errnoRet": 13
+            },
+            {
+                "names": ["personality"],
+                "action": "SCMP_ACT_ALLOW",
+                "args": [
+                    { "index": 0, "value": 0, "op": "SCMP_CMP_EQ" }
+                ]
+            },
+            {
+                "names": ["mount"],
+                "action": "SCMP_ACT_ALLOW",
+                "includes": { "caps": ["CAP_SYS_ADMIN"] }
+            },
+            {
+                "names": ["getpid"],
+                "action": "SCMP_ACT_LOG"
+            },
+            {
+                "names": ["openat2"],
+                "action": "SCMP_ACT_ALLOW",
+                "includes": { "minKernel": "5.6" }
+            },
+            {
+                "names": ["close_range"],
+                "action": "SCMP_ACT_ALLOW",
+                "excludes": { "minKernel": "5.9" }
+            }
+        ]
+    }"#;
+
+    /// Rules from Docker's default profile that depend on the architecture.
+    const DOCKER: &str = r#"{
+        "defaultAction": "SCMP_ACT_ERRNO",
+        "defaultErrnoRet": 1,
+        "syscalls": [
+            {
+                "names": ["arch_prctl"],
+                "action": "SCMP_ACT_ALLOW",
+                "includes": { "arches": ["amd64", "x32"] }
+            },
+            {
+                "names": ["modify_ldt"],
+                "action": "SCMP_ACT_ALLOW",
+                "includes": { "arches": ["amd64", "x32", "x86"] }
+            },
+            {
+                "names": [
+                    "s390_pci_mmio_read",
+                    "s390_pci_mmio_write",
+                    "s390_runtime_instr"
+                ],
+                "action": "SCMP_ACT_ALLOW",
+                "includes": { "arches": ["s390", "s390x"] }
+            },
+            {
+                "names": ["clone"],
+                "action": "SCMP_ACT_ALLOW",
+                "args": [
+                    {
+                        "index": 0,
+                        "value": 2114060288,
+                        "op": "SCMP_CMP_MASKED_EQ"
+                    }
+                ],
+                "excludes": {
+                    "caps": ["CAP_SYS_ADMIN"],
+                    "arches": ["s390", "s390x"]
+                }
+            },
+            {
+                "names": ["clone"],
+                "action": "SCMP_ACT_ALLOW",
+                "args": [
+                    {
+                        "index": 1,
+                        "value": 2114060288,
+                        "op": "SCMP_CMP_MASKED_EQ"
+                    }
+                ],
+                "comment": "s390 parameter ordering for clone is different",
+                "includes": { "arches": ["s390", "s390x"] },
+                "excludes": { "caps": ["CAP_SYS_ADMIN"] }
+            }
+        ]
+    }"#;
+
+    /// The kernel version that the tests convert the profile for.
+    const KERNEL: KernelVersion = KernelVersion(5, 4);
+
+    /// Parses the test profile, without its architectures so that it also
+    /// applies to the native one.
+    fn native_profile() -> Profile {
+        let mut profile: Profile = serde_json::from_str(PROFILE).unwrap();
+        profile.architectures.clear();
+        profile
+    }
+
+    #[test]
+    fn test_to_policy() {
+        let policy = native_profile().to_policy::<Sysno>(KERNEL).unwrap();
+
+        assert_eq!(policy.default, Action::Errno(Errno::ENOSYS));
+        assert_eq!(policy.rules.get(Sysno::read), Some(&Action::Allow));
+        assert_eq!(policy.rules.get(Sysno::close), Some(&Action::Allow));
+        assert_eq!(
+            policy.rules.get(Sysno::openat),
+            Some(&Action::Errno(Errno::EACCES))
+        );
+        assert_eq!(policy.rules.get(Sysno::getpid), Some(&Action::Log));
+        assert_eq!(policy.rules.get(Sysno::mount), None);
+        assert_eq!(policy.rules.get(Sysno::personality), None);
+        assert_eq!(policy.unknown, ["bogus"]);
+        assert_eq!(policy.conditional, ["personality"]);
+    }
+
+    #[test]
+    fn test_to_policy_kernel() {
+        let profile = native_profile();
+        let rules = |kernel| profile.to_policy::<Sysno>(kernel).unwrap().rules;
+
+        assert_eq!(rules(KERNEL).get(Sysno::openat2), None);
+        assert_eq!(rules(KERNEL).get(Sysno::close_range), Some(&Action::Allow));
+
+        let rules = rules(KernelVersion(6, 1));
+        assert_eq!(rules.get(Sysno::openat2), Some(&Action::Allow));
+        assert_eq!(rules.get(Sysno::close_range), None);
+    }
+
+    #[test]
+    fn test_to_policy_arches() {
+        let profile: Profile = serde_json::from_str(PROFILE).unwrap();
+        assert_eq!(profile.arches(), [Arch::X86_64, Arch::X86, Arch::X32]);
+
+        let listed = profile.arches().contains(&Arch::NATIVE);
+        assert_eq!(profile.to_policy::<Sysno>(KERNEL).is_some(), listed);
+
+        let mut profile = profile;
+        profile.architectures = vec!["SCMP_ARCH_PARISC".to_string()];
+        assert!(profile.arches().is_empty());
+        assert!(profile.to_policy::<Sysno>(KERNEL).is_none());
+    }
+
+    #[test]
+    fn test_native_scmp_arch() {
+        if let Some(name) = SCMP_ARCH {
+            assert_eq!(arch_from_scmp(name), Some(Arch::NATIVE));
+        }
+    }
+
+    #[test]
+    fn test_arch_from_go() {
+        for &(name, arch) in GO_ARCHES {
+            assert_eq!(arch_from_go(name), Some(arch));
+        }
+
+        assert_eq!(arch_from_go("SCMP_ARCH_X86_64"), None);
+    }
+
+    #[test]
+    fn test_docker_native() {
+        let profile: Profile = serde_json::from_str(DOCKER).unwrap();
+        let policy = profile.to_policy::<Sysno>(KERNEL).unwrap();
+
+        // Exactly one of the rules for `clone` applies to each architecture.
+        assert_eq!(policy.conditional, ["clone"]);
+        assert!(policy.unknown.is_empty());
+    }
+
+    #[cfg(feature = "x86_64")]
+    #[test]
+    fn test_docker_x86_64() {
+        use crate::x86_64;
+
+        let profile: Profile = serde_json::from_str(DOCKER).unwrap();
+        let policy = profile.to_policy::<x86_64::Sysno>(KERNEL).unwrap();
+
+        let allowed = [x86_64::Sysno::arch_prctl, x86_64::Sysno::modify_ldt];
+        for sysno in allowed {
+            assert_eq!(policy.rules.get(sysno), Some(&Action::Allow));
+        }
+        assert_eq!(policy.rules.count(), 2);
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_docker_x86() {
+        use crate::x86;
+
+        let profile: Profile = serde_json::from_str(DOCKER).unwrap();
+        let policy = profile.to_policy::<x86::Sysno>(KERNEL).unwrap();
+
+        assert_eq!(
+            policy.rules.get(x86::Sysno::modify_ldt),
+            Some(&Action::Allow)
+        );
+        assert_eq!(policy.rules.get(x86::Sysno::arch_prctl), None);
+        assert_eq!(policy.conditional, ["clone"]);
+    }
+
+    #[cfg(feature = "s390x")]
+    #[test]
+    fn test_docker_s390x() {
+        use crate::s390x;
+
+        let profile: Profile = serde_json::from_str(DOCKER).unwrap();
+        let policy = profile.to_policy::<s390x::Sysno>(KERNEL).unwrap();
+
+        assert_eq!(
+            policy.rules.get(s390x::Sysno::s390_runtime_instr),
+            Some(&Action::Allow)
+        );
+        assert_eq!(policy.rules.count(), 3);
+        // Only the rule with the s390 argument order applies.
+        assert_eq!(policy.conditional, ["clone"]);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let rules = SysnoMap::from_iter([
+            (Sysno::read, Action::Allow),
+            (Sysno::write, Action::Allow),
+            (Sysno::openat, Action::Errno(Errno::EACCES)),
+            (Sysno::close, Action::KillProcess),
+        ]);
+
+        let profile = Profile::from_map(&rules, Action::KillProcess);
+
+        assert_eq!(profile.syscalls.len(), 2);
+        assert_eq!(profile.syscalls[0].names, ["read", "write"]);
+
+        let json = serde_json::to_string(&profile).unwrap();
+        let parsed: Profile = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, profile);
+
+        let policy = parsed.to_policy::<Sysno>(KERNEL).unwrap();
+        assert_eq!(policy.default, Action::KillProcess);
+        assert_eq!(policy.rules.count(), 3);
+        assert_eq!(
+            policy.rules.get(Sysno::openat),
+            Some(&Action::Errno(Errno::EACCES))
+        );
+    }
+
+    #[test]
+    fn test_from_set() {
+        let set = SysnoSet::new(&[Sysno::read, Sysno::write]);
+        let profile =
+            Profile::from_set(&set, Action::Allow, Action::Errno(Errno::EPERM));
+
+        assert_eq!(profile.architectures, SCMP_ARCH.as_slice());
+
+        let json = serde_json::to_value(&profile).unwrap();
+        assert_eq!(json["defaultAction"], "SCMP_ACT_ERRNO");
+        assert_eq!(json["defaultErrnoRet"], 1);
+        assert_eq!(json["syscalls"][0]["action"], "SCMP_ACT_ALLOW");
+        assert_eq!(
+            json["syscalls"][0]["names"],
+            serde_json::json!(["read", "write"])
+        );
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_from_set_x86() {
+        use crate::x86;
+
+        let set = SysnoSet::new(&[x86::Sysno::read, x86::Sysno::socketcall]);
+        let profile =
+            Profile::from_set(&set, Action::Allow, Action::KillThread);
+
+        assert_eq!(profile.architectures, ["SCMP_ARCH_X86"]);
+        assert_eq!(profile.syscalls[0].names, ["read", "socketcall"]);
+
+        let policy = profile.to_policy::<x86::Sysno>(KERNEL).unwrap();
+        assert_eq!(policy.rules.count(), 2);
+        assert_eq!(
+            policy.rules.get(x86::Sysno::socketcall),
+            Some(&Action::Allow)
+        );
+    }
+
+    #[cfg(feature = "mips")]
+    #[test]
+    fn test_from_set_mips() {
+        use crate::mips;
+
+        // The byte order is only known for the native table.
+        if Arch::NATIVE != Arch::Mips {
+            let set = SysnoSet::new(&[mips::Sysno::read]);
+            let profile =
+                Profile::from_set(&set, Action::Allow, Action::KillThread);
+
+            assert_eq!(
+                profile.architectures,
+                ["SCMP_ARCH_MIPS", "SCMP_ARCH_MIPSEL"]
+            );
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! Enables the creation of a syscall bitset.
 
 use super::Sysno;
+use crate::arch::{index_to_id, sysno_index, MAX_TABLE_SIZE};
+use crate::{ArchSysno, KernelVersion};
 
 use core::fmt;
+use core::marker::PhantomData;
 use core::num::NonZeroUsize;
 
 const fn bits_per<T>() -> usize {
@@ -22,7 +25,13 @@ const fn words<T>(bits: usize) -> usize {
 
 /// A set of syscalls.
 ///
-/// This provides constant-time lookup of syscalls within a bitset.
+/// This provides constant-time lookup of syscalls within a bitset. By default,
+/// this holds syscalls of the native [`Sysno`] table, but the `Sysno` type of
+/// any enabled architecture module can be used instead (see [`ArchSysno`]).
+///
+/// The bitset is sized for the largest enabled syscall table, so enabling an
+/// architecture feature with a larger table (such as `x32`) also makes sets of
+/// the native table larger.
 ///
 /// # Examples
 ///
@@ -41,35 +50,121 @@ const fn words<T>(bits: usize) -> usize {
 /// const _: () = assert!(SYSCALLS.contains(Sysno::read));
 /// const _: () = assert!(SYSCALLS.contains(Sysno::openat));
 /// ```
-#[derive(Clone, Eq, PartialEq)]
-pub struct SysnoSet {
-    pub(crate) data: [usize; words::<usize>(Sysno::table_size())],
+pub struct SysnoSet<S = Sysno> {
+    pub(crate) data: [usize; words::<usize>(MAX_TABLE_SIZE)],
+    _sysno: PhantomData<S>,
+}
+
+impl<S> Clone for SysnoSet<S> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            _sysno: PhantomData,
+        }
+    }
 }
 
-impl Default for SysnoSet {
+impl<S> PartialEq for SysnoSet<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<S> Eq for SysnoSet<S> {}
+
+impl<S: ArchSysno> Default for SysnoSet<S> {
     fn default() -> Self {
-        Self::empty()
+        Self::EMPTY
     }
 }
 
 impl SysnoSet {
+    /// Creates an empty set of syscalls.
+    ///
+    /// This only creates sets of the native table. Use [`SysnoSet::EMPTY`]
+    /// for other architectures.
+    pub const fn empty() -> Self {
+        Self::EMPTY
+    }
+
+    /// Creates a set containing all valid syscalls.
+    ///
+    /// This only creates sets of the native table. Use [`SysnoSet::ALL`] for
+    /// other architectures.
+    pub const fn all() -> Self {
+        Self::ALL
+    }
+}
+
+impl<S: ArchSysno> SysnoSet<S> {
+    /// The empty set.
+    pub const EMPTY: Self = Self {
+        data: [0; words::<usize>(MAX_TABLE_SIZE)],
+        _sysno: PhantomData,
+    };
+
     /// The set of all valid syscalls.
-    const ALL: &'static Self = &Self::new(Sysno::ALL);
+    pub const ALL: Self = Self::new(S::ALL);
+
+    /// Syscalls that take a file name as an argument. This is the same as
+    /// strace's `%file` class.
+    pub const FILE: Self = Self::new(S::CLASSES.file);
+
+    /// Syscalls that take or return a file descriptor. This is the same as
+    /// strace's `%desc` class.
+    pub const DESC: Self = Self::new(S::CLASSES.desc);
+
+    /// Network-related syscalls. This is the same as strace's `%network`
+    /// class.
+    pub const NETWORK: Self = Self::new(S::CLASSES.network);
+
+    /// Syscalls related to the process lifecycle (creation, exec, and
+    /// termination). This is the same as strace's `%process` class.
+    pub const PROCESS: Self = Self::new(S::CLASSES.process);
+
+    /// Signal-related syscalls. This is the same as strace's `%signal` class.
+    pub const SIGNAL: Self = Self::new(S::CLASSES.signal);
+
+    /// System V IPC-related syscalls. This is the same as strace's `%ipc`
+    /// class.
+    pub const IPC: Self = Self::new(S::CLASSES.ipc);
+
+    /// Memory mapping-related syscalls. This is the same as strace's `%memory`
+    /// class.
+    pub const MEMORY: Self = Self::new(S::CLASSES.memory);
+
+    /// Syscalls that read or modify user and group IDs or capability sets.
+    /// This is the same as strace's `%creds` class.
+    pub const CREDS: Self = Self::new(S::CLASSES.creds);
+
+    /// Syscalls that read or modify system clocks. This is the same as
+    /// strace's `%clock` class.
+    pub const CLOCK: Self = Self::new(S::CLASSES.clock);
+
+    /// Variants of `stat`. This is the same as strace's `%stat` class.
+    pub const STAT: Self = Self::new(S::CLASSES.stat);
+
+    /// Variants of `statfs`. This is the same as strace's `%statfs` class.
+    pub const STATFS: Self = Self::new(S::CLASSES.statfs);
+
+    /// Syscalls that always succeed and have no arguments. This is the same as
+    /// strace's `%pure` class.
+    pub const PURE: Self = Self::new(S::CLASSES.pure);
 
     const WORD_WIDTH: usize = usize::BITS as usize;
 
     /// Compute the index and mask for the given syscall as stored in the set data.
     #[inline]
-    pub(crate) const fn get_idx_mask(sysno: Sysno) -> (usize, usize) {
-        let bit = (sysno.id() as usize) - (Sysno::first().id() as usize);
+    pub(crate) const fn get_idx_mask(sysno: S) -> (usize, usize) {
+        let bit = sysno_index(&sysno);
         (bit / Self::WORD_WIDTH, 1 << (bit % Self::WORD_WIDTH))
     }
 
     /// Initialize the syscall set with the given slice of syscalls.
     ///
     /// Since this is a `const fn`, this can be used at compile-time.
-    pub const fn new(syscalls: &[Sysno]) -> Self {
-        let mut set = Self::empty();
+    pub const fn new(syscalls: &[S]) -> Self {
+        let mut set = Self::EMPTY;
 
         // Use while-loop because for-loops are not yet allowed in const-fns.
         // https://github.com/rust-lang/rust/issues/87575
@@ -83,22 +178,39 @@ impl SysnoSet {
         set
     }
 
-    /// Creates an empty set of syscalls.
-    pub const fn empty() -> Self {
-        Self {
-            data: [0; words::<usize>(Sysno::table_size())],
-        }
-    }
+    /// Returns the set of syscalls that are available in the given Linux
+    /// version on this architecture, according to [`Sysno::since`]. Syscalls
+    /// that aren't in this set need a fallback for `ENOSYS` on that version.
+    ///
+    /// Since this is a `const fn`, this can be used at compile-time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syscalls::{KernelVersion, Sysno, SysnoSet};
+    ///
+    /// const LTS: SysnoSet = SysnoSet::available_in(KernelVersion(4, 19));
+    ///
+    /// assert!(LTS.contains(Sysno::read));
+    /// assert!(!LTS.contains(Sysno::openat2));
+    /// ```
+    pub const fn available_in(version: KernelVersion) -> Self {
+        let mut set = Self::EMPTY;
 
-    /// Creates a set containing all valid syscalls.
-    pub const fn all() -> Self {
-        Self {
-            data: Self::ALL.data,
+        let mut i = 0;
+        while i < S::ALL.len() {
+            if version.is_at_least(S::SINCE[i]) {
+                let (idx, mask) = Self::get_idx_mask(S::ALL[i]);
+                set.data[idx] |= mask;
+            }
+            i += 1;
         }
+
+        set
     }
 
     /// Returns true if the set contains the given syscall.
-    pub const fn contains(&self, sysno: Sysno) -> bool {
+    pub const fn contains(&self, sysno: S) -> bool {
         let (idx, mask) = Self::get_idx_mask(sysno);
         self.data[idx] & mask != 0
     }
@@ -130,7 +242,7 @@ impl SysnoSet {
 
     /// Inserts the given syscall into the set. Returns true if the syscall was
     /// not already in the set.
-    pub fn insert(&mut self, sysno: Sysno) -> bool {
+    pub fn insert(&mut self, sysno: S) -> bool {
         // The returned value computation will be optimized away by the compiler
         // if not needed.
         let (idx, mask) = Self::get_idx_mask(sysno);
@@ -141,7 +253,7 @@ impl SysnoSet {
 
     /// Removes the given syscall from the set. Returns true if the syscall was
     /// in the set.
-    pub fn remove(&mut self, sysno: Sysno) -> bool {
+    pub fn remove(&mut self, sysno: S) -> bool {
         // The returned value computation will be optimized away by the compiler
         // if not needed.
         let (idx, mask) = Self::get_idx_mask(sysno);
@@ -207,18 +319,18 @@ impl SysnoSet {
     }
 
     /// Returns an iterator that iterates over the syscalls contained in the set.
-    pub fn iter(&self) -> SysnoSetIter<'_> {
+    pub fn iter(&self) -> SysnoSetIter<'_, S> {
         SysnoSetIter::new(self.data.iter())
     }
 }
 
-impl fmt::Debug for SysnoSet {
+impl<S: ArchSysno> fmt::Debug for SysnoSet<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_set().entries(self.iter()).finish()
     }
 }
 
-impl core::ops::BitOr for SysnoSet {
+impl<S> core::ops::BitOr for SysnoSet<S> {
     type Output = Self;
 
     fn bitor(mut self, rhs: Self) -> Self::Output {
@@ -227,7 +339,7 @@ impl core::ops::BitOr for SysnoSet {
     }
 }
 
-impl core::ops::BitOrAssign<&Self> for SysnoSet {
+impl<S> core::ops::BitOrAssign<&Self> for SysnoSet<S> {
     fn bitor_assign(&mut self, rhs: &Self) {
         for (left, right) in self.data.iter_mut().zip(rhs.data.iter()) {
             *left |= right;
@@ -235,37 +347,37 @@ impl core::ops::BitOrAssign<&Self> for SysnoSet {
     }
 }
 
-impl core::ops::BitOrAssign for SysnoSet {
+impl<S> core::ops::BitOrAssign for SysnoSet<S> {
     fn bitor_assign(&mut self, rhs: Self) {
         *self |= &rhs;
     }
 }
 
-impl core::ops::BitOrAssign<Sysno> for SysnoSet {
-    fn bitor_assign(&mut self, sysno: Sysno) {
+impl<S: ArchSysno> core::ops::BitOrAssign<S> for SysnoSet<S> {
+    fn bitor_assign(&mut self, sysno: S) {
         self.insert(sysno);
     }
 }
 
-impl FromIterator<Sysno> for SysnoSet {
-    fn from_iter<I: IntoIterator<Item = Sysno>>(iter: I) -> Self {
-        let mut set = SysnoSet::empty();
+impl<S: ArchSysno> FromIterator<S> for SysnoSet<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut set = SysnoSet::EMPTY;
         set.extend(iter);
         set
     }
 }
 
-impl Extend<Sysno> for SysnoSet {
-    fn extend<T: IntoIterator<Item = Sysno>>(&mut self, iter: T) {
+impl<S: ArchSysno> Extend<S> for SysnoSet<S> {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
         for sysno in iter {
             self.insert(sysno);
         }
     }
 }
 
-impl<'a> IntoIterator for &'a SysnoSet {
-    type Item = Sysno;
-    type IntoIter = SysnoSetIter<'a>;
+impl<'a, S: ArchSysno> IntoIterator for &'a SysnoSet<S> {
+    type Item = S;
+    type IntoIter = SysnoSetIter<'a, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -301,7 +413,7 @@ impl Iterator for NonZeroUsizeIter<'_> {
 }
 
 /// An iterator over the syscalls contained in a [`SysnoSet`].
-pub struct SysnoSetIter<'a> {
+pub struct SysnoSetIter<'a, S = Sysno> {
     // Our iterator over nonzero words in the bitset.
     iter: NonZeroUsizeIter<'a>,
 
@@ -309,18 +421,24 @@ pub struct SysnoSetIter<'a> {
     // iterator has been exhausted. The next bit that is set is found by
     // counting the number of leading zeros. When found, we just mask it off.
     current: Option<NonZeroUsize>,
+
+    _sysno: PhantomData<S>,
 }
 
-impl<'a> SysnoSetIter<'a> {
+impl<'a, S> SysnoSetIter<'a, S> {
     fn new(iter: core::slice::Iter<'a, usize>) -> Self {
         let mut iter = NonZeroUsizeIter::new(iter);
         let current = iter.next();
-        Self { iter, current }
+        Self {
+            iter,
+            current,
+            _sysno: PhantomData,
+        }
     }
 }
 
-impl Iterator for SysnoSetIter<'_> {
-    type Item = Sysno;
+impl<S: ArchSysno> Iterator for SysnoSetIter<'_, S> {
+    type Item = S;
 
     fn next(&mut self) -> Option<Self::Item> {
         // Construct a mask where all but the last bit is set. This is then
@@ -344,11 +462,11 @@ impl Iterator for SysnoSetIter<'_> {
 
             self.current = next_word.or_else(|| self.iter.next());
 
-            let offset = Sysno::first().id() as u32;
-            let sysno = index as u32 * usize::BITS + bit + offset;
+            let sysno =
+                index_to_id::<S>(index * usize::BITS as usize + bit as usize);
 
             // TODO: Use an unchecked conversion to speed this up.
-            return Some(Sysno::from(sysno));
+            return S::new(sysno as usize);
         }
 
         None
@@ -362,10 +480,10 @@ use serde::{
 };
 
 #[cfg(feature = "serde")]
-impl Serialize for SysnoSet {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl<S: ArchSysno + Serialize> Serialize for SysnoSet<S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
     where
-        S: Serializer,
+        Ser: Serializer,
     {
         let mut seq = serializer.serialize_seq(Some(self.count()))?;
         for sysno in self {
@@ -376,15 +494,15 @@ impl Serialize for SysnoSet {
 }
 
 #[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for SysnoSet {
+impl<'de, S: ArchSysno + Deserialize<'de>> Deserialize<'de> for SysnoSet<S> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct SeqVisitor;
+        struct SeqVisitor<S>(PhantomData<S>);
 
-        impl<'de> Visitor<'de> for SeqVisitor {
-            type Value = SysnoSet;
+        impl<'de, S: ArchSysno + Deserialize<'de>> Visitor<'de> for SeqVisitor<S> {
+            type Value = SysnoSet<S>;
 
             fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 f.write_str("a sequence")
@@ -394,7 +512,7 @@ impl<'de> Deserialize<'de> for SysnoSet {
             where
                 A: SeqAccess<'de>,
             {
-                let mut values = SysnoSet::empty();
+                let mut values = SysnoSet::EMPTY;
 
                 while let Some(value) = seq.next_element()? {
                     values.insert(value);
@@ -404,7 +522,7 @@ impl<'de> Deserialize<'de> for SysnoSet {
             }
         }
 
-        deserializer.deserialize_seq(SeqVisitor)
+        deserializer.deserialize_seq(SeqVisitor(PhantomData))
     }
 }
 
@@ -642,6 +760,137 @@ mod tests {
         assert_eq!(SysnoSet::empty().iter().collect::<Vec<_>>(), &[]);
     }
 
+    #[test]
+    fn test_classes() {
+        const _: () = assert!(SysnoSet::FILE.contains(Sysno::openat));
+        const _: () = assert!(SysnoSet::DESC.contains(Sysno::close));
+
+        assert!(SysnoSet::NETWORK.contains(Sysno::socket));
+        assert!(SysnoSet::PROCESS.contains(Sysno::exit_group));
+        assert!(SysnoSet::SIGNAL.contains(Sysno::rt_sigaction));
+        assert!(SysnoSet::MEMORY.contains(Sysno::mmap));
+        assert!(SysnoSet::CREDS.contains(Sysno::setuid));
+        assert!(SysnoSet::CLOCK.contains(Sysno::clock_gettime));
+        assert!(SysnoSet::PURE.contains(Sysno::getpid));
+        assert!(!SysnoSet::PURE.contains(Sysno::read));
+
+        // Syscalls can belong to more than one class.
+        assert!(SysnoSet::DESC.contains(Sysno::openat));
+        assert!(SysnoSet::IPC.contains(Sysno::shmat));
+        assert!(SysnoSet::MEMORY.contains(Sysno::shmat));
+    }
+
+    #[cfg(any(target_arch = "x86", feature = "x86"))]
+    #[test]
+    fn test_classes_foreign() {
+        use crate::x86;
+
+        const NETWORK: SysnoSet<x86::Sysno> = SysnoSet::NETWORK;
+        const _: () = assert!(NETWORK.contains(x86::Sysno::socketcall));
+
+        assert!(SysnoSet::IPC.contains(x86::Sysno::ipc));
+        assert!(SysnoSet::MEMORY.contains(x86::Sysno::mmap2));
+        assert_eq!(
+            SysnoSet::<x86::Sysno>::FILE.count(),
+            x86::class::FILE.len()
+        );
+    }
+
+    #[test]
+    fn test_native() {
+        assert_eq!(SysnoSet::all(), SysnoSet::<Sysno>::ALL);
+        assert_eq!(SysnoSet::empty(), SysnoSet::<Sysno>::EMPTY);
+    }
+
+    #[test]
+    fn test_available_in() {
+        assert_eq!(Sysno::openat2.since(), KernelVersion(5, 6));
+        assert_eq!(Sysno::read.since(), KernelVersion::OLDEST);
+
+        let set = SysnoSet::available_in(KernelVersion(5, 6));
+        assert!(set.contains(Sysno::read));
+        assert!(set.contains(Sysno::openat2));
+        assert!(!set.contains(Sysno::close_range));
+
+        for sysno in Sysno::ALL {
+            assert_eq!(
+                set.contains(*sysno),
+                sysno.since() <= KernelVersion(5, 6),
+                "{sysno}"
+            );
+        }
+
+        assert_eq!(
+            SysnoSet::available_in(KernelVersion(u8::MAX, 0)),
+            SysnoSet::<Sysno>::ALL
+        );
+        assert!(SysnoSet::<Sysno>::available_in(KernelVersion(2, 6)).is_empty());
+    }
+
+    #[cfg(feature = "x86")]
+    #[test]
+    fn test_x86() {
+        use crate::x86;
+
+        let mut set = SysnoSet::new(&[x86::Sysno::socketcall]);
+        assert!(set.insert(x86::Sysno::ipc));
+        assert!(set.contains(x86::Sysno::socketcall));
+        assert!(!set.contains(x86::Sysno::read));
+        assert_eq!(set.count(), 2);
+
+        let all = SysnoSet::<x86::Sysno>::ALL;
+        assert_eq!(all.count(), x86::Sysno::count());
+        assert!(all.iter().eq(x86::Sysno::ALL.iter().copied()));
+
+        // The direct socket syscalls were added long after `socketcall`.
+        let old = SysnoSet::<x86::Sysno>::available_in(KernelVersion(4, 2));
+        assert!(old.contains(x86::Sysno::socketcall));
+        assert!(!old.contains(x86::Sysno::connect));
+        assert_eq!(x86::Sysno::connect.since(), KernelVersion(4, 3));
+    }
+
+    #[cfg(feature = "mips")]
+    #[test]
+    fn test_mips() {
+        use crate::mips;
+
+        // The mips table doesn't start at zero.
+        let all = SysnoSet::<mips::Sysno>::ALL;
+        assert!(all.contains(mips::Sysno::first()));
+        assert!(all.contains(mips::Sysno::last()));
+        assert!(all.iter().eq(mips::Sysno::ALL.iter().copied()));
+    }
+
+    #[cfg(feature = "arm")]
+    #[test]
+    fn test_arm_private() {
+        use crate::arm;
+
+        // The private syscalls are far above the rest of the table, but they
+        // don't make the set any bigger.
+        assert!(arm::Sysno::table_size() < 512);
+        assert_eq!(
+            arm::Sysno::rseq_slice_yield.next(),
+            Some(arm::Sysno::breakpoint)
+        );
+
+        let mut set = SysnoSet::new(&[arm::Sysno::cacheflush]);
+        assert!(set.insert(arm::Sysno::rseq_slice_yield));
+        assert!(set.insert(arm::Sysno::set_tls));
+        assert!(set.contains(arm::Sysno::cacheflush));
+        assert!(!set.contains(arm::Sysno::breakpoint));
+        assert!(set.iter().eq([
+            arm::Sysno::rseq_slice_yield,
+            arm::Sysno::cacheflush,
+            arm::Sysno::set_tls,
+        ]));
+
+        let all = SysnoSet::<arm::Sysno>::ALL;
+        assert_eq!(all.count(), arm::Sysno::count());
+        assert!(all.contains(arm::Sysno::get_tls));
+        assert!(all.iter().eq(arm::Sysno::ALL.iter().copied()));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde_roundtrip() {
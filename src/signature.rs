@@ -0,0 +1,134 @@
+//! Signature metadata for syscalls.
+//!
+//! This information is extracted from the kernel's syscall prototypes
+//! (`include/linux/syscalls.h`) by `syscalls-gen`. See [`Sysno::args`] and
+//! [`Sysno::return_kind`].
+//!
+//! [`Sysno::args`]: crate::Sysno::args
+//! [`Sysno::return_kind`]: crate::Sysno::return_kind
+
+/// A coarse classification of the C type of a syscall argument or return
+/// value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArgKind {
+    /// A signed integer (e.g., `int`, `long`, `off_t`, `pid_t`).
+    Int,
+    /// An unsigned integer (e.g., `unsigned int`, `size_t`, `umode_t`).
+    UInt,
+    /// A file descriptor.
+    Fd,
+    /// A pointer to some memory in the tracee's address space.
+    Ptr,
+    /// A pointer to a NUL-terminated string (e.g., a path).
+    Str,
+}
+
+impl ArgKind {
+    /// Returns true if this is a pointer. Strings are also pointers.
+    pub const fn is_pointer(&self) -> bool {
+        matches!(self, Self::Ptr | Self::Str)
+    }
+}
+
+/// A single argument of a syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SyscallArg {
+    /// The name of the argument, as it appears in the kernel source.
+    pub name: &'static str,
+    /// The C type of the argument with any `__user` annotations removed (e.g.,
+    /// `"const char *"`).
+    pub ty: &'static str,
+    /// The kind of argument.
+    pub kind: ArgKind,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sysno;
+
+    #[test]
+    fn test_arg_count() {
+        assert_eq!(Sysno::close.arg_count(), Some(1));
+        assert_eq!(Sysno::read.arg_count(), Some(3));
+        assert_eq!(Sysno::openat.arg_count(), Some(4));
+        assert_eq!(Sysno::getpid.arg_count(), Some(0));
+    }
+
+    #[test]
+    fn test_args() {
+        let args = Sysno::openat.args().unwrap();
+        assert_eq!(args[0].name, "dfd");
+        assert_eq!(args[0].kind, ArgKind::Fd);
+        assert_eq!(args[1].name, "filename");
+        assert_eq!(args[1].ty, "const char *");
+        assert_eq!(args[1].kind, ArgKind::Str);
+        assert_eq!(args[2].kind, ArgKind::Int);
+        assert_eq!(args[3].kind, ArgKind::UInt);
+
+        let args = Sysno::read.args().unwrap();
+        assert_eq!(args[0].kind, ArgKind::Fd);
+        assert_eq!(args[1].kind, ArgKind::Ptr);
+        assert_eq!(args[2].ty, "size_t");
+        assert_eq!(args[2].kind, ArgKind::UInt);
+    }
+
+    #[test]
+    fn test_clone_args() {
+        fn names(args: &[SyscallArg]) -> [&str; 5] {
+            assert_eq!(args.len(), 5);
+            core::array::from_fn(|i| args[i].name)
+        }
+
+        #[cfg(any(target_arch = "x86_64", feature = "x86_64"))]
+        assert_eq!(
+            names(crate::x86_64::Sysno::clone.args().unwrap()),
+            [
+                "clone_flags",
+                "newsp",
+                "parent_tidptr",
+                "child_tidptr",
+                "tls"
+            ]
+        );
+
+        // `CONFIG_CLONE_BACKWARDS` passes `tls` before `child_tidptr`.
+        #[cfg(any(target_arch = "x86", feature = "x86"))]
+        {
+            let args = crate::x86::Sysno::clone.args().unwrap();
+            assert_eq!(
+                names(args),
+                [
+                    "clone_flags",
+                    "newsp",
+                    "parent_tidptr",
+                    "tls",
+                    "child_tidptr"
+                ]
+            );
+            assert_eq!(args[3].kind, ArgKind::UInt);
+            assert_eq!(args[4].kind, ArgKind::Ptr);
+        }
+
+        // `CONFIG_CLONE_BACKWARDS2` passes `newsp` before `clone_flags`.
+        #[cfg(any(target_arch = "s390x", feature = "s390x"))]
+        assert_eq!(
+            names(crate::s390x::Sysno::clone.args().unwrap()),
+            [
+                "newsp",
+                "clone_flags",
+                "parent_tidptr",
+                "child_tidptr",
+                "tls"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_return_kind() {
+        assert_eq!(Sysno::openat.return_kind(), ArgKind::Fd);
+        assert_eq!(Sysno::brk.return_kind(), ArgKind::Ptr);
+        assert_eq!(Sysno::getpid.return_kind(), ArgKind::Int);
+    }
+}
@@ -14,8 +14,10 @@ mod arm_thumb;
 mod loongarch64;
 #[cfg(target_arch = "mips")]
 mod mips;
-#[cfg(target_arch = "mips64")]
+#[cfg(all(target_arch = "mips64", not(target_abi = "abin32")))]
 mod mips64;
+#[cfg(all(target_arch = "mips64", target_abi = "abin32"))]
+mod mipsn32;
 #[cfg(target_arch = "powerpc")]
 mod powerpc;
 #[cfg(target_arch = "powerpc64")]
@@ -30,9 +32,11 @@ mod s390x;
 mod sparc;
 #[cfg(target_arch = "sparc64")]
 mod sparc64;
+#[cfg(all(target_arch = "x86_64", target_abi = "x32"))]
+mod x32;
 #[cfg(target_arch = "x86")]
 mod x86;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(target_abi = "x32")))]
 mod x86_64;
 
 #[cfg(target_arch = "aarch64")]
@@ -56,9 +60,12 @@ pub use loongarch64::*;
 #[cfg(target_arch = "mips")]
 pub use mips::*;
 
-#[cfg(target_arch = "mips64")]
+#[cfg(all(target_arch = "mips64", not(target_abi = "abin32")))]
 pub use mips64::*;
 
+#[cfg(all(target_arch = "mips64", target_abi = "abin32"))]
+pub use mipsn32::*;
+
 #[cfg(target_arch = "powerpc")]
 pub use powerpc::*;
 
@@ -83,5 +90,8 @@ pub use sparc64::*;
 #[cfg(target_arch = "x86")]
 pub use x86::*;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(target_abi = "x32")))]
 pub use x86_64::*;
+
+#[cfg(all(target_arch = "x86_64", target_abi = "x32"))]
+pub use x32::*;
@@ -1 +1,225 @@
+// SPARC64 uses the following registers for args 1-6:
+//
+// arg1: %o0
+// arg2: %o1
+// arg3: %o2
+// arg4: %o3
+// arg5: %o4
+// arg6: %o5
+//
+// Register %g1 specifies the syscall number.
+// Register %o0 is also used for the return value.
+// Register %o1 is clobbered (e.g., `fork` returns a second value in it).
+//
+// The `ta 0x6d` instruction traps into the kernel. If the syscall failed, the
+// kernel sets the carry bit in the 64-bit condition codes (`xcc`) and %o0
+// holds a positive error code. We negate it in that case so that the return
+// value follows the same convention as every other architecture.
+//
+// NOTE: LLVM reserves %g1 and does not allow it to be used as an operand for
+// inline assembly. Thus, it must be set from within the assembly block.
+use core::arch::asm;
 
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: usize) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        lateout("o0") ret,
+        lateout("o1") _,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: usize, arg1: usize) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        lateout("o1") _,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(n: usize, arg1: usize, arg2: usize) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        inlateout("o1") arg2 => _,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        inlateout("o1") arg2 => _,
+        in("o2") arg3,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        inlateout("o1") arg2 => _,
+        in("o2") arg3,
+        in("o3") arg4,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        inlateout("o1") arg2 => _,
+        in("o2") arg3,
+        in("o3") arg4,
+        in("o4") arg5,
+        options(nostack)
+    );
+    ret
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    let mut ret: usize;
+    asm!(
+        "mov {n}, %g1",
+        "ta 0x6d",
+        "bcc,pt %xcc, 1f",
+        "nop",
+        "sub %g0, %o0, %o0",
+        "1:",
+        n = in(reg) n,
+        inlateout("o0") arg1 => ret,
+        inlateout("o1") arg2 => _,
+        in("o2") arg3,
+        in("o3") arg4,
+        in("o4") arg5,
+        in("o5") arg6,
+        options(nostack)
+    );
+    ret
+}
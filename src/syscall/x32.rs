@@ -0,0 +1,189 @@
+// The x32 ABI uses the same registers as x86-64, but `usize` is only 32 bits
+// wide. The upper half of a register is undefined when a 32-bit value is
+// passed to `asm!()`, so every value is zero-extended to 64 bits first, which
+// is what the kernel expects of 32-bit pointers. The 64-bit return value is
+// truncated, which keeps `-errno` values intact.
+//
+// The syscall numbers have `__X32_SYSCALL_BIT` (0x40000000) set, which is
+// already included in the x32 `Sysno` table.
+use core::arch::asm;
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: usize) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: usize, arg1: usize) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(n: usize, arg1: usize, arg2: usize) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        in("rsi") arg2 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        in("rsi") arg2 as u64,
+        in("rdx") arg3 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        in("rsi") arg2 as u64,
+        in("rdx") arg3 as u64,
+        in("r10") arg4 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        in("rsi") arg2 as u64,
+        in("rdx") arg3 as u64,
+        in("r10") arg4 as u64,
+        in("r8")  arg5 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> usize {
+    let mut ret: u64;
+    asm!(
+        "syscall",
+        inlateout("rax") n as u64 => ret,
+        in("rdi") arg1 as u64,
+        in("rsi") arg2 as u64,
+        in("rdx") arg3 as u64,
+        in("r10") arg4 as u64,
+        in("r8")  arg5 as u64,
+        in("r9")  arg6 as u64,
+        out("rcx") _, // rcx is used to store old rip
+        out("r11") _, // r11 is used to store old rflags
+        options(nostack, preserves_flags)
+    );
+    ret as usize
+}
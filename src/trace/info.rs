@@ -0,0 +1,267 @@
+use core::mem::size_of;
+
+use crate::seccomp::AUDIT_ARCH;
+use crate::{AnySysno, Errno, RawSysno, SyscallArgs, Sysno};
+
+const PTRACE_SYSCALL_INFO_NONE: u8 = 0;
+const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+const PTRACE_SYSCALL_INFO_SECCOMP: u8 = 3;
+
+/// Size of the fields that every `struct ptrace_syscall_info` has.
+const HEADER_SIZE: usize = 24;
+
+/// Size of the largest `struct ptrace_syscall_info`.
+pub(super) const SYSCALL_INFO_SIZE: usize = HEADER_SIZE + 64;
+
+/// Information about the syscall that a tracee is stopped at, as returned by
+/// `PTRACE_GET_SYSCALL_INFO`. This is available since Linux 5.3.
+///
+/// Unlike the register set, this has the same layout on every architecture
+/// and reports the architecture of the syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SyscallInfo {
+    /// The `AUDIT_ARCH_*` value of the syscall.
+    pub arch: u32,
+    /// The instruction pointer of the tracee.
+    pub instruction_pointer: u64,
+    /// The stack pointer of the tracee.
+    pub stack_pointer: u64,
+    /// The kind of stop and its data.
+    pub op: SyscallOp,
+}
+
+/// The kind of stop that a [`SyscallInfo`] describes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SyscallOp {
+    /// The tracee isn't stopped at a syscall.
+    None,
+    /// The tracee is stopped at syscall entry.
+    Entry {
+        /// The raw syscall number.
+        nr: u64,
+        /// The syscall arguments.
+        args: SyscallArgs,
+    },
+    /// The tracee is stopped at syscall exit.
+    Exit {
+        /// The return value of the syscall.
+        ret: Result<usize, Errno>,
+    },
+    /// The tracee is stopped because a seccomp filter returned
+    /// [`Action::Trace`](crate::seccomp::Action::Trace).
+    Seccomp {
+        /// The raw syscall number.
+        nr: u64,
+        /// The syscall arguments.
+        args: SyscallArgs,
+        /// The data of the `SECCOMP_RET_TRACE` value.
+        ret_data: u32,
+    },
+}
+
+impl SyscallInfo {
+    /// Parses the buffer filled in by `PTRACE_GET_SYSCALL_INFO`. The buffer
+    /// should be truncated to the size that the kernel returned.
+    ///
+    /// Returns `None` if the buffer is too small for its kind of stop or if
+    /// the kind of stop isn't known.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let op = match *buf.first()? {
+            PTRACE_SYSCALL_INFO_NONE => SyscallOp::None,
+            PTRACE_SYSCALL_INFO_ENTRY => SyscallOp::Entry {
+                nr: read_u64(buf, HEADER_SIZE)?,
+                args: read_args(buf, HEADER_SIZE + 8)?,
+            },
+            PTRACE_SYSCALL_INFO_EXIT => {
+                let rval = read_u64(buf, HEADER_SIZE)?;
+                let is_error = *buf.get(HEADER_SIZE + 8)? != 0;
+
+                SyscallOp::Exit {
+                    ret: if is_error {
+                        Errno::from_ret(rval as usize)
+                    } else {
+                        Ok(rval as usize)
+                    },
+                }
+            }
+            PTRACE_SYSCALL_INFO_SECCOMP => SyscallOp::Seccomp {
+                nr: read_u64(buf, HEADER_SIZE)?,
+                args: read_args(buf, HEADER_SIZE + 8)?,
+                ret_data: read_u32(buf, HEADER_SIZE + 56)?,
+            },
+            _ => return None,
+        };
+
+        Some(Self {
+            arch: read_u32(buf, 4)?,
+            instruction_pointer: read_u64(buf, 8)?,
+            stack_pointer: read_u64(buf, 16)?,
+            op,
+        })
+    }
+
+    /// Returns the syscall at an entry or seccomp stop. Returns `None` for
+    /// other stops, or if the syscall wasn't made with the native
+    /// architecture (see [`AUDIT_ARCH`]), or if the syscall number is unknown.
+    pub fn sysno(&self) -> Option<Sysno> {
+        match self.op {
+            SyscallOp::Entry { nr, .. } | SyscallOp::Seccomp { nr, .. }
+                if self.arch == AUDIT_ARCH =>
+            {
+                Sysno::new(usize::try_from(nr).ok()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Like [`SyscallInfo::sysno`], but also returns syscall numbers that
+    /// aren't in the syscall table.
+    pub fn raw_sysno(&self) -> Option<RawSysno> {
+        match self.op {
+            SyscallOp::Entry { nr, .. } | SyscallOp::Seccomp { nr, .. }
+                if self.arch == AUDIT_ARCH =>
+            {
+                usize::try_from(nr).ok().map(RawSysno::new)
+            }
+            _ => None,
+        }
+    }
+
+    /// Same as [`SyscallInfo::sysno`], but for any enabled architecture. This
+    /// also resolves syscalls of compat processes, such as i386 processes on
+    /// x86-64 with the `x86` feature enabled.
+    pub fn any_sysno(&self) -> Option<AnySysno> {
+        match self.op {
+            SyscallOp::Entry { nr, .. } | SyscallOp::Seccomp { nr, .. } => {
+                AnySysno::from_audit_arch(self.arch, usize::try_from(nr).ok()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the syscall arguments at an entry or seccomp stop.
+    pub fn args(&self) -> Option<SyscallArgs> {
+        match self.op {
+            SyscallOp::Entry { args, .. } | SyscallOp::Seccomp { args, .. } => {
+                Some(args)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the syscall return value at an exit stop.
+    pub fn ret(&self) -> Option<Result<usize, Errno>> {
+        match self.op {
+            SyscallOp::Exit { ret } => Some(ret),
+            _ => None,
+        }
+    }
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buf.get(offset..offset + size_of::<u32>())?;
+    Some(u32::from_ne_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u64(buf: &[u8], offset: usize) -> Option<u64> {
+    let bytes = buf.get(offset..offset + size_of::<u64>())?;
+    Some(u64::from_ne_bytes(bytes.try_into().ok()?))
+}
+
+fn read_args(buf: &[u8], offset: usize) -> Option<SyscallArgs> {
+    let mut args = [0usize; 6];
+
+    for (i, arg) in args.iter_mut().enumerate() {
+        *arg = read_u64(buf, offset + i * 8)? as usize;
+    }
+
+    Some(SyscallArgs::from(&args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(op: u8, arch: u32) -> Vec<u8> {
+        let mut buf = vec![op, 0, 0, 0];
+        buf.extend(arch.to_ne_bytes());
+        buf.extend(0x1000u64.to_ne_bytes());
+        buf.extend(0x2000u64.to_ne_bytes());
+        buf
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let mut buf = header(PTRACE_SYSCALL_INFO_ENTRY, AUDIT_ARCH);
+        buf.extend((Sysno::openat.id() as u64).to_ne_bytes());
+        for arg in 1..=6u64 {
+            buf.extend(arg.to_ne_bytes());
+        }
+
+        let info = SyscallInfo::parse(&buf).unwrap();
+        assert_eq!(info.instruction_pointer, 0x1000);
+        assert_eq!(info.stack_pointer, 0x2000);
+        assert_eq!(info.sysno(), Some(Sysno::openat));
+        assert_eq!(info.any_sysno(), Some(AnySysno::from(Sysno::openat)));
+        assert_eq!(info.args(), Some(SyscallArgs::new(1, 2, 3, 4, 5, 6)));
+        assert_eq!(info.ret(), None);
+
+        // Numbers outside the table are kept.
+        let mut unknown = buf.clone();
+        unknown[HEADER_SIZE..HEADER_SIZE + 8]
+            .copy_from_slice(&0xdeadu64.to_ne_bytes());
+        let info = SyscallInfo::parse(&unknown).unwrap();
+        assert_eq!(info.sysno(), None);
+        assert_eq!(info.raw_sysno(), Some(RawSysno::new(0xdead)));
+
+        // A different architecture doesn't use the native table.
+        buf[4..8].copy_from_slice(&0u32.to_ne_bytes());
+        assert_eq!(SyscallInfo::parse(&buf).unwrap().sysno(), None);
+        assert_eq!(SyscallInfo::parse(&buf).unwrap().any_sysno(), None);
+        assert_eq!(SyscallInfo::parse(&buf).unwrap().raw_sysno(), None);
+
+        // Truncated.
+        assert_eq!(SyscallInfo::parse(&buf[..40]), None);
+    }
+
+    #[test]
+    fn test_parse_exit() {
+        let mut buf = header(PTRACE_SYSCALL_INFO_EXIT, AUDIT_ARCH);
+        buf.extend((-2i64).to_ne_bytes());
+        buf.extend([1, 0, 0, 0, 0, 0, 0, 0]);
+
+        let info = SyscallInfo::parse(&buf).unwrap();
+        assert_eq!(info.ret(), Some(Err(Errno::ENOENT)));
+        assert_eq!(info.sysno(), None);
+
+        buf[HEADER_SIZE..HEADER_SIZE + 8].copy_from_slice(&3u64.to_ne_bytes());
+        buf[HEADER_SIZE + 8] = 0;
+        let info = SyscallInfo::parse(&buf).unwrap();
+        assert_eq!(info.ret(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_parse_seccomp() {
+        let mut buf = header(PTRACE_SYSCALL_INFO_SECCOMP, AUDIT_ARCH);
+        buf.extend((Sysno::close.id() as u64).to_ne_bytes());
+        for arg in 0..6u64 {
+            buf.extend(arg.to_ne_bytes());
+        }
+        buf.extend(42u32.to_ne_bytes());
+
+        let info = SyscallInfo::parse(&buf).unwrap();
+        assert_eq!(info.sysno(), Some(Sysno::close));
+        assert!(matches!(info.op, SyscallOp::Seccomp { ret_data: 42, .. }));
+    }
+
+    #[test]
+    fn test_parse_none() {
+        let buf = header(PTRACE_SYSCALL_INFO_NONE, AUDIT_ARCH);
+        let info = SyscallInfo::parse(&buf).unwrap();
+        assert_eq!(info.op, SyscallOp::None);
+
+        assert_eq!(SyscallInfo::parse(&header(42, AUDIT_ARCH)), None);
+        assert_eq!(SyscallInfo::parse(&[]), None);
+    }
+}
@@ -0,0 +1,416 @@
+//! Tracing the syscalls of a process with `ptrace(2)`.
+//!
+//! A [`Tracer`] either spawns a new process or attaches to an existing one
+//! and then reports every syscall the process makes as a pair of
+//! [`SyscallEvent`]s: one when the syscall is entered and one when it
+//! returns.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::process::Command;
+//! use syscalls::trace::{SyscallEvent, Tracer};
+//!
+//! let mut tracer = Tracer::spawn(&mut Command::new("true")).unwrap();
+//!
+//! for event in &mut tracer {
+//!     match event.unwrap() {
+//!         SyscallEvent::Enter { sysno, args } => {
+//!             println!("{}({:#x}, {:#x}, ...)", sysno, args.arg0, args.arg1);
+//!         }
+//!         SyscallEvent::Exit { sysno, ret } => {
+//!             println!("{} = {:?}", sysno, ret);
+//!         }
+//!     }
+//! }
+//!
+//! println!("{:?}", tracer.exit_status());
+//! ```
+
+mod info;
+
+pub use info::*;
+
+use core::marker::PhantomData;
+use std::io;
+use std::os::unix::process::{CommandExt, ExitStatusExt};
+use std::process::{Command, ExitStatus};
+
+use crate::{Errno, Regs, SyscallArgs, SyscallRegs, Sysno};
+
+const PTRACE_TRACEME: usize = 0;
+#[cfg(target_arch = "mips")]
+const PTRACE_PEEKDATA: usize = 2;
+const PTRACE_DETACH: usize = 17;
+const PTRACE_SYSCALL: usize = 24;
+const PTRACE_SETOPTIONS: usize = 0x4200;
+const PTRACE_GETSIGINFO: usize = 0x4202;
+const PTRACE_GETREGSET: usize = 0x4204;
+const PTRACE_SEIZE: usize = 0x4206;
+const PTRACE_INTERRUPT: usize = 0x4207;
+const PTRACE_GET_SYSCALL_INFO: usize = 0x420e;
+
+const PTRACE_O_TRACESYSGOOD: usize = 1 << 0;
+const PTRACE_O_TRACEEXEC: usize = 1 << 4;
+const PTRACE_O_EXITKILL: usize = 1 << 20;
+
+const NT_PRSTATUS: usize = 1;
+const WALL: usize = 0x4000_0000;
+
+const SIGKILL: usize = 9;
+const SIGTRAP: i32 = 5;
+
+/// A syscall made by the tracee.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SyscallEvent {
+    /// The tracee is about to execute a syscall.
+    Enter {
+        /// The syscall.
+        sysno: Sysno,
+        /// The arguments of the syscall.
+        args: SyscallArgs,
+    },
+    /// A syscall returned. This always follows the matching
+    /// [`SyscallEvent::Enter`], except for syscalls that don't return, such
+    /// as `exit_group`.
+    Exit {
+        /// The syscall.
+        sysno: Sysno,
+        /// The return value of the syscall.
+        ret: Result<usize, Errno>,
+    },
+}
+
+/// Where the tracee is with respect to its current syscall.
+#[derive(Debug, Clone, Copy)]
+enum SyscallState {
+    /// Not in a syscall.
+    Outside,
+    /// In a syscall. The syscall is `None` if it isn't in the native table.
+    Inside(Option<Sysno>),
+}
+
+/// Traces the syscalls of a single thread.
+///
+/// Only syscalls made with the native architecture are reported, since the
+/// arguments are decoded from the native register set. Children of the
+/// tracee are not traced.
+///
+/// The kernel only accepts `ptrace` requests from the thread that is tracing
+/// the tracee, so a `Tracer` can't be sent to another thread.
+///
+/// When dropped, a spawned tracee is killed and an attached tracee is
+/// detached.
+#[derive(Debug)]
+pub struct Tracer {
+    pid: i32,
+    spawned: bool,
+    state: SyscallState,
+    /// The signal to deliver when the tracee is resumed.
+    signal: usize,
+    exit_status: Option<ExitStatus>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Tracer {
+    /// Spawns the command as a tracee. The tracee is stopped right after its
+    /// `execve` and starts running with the first call to
+    /// [`Tracer::next_event`].
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        // SAFETY: The closure only makes a syscall, which is safe to do after
+        // a fork.
+        unsafe {
+            command.pre_exec(|| {
+                syscall!(Sysno::ptrace, PTRACE_TRACEME, 0, 0, 0)?;
+                Ok(())
+            });
+        }
+
+        let child = command.spawn()?;
+
+        let mut tracer = Self::new(child.id() as i32, true);
+
+        // The tracee stops with a `SIGTRAP` after a successful `execve`.
+        tracer.wait()?;
+        tracer.ptrace(
+            PTRACE_SETOPTIONS,
+            0,
+            PTRACE_O_TRACESYSGOOD | PTRACE_O_TRACEEXEC | PTRACE_O_EXITKILL,
+        )?;
+
+        Ok(tracer)
+    }
+
+    /// Attaches to the thread with the given ID and stops it. The tracee
+    /// continues running with the first call to [`Tracer::next_event`].
+    ///
+    /// If the tracee is in the middle of a syscall, the exit of that syscall
+    /// isn't reported, since its entry wasn't seen. Telling the exit apart
+    /// from an entry requires `PTRACE_GET_SYSCALL_INFO` (Linux 5.3). On older
+    /// kernels, stops are assumed to alternate between entry and exit, so
+    /// the events are mismatched if the tracee was in a syscall.
+    pub fn attach(pid: i32) -> Result<Self, Errno> {
+        let mut tracer = Self::new(pid, false);
+
+        tracer.ptrace(
+            PTRACE_SEIZE,
+            0,
+            PTRACE_O_TRACESYSGOOD | PTRACE_O_TRACEEXEC,
+        )?;
+        tracer.ptrace(PTRACE_INTERRUPT, 0, 0)?;
+
+        let status = tracer.wait()?;
+
+        // A signal could have arrived before the interrupt. It needs to be
+        // delivered once the tracee is resumed.
+        if status >> 16 == 0 {
+            tracer.signal = stop_signal(status) as usize;
+        }
+
+        Ok(tracer)
+    }
+
+    fn new(pid: i32, spawned: bool) -> Self {
+        Self {
+            pid,
+            spawned,
+            state: SyscallState::Outside,
+            signal: 0,
+            exit_status: None,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns the process ID of the tracee.
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Returns the exit status of the tracee if it has exited.
+    pub fn exit_status(&self) -> Option<ExitStatus> {
+        self.exit_status
+    }
+
+    /// Resumes the tracee and waits for its next syscall event. Returns
+    /// `None` once the tracee has exited.
+    pub fn next_event(&mut self) -> Result<Option<SyscallEvent>, Errno> {
+        while self.exit_status.is_none() {
+            let signal = core::mem::take(&mut self.signal);
+            self.ptrace(PTRACE_SYSCALL, 0, signal)?;
+
+            let status = self.wait()?;
+
+            if self.exit_status.is_some() {
+                break;
+            } else if stop_signal(status) == SIGTRAP | 0x80 {
+                if let Some(event) = self.syscall_stop()? {
+                    return Ok(Some(event));
+                }
+            } else if status >> 16 != 0 {
+                // A `PTRACE_EVENT_*` stop, such as after an `execve` or the
+                // group-stop of a seized tracee.
+            } else if !self.is_group_stop()? {
+                self.signal = stop_signal(status) as usize;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns information about the syscall that the tracee is stopped at.
+    /// This uses `PTRACE_GET_SYSCALL_INFO`, which is available since Linux
+    /// 5.3.
+    ///
+    /// Unlike [`SyscallEvent`]s, this also describes syscalls that weren't
+    /// made with the native architecture.
+    pub fn syscall_info(&self) -> Result<SyscallInfo, Errno> {
+        let mut buf = [0u8; SYSCALL_INFO_SIZE];
+
+        // SAFETY: The kernel writes at most `buf.len()` bytes.
+        let len = unsafe {
+            syscall!(
+                Sysno::ptrace,
+                PTRACE_GET_SYSCALL_INFO,
+                self.pid,
+                buf.len(),
+                buf.as_mut_ptr()
+            )
+        }?;
+
+        SyscallInfo::parse(&buf[..len.min(buf.len())]).ok_or(Errno::EINVAL)
+    }
+
+    /// Detaches from the tracee and lets it continue running.
+    pub fn detach(self) -> Result<(), Errno> {
+        self.ptrace(PTRACE_DETACH, 0, self.signal)?;
+        core::mem::forget(self);
+        Ok(())
+    }
+
+    fn syscall_stop(&mut self) -> Result<Option<SyscallEvent>, Errno> {
+        // The kernel knows whether this is an entry or an exit. Without
+        // `PTRACE_GET_SYSCALL_INFO`, they can only be assumed to alternate.
+        let entering = match self.syscall_info().map(|info| info.op) {
+            Ok(SyscallOp::Entry { .. }) => true,
+            Ok(SyscallOp::Exit { .. }) => false,
+            _ => matches!(self.state, SyscallState::Outside),
+        };
+
+        let Some(regs) = self.regs()? else {
+            // Not a native syscall.
+            self.state = if entering {
+                SyscallState::Inside(None)
+            } else {
+                SyscallState::Outside
+            };
+            return Ok(None);
+        };
+
+        if entering {
+            let sysno = regs.sysno();
+            self.state = SyscallState::Inside(sysno);
+
+            match sysno {
+                Some(sysno) => Ok(Some(SyscallEvent::Enter {
+                    sysno,
+                    args: self.args(&regs)?,
+                })),
+                None => Ok(None),
+            }
+        } else {
+            // The entry wasn't seen if the tracee was attached in the middle
+            // of the syscall.
+            match core::mem::replace(&mut self.state, SyscallState::Outside) {
+                SyscallState::Inside(sysno) => {
+                    Ok(sysno.map(|sysno| SyscallEvent::Exit {
+                        sysno,
+                        ret: regs.ret(),
+                    }))
+                }
+                SyscallState::Outside => Ok(None),
+            }
+        }
+    }
+
+    /// Reads the registers of the tracee. Returns `None` if the tracee uses a
+    /// different register set than the native one, such as a 32-bit process
+    /// traced by a 64-bit tracer.
+    fn regs(&self) -> Result<Option<Regs>, Errno> {
+        const SIZE: usize = core::mem::size_of::<Regs>();
+
+        let mut regs = Regs::default();
+        let mut iov = [&mut regs as *mut Regs as usize, SIZE];
+
+        self.ptrace(PTRACE_GETREGSET, NT_PRSTATUS, iov.as_mut_ptr() as usize)?;
+
+        Ok((iov[1] == SIZE).then_some(regs))
+    }
+
+    /// Returns the arguments of the syscall that the tracee is entering.
+    fn args(&self, regs: &Regs) -> Result<SyscallArgs, Errno> {
+        #[allow(unused_mut)]
+        let mut args = SyscallArgs::from_regs(regs);
+
+        // The last two arguments are passed on the stack.
+        #[cfg(target_arch = "mips")]
+        {
+            let sp = regs.regs[29] as usize;
+            args.arg4 = self.peek(sp + 16)?;
+            args.arg5 = self.peek(sp + 20)?;
+        }
+
+        Ok(args)
+    }
+
+    /// Reads a word from the memory of the tracee.
+    #[cfg(target_arch = "mips")]
+    fn peek(&self, addr: usize) -> Result<usize, Errno> {
+        let mut word = 0usize;
+        self.ptrace(PTRACE_PEEKDATA, addr, &mut word as *mut usize as usize)?;
+        Ok(word)
+    }
+
+    /// Returns true if the tracee is in a group-stop rather than a
+    /// signal-delivery-stop. Only the latter have signal info.
+    fn is_group_stop(&self) -> Result<bool, Errno> {
+        let mut siginfo = [0u64; 16];
+
+        match self.ptrace(PTRACE_GETSIGINFO, 0, siginfo.as_mut_ptr() as usize) {
+            Ok(()) => Ok(false),
+            Err(Errno::EINVAL) => Ok(true),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Waits for the tracee to stop or exit and returns the wait status.
+    fn wait(&mut self) -> Result<i32, Errno> {
+        let mut status = 0i32;
+
+        loop {
+            // SAFETY: `status` outlives the syscall.
+            match unsafe {
+                syscall!(
+                    Sysno::wait4,
+                    self.pid,
+                    &mut status as *mut i32,
+                    WALL,
+                    0
+                )
+            } {
+                Ok(_) => break,
+                Err(Errno::EINTR) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        // Anything other than a stop means that the tracee is gone.
+        if status & 0xff != 0x7f {
+            self.exit_status = Some(ExitStatus::from_raw(status));
+        }
+
+        Ok(status)
+    }
+
+    fn ptrace(
+        &self,
+        request: usize,
+        addr: usize,
+        data: usize,
+    ) -> Result<(), Errno> {
+        // SAFETY: All requests that take a pointer get one to memory that
+        // lives for the duration of the syscall.
+        unsafe { syscall!(Sysno::ptrace, request, self.pid, addr, data) }
+            .map(drop)
+    }
+}
+
+impl Iterator for Tracer {
+    type Item = Result<SyscallEvent, Errno>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        if self.exit_status.is_some() {
+            return;
+        }
+
+        if self.spawned {
+            // SAFETY: Sending a signal to our own child has no memory safety
+            // implications.
+            let _ = unsafe { syscall!(Sysno::kill, self.pid, SIGKILL) };
+
+            while self.exit_status.is_none() && self.wait().is_ok() {}
+        } else {
+            let _ = self.ptrace(PTRACE_DETACH, 0, self.signal);
+        }
+    }
+}
+
+/// Returns the signal that stopped the tracee.
+fn stop_signal(status: i32) -> i32 {
+    (status >> 8) & 0xff
+}
@@ -0,0 +1,822 @@
+//! Translation of syscalls between architectures.
+//!
+//! A syscall is translated to the syscall with the same name on the other
+//! architecture if there is one. Otherwise, it is translated to its closest
+//! equivalent using the rules below, such as `open` to `openat` on
+//! architectures that only have the `*at` variants. See
+//! [`AnySysno::translate`].
+//!
+//! Only the syscall number and the arguments in registers are translated. The
+//! memory that arguments point to (such as a `struct stat` or a `struct
+//! timespec`) may have a different layout, and flags (such as `O_CREAT`) may
+//! have different values. 64-bit arguments that are split into two registers
+//! on 32-bit architectures aren't joined or split either.
+
+use crate::{AnySysno, Arch, Errno, SyscallArgs};
+
+use ArgMap::*;
+
+const AT_FDCWD: usize = -100isize as usize;
+const AT_SYMLINK_NOFOLLOW: usize = 0x100;
+const AT_REMOVEDIR: usize = 0x200;
+const AT_EMPTY_PATH: usize = 0x1000;
+
+/// The unit of the offset of `mmap2`, regardless of the page size.
+const MMAP2_UNIT: usize = 4096;
+
+/// How to compute an argument of a translated syscall.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ArgMap {
+    /// The argument of the original syscall with this index.
+    Arg(usize),
+    /// A constant value.
+    Const(usize),
+    /// The argument of the original syscall with this index, which is an
+    /// offset in bytes, converted to an `mmap2` offset in units of 4096 bytes.
+    Pages(usize),
+    /// The argument of the original syscall with this index, which is an
+    /// `mmap2` offset in units of 4096 bytes, converted to bytes.
+    Bytes(usize),
+    /// A pointer to an empty string, as used with `AT_EMPTY_PATH`.
+    EmptyPath,
+}
+
+/// A syscall translated to another architecture. See
+/// [`AnySysno::translate`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Translation {
+    sysno: AnySysno,
+    args: &'static [ArgMap],
+}
+
+impl Translation {
+    /// Returns the syscall on the other architecture.
+    pub const fn sysno(&self) -> AnySysno {
+        self.sysno
+    }
+
+    /// Returns how each argument of the translated syscall is computed.
+    pub const fn args(&self) -> &'static [ArgMap] {
+        self.args
+    }
+
+    /// Returns true if the arguments are passed through unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.args == IDENTITY
+    }
+
+    /// Computes the arguments of the translated syscall from the arguments of
+    /// the original syscall. `empty_path` is the address of an empty string in
+    /// the process that will make the syscall and is only used for
+    /// [`ArgMap::EmptyPath`].
+    ///
+    /// Returns `EINVAL` if an offset isn't a multiple of 4096 bytes and
+    /// `EOVERFLOW` if it doesn't fit into an argument, just like the kernel
+    /// would for the original syscall.
+    pub fn apply(
+        &self,
+        args: &SyscallArgs,
+        empty_path: usize,
+    ) -> Result<SyscallArgs, Errno> {
+        let from = [
+            args.arg0, args.arg1, args.arg2, args.arg3, args.arg4, args.arg5,
+        ];
+        let mut to = [0; 6];
+
+        for (value, map) in to.iter_mut().zip(self.args) {
+            *value = match *map {
+                Arg(index) => from[index],
+                Const(value) => value,
+                Pages(index) if !from[index].is_multiple_of(MMAP2_UNIT) => {
+                    return Err(Errno::EINVAL);
+                }
+                Pages(index) => from[index] / MMAP2_UNIT,
+                Bytes(index) => from[index]
+                    .checked_mul(MMAP2_UNIT)
+                    .ok_or(Errno::EOVERFLOW)?,
+                EmptyPath => empty_path,
+            };
+        }
+
+        Ok(SyscallArgs::from(&to))
+    }
+}
+
+impl AnySysno {
+    /// Translates this syscall to its closest equivalent on another
+    /// architecture. Returns `None` if there is no equivalent or if the other
+    /// architecture isn't enabled.
+    ///
+    /// See the [module documentation](crate::translate) for what is and isn't
+    /// translated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "x86_64", feature = "aarch64"))] {
+    /// use syscalls::{x86_64, AnySysno, Arch, SyscallArgs};
+    ///
+    /// let open = AnySysno::from(x86_64::Sysno::open);
+    /// let openat = open.translate(Arch::Aarch64).unwrap();
+    /// assert_eq!(openat.sysno().name(), "openat");
+    ///
+    /// let args = openat.apply(&SyscallArgs::new(0x1000, 2, 0o644, 0, 0, 0), 0);
+    /// assert_eq!(
+    ///     args,
+    ///     Ok(SyscallArgs::new(-100isize as usize, 0x1000, 2, 0o644, 0, 0))
+    /// );
+    /// # }
+    /// ```
+    pub fn translate(&self, to: Arch) -> Option<Translation> {
+        let name = self.name();
+
+        if self.arch() == to {
+            return Some(Translation {
+                sysno: *self,
+                args: IDENTITY,
+            });
+        }
+
+        if !is_compatible(self.arch(), name) {
+            return None;
+        }
+
+        if let Some(sysno) = lookup(to, name) {
+            let args = match name {
+                "clone" => clone_args(self.arch(), to),
+                _ => IDENTITY,
+            };
+
+            return Some(Translation { sysno, args });
+        }
+
+        for rule in RULES {
+            if rule.from == name {
+                if let Some(sysno) = lookup(to, rule.to) {
+                    return Some(Translation {
+                        sysno,
+                        args: rule.args,
+                    });
+                }
+            }
+        }
+
+        for (time32, time64) in TIME64 {
+            let other = if name == *time32 {
+                time64
+            } else if name == *time64 {
+                time32
+            } else {
+                continue;
+            };
+
+            return lookup(to, other).map(|sysno| Translation {
+                sysno,
+                args: IDENTITY,
+            });
+        }
+
+        None
+    }
+}
+
+/// Looks up a syscall that can be the target of a translation.
+fn lookup(arch: Arch, name: &str) -> Option<AnySysno> {
+    if is_compatible(arch, name) {
+        AnySysno::from_name(arch, name)
+    } else {
+        None
+    }
+}
+
+/// Returns false for syscalls that don't take the same arguments as the
+/// syscalls with the same name on other architectures.
+fn is_compatible(arch: Arch, name: &str) -> bool {
+    if INCOMPATIBLE.contains(&(arch, name)) {
+        return false;
+    }
+
+    // The 64-bit architectures that use the generic syscall table list the
+    // `*_time64` syscalls, but they are only implemented for 32-bit
+    // architectures.
+    if matches!(arch, Arch::Aarch64 | Arch::LoongArch64) {
+        return !TIME64.iter().any(|(_, time64)| *time64 == name);
+    }
+
+    true
+}
+
+/// Returns how the arguments of `clone` are reordered between two
+/// architectures. The generic order is `clone_flags`, `newsp`,
+/// `parent_tidptr`, `child_tidptr` and `tls`.
+fn clone_args(from: Arch, to: Arch) -> &'static [ArgMap] {
+    // Whether `newsp` comes first and whether `tls` comes before
+    // `child_tidptr`.
+    let order = |arch| {
+        (
+            CLONE_BACKWARDS2.contains(&arch),
+            CLONE_BACKWARDS.contains(&arch),
+        )
+    };
+
+    let (from, to) = (order(from), order(to));
+
+    match (from.0 != to.0, from.1 != to.1) {
+        (false, false) => IDENTITY,
+        (true, false) => &[Arg(1), Arg(0), Arg(2), Arg(3), Arg(4)],
+        (false, true) => &[Arg(0), Arg(1), Arg(2), Arg(4), Arg(3)],
+        (true, true) => &[Arg(1), Arg(0), Arg(2), Arg(4), Arg(3)],
+    }
+}
+
+/// Architectures where `clone` takes `tls` before `child_tidptr`
+/// (`CONFIG_CLONE_BACKWARDS`). Sparc doesn't select it, but reads the
+/// arguments in the same order.
+const CLONE_BACKWARDS: &[Arch] = &[
+    Arch::Aarch64,
+    Arch::Arm,
+    Arch::Mips,
+    Arch::Mips64,
+    Arch::MipsN32,
+    Arch::PowerPc,
+    Arch::PowerPc64,
+    Arch::Riscv32,
+    Arch::Riscv64,
+    Arch::Sparc,
+    Arch::Sparc64,
+    Arch::X86,
+];
+
+/// Architectures where `clone` takes `newsp` before `clone_flags`
+/// (`CONFIG_CLONE_BACKWARDS2`).
+const CLONE_BACKWARDS2: &[Arch] = &[Arch::S390x];
+
+/// Passes the arguments through unchanged.
+const IDENTITY: &[ArgMap] = &[Arg(0), Arg(1), Arg(2), Arg(3), Arg(4), Arg(5)];
+
+/// Syscalls that take their arguments in a different way than the syscalls
+/// with the same name on other architectures.
+const INCOMPATIBLE: &[(Arch, &str)] = &[
+    // `old_mmap` and `old_select` take a pointer to their arguments.
+    (Arch::X86, "mmap"),
+    (Arch::X86, "select"),
+    (Arch::S390x, "mmap"),
+    // `pipe` returns both file descriptors in registers instead of writing
+    // them to its argument.
+    (Arch::Mips, "pipe"),
+    (Arch::Mips64, "pipe"),
+    (Arch::MipsN32, "pipe"),
+    (Arch::Sparc, "pipe"),
+    (Arch::Sparc64, "pipe"),
+];
+
+/// A syscall with a different name and arguments on another architecture.
+struct Rule {
+    from: &'static str,
+    to: &'static str,
+    args: &'static [ArgMap],
+}
+
+/// Equivalents of syscalls, tried in order until one exists on the other
+/// architecture.
+const RULES: &[Rule] = &[
+    Rule {
+        from: "open",
+        to: "openat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Arg(2)],
+    },
+    Rule {
+        from: "stat",
+        to: "newfstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "stat",
+        to: "fstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "stat",
+        to: "fstatat64",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "stat64",
+        to: "stat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "stat64",
+        to: "newfstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "stat64",
+        to: "fstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "lstat",
+        to: "newfstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(AT_SYMLINK_NOFOLLOW)],
+    },
+    Rule {
+        from: "lstat",
+        to: "fstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(AT_SYMLINK_NOFOLLOW)],
+    },
+    Rule {
+        from: "lstat",
+        to: "fstatat64",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(AT_SYMLINK_NOFOLLOW)],
+    },
+    Rule {
+        from: "lstat64",
+        to: "lstat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "lstat64",
+        to: "newfstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(AT_SYMLINK_NOFOLLOW)],
+    },
+    Rule {
+        from: "lstat64",
+        to: "fstatat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Const(AT_SYMLINK_NOFOLLOW)],
+    },
+    Rule {
+        from: "fstat",
+        to: "newfstatat",
+        args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+    },
+    Rule {
+        from: "fstat",
+        to: "fstatat",
+        args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+    },
+    Rule {
+        from: "fstat",
+        to: "fstatat64",
+        args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+    },
+    Rule {
+        from: "fstat64",
+        to: "fstat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "fstat64",
+        to: "newfstatat",
+        args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+    },
+    Rule {
+        from: "fstat64",
+        to: "fstatat",
+        args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+    },
+    Rule {
+        from: "newfstatat",
+        to: "fstatat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "newfstatat",
+        to: "fstatat64",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "fstatat",
+        to: "newfstatat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "fstatat",
+        to: "fstatat64",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "fstatat64",
+        to: "newfstatat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "fstatat64",
+        to: "fstatat",
+        args: IDENTITY,
+    },
+    Rule {
+        from: "mmap",
+        to: "mmap2",
+        args: &[Arg(0), Arg(1), Arg(2), Arg(3), Arg(4), Pages(5)],
+    },
+    Rule {
+        from: "mmap2",
+        to: "mmap",
+        args: &[Arg(0), Arg(1), Arg(2), Arg(3), Arg(4), Bytes(5)],
+    },
+    Rule {
+        from: "access",
+        to: "faccessat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1)],
+    },
+    Rule {
+        from: "mkdir",
+        to: "mkdirat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1)],
+    },
+    Rule {
+        from: "mknod",
+        to: "mknodat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Arg(2)],
+    },
+    Rule {
+        from: "unlink",
+        to: "unlinkat",
+        args: &[Const(AT_FDCWD), Arg(0), Const(0)],
+    },
+    Rule {
+        from: "rmdir",
+        to: "unlinkat",
+        args: &[Const(AT_FDCWD), Arg(0), Const(AT_REMOVEDIR)],
+    },
+    Rule {
+        from: "rename",
+        to: "renameat",
+        args: &[Const(AT_FDCWD), Arg(0), Const(AT_FDCWD), Arg(1)],
+    },
+    Rule {
+        from: "rename",
+        to: "renameat2",
+        args: &[Const(AT_FDCWD), Arg(0), Const(AT_FDCWD), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "renameat",
+        to: "renameat2",
+        args: &[Arg(0), Arg(1), Arg(2), Arg(3), Const(0)],
+    },
+    Rule {
+        from: "link",
+        to: "linkat",
+        args: &[Const(AT_FDCWD), Arg(0), Const(AT_FDCWD), Arg(1), Const(0)],
+    },
+    Rule {
+        from: "symlink",
+        to: "symlinkat",
+        args: &[Arg(0), Const(AT_FDCWD), Arg(1)],
+    },
+    Rule {
+        from: "readlink",
+        to: "readlinkat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Arg(2)],
+    },
+    Rule {
+        from: "chmod",
+        to: "fchmodat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1)],
+    },
+    Rule {
+        from: "chown",
+        to: "fchownat",
+        args: &[Const(AT_FDCWD), Arg(0), Arg(1), Arg(2), Const(0)],
+    },
+    Rule {
+        from: "lchown",
+        to: "fchownat",
+        args: &[
+            Const(AT_FDCWD),
+            Arg(0),
+            Arg(1),
+            Arg(2),
+            Const(AT_SYMLINK_NOFOLLOW),
+        ],
+    },
+    Rule {
+        from: "pipe",
+        to: "pipe2",
+        args: &[Arg(0), Const(0)],
+    },
+    Rule {
+        from: "epoll_create",
+        to: "epoll_create1",
+        args: &[Const(0)],
+    },
+    Rule {
+        from: "epoll_wait",
+        to: "epoll_pwait",
+        args: &[Arg(0), Arg(1), Arg(2), Arg(3), Const(0), Const(0)],
+    },
+    Rule {
+        from: "inotify_init",
+        to: "inotify_init1",
+        args: &[Const(0)],
+    },
+    Rule {
+        from: "eventfd",
+        to: "eventfd2",
+        args: &[Arg(0), Const(0)],
+    },
+    Rule {
+        from: "signalfd",
+        to: "signalfd4",
+        args: &[Arg(0), Arg(1), Arg(2), Const(0)],
+    },
+    Rule {
+        from: "getpgrp",
+        to: "getpgid",
+        args: &[Const(0)],
+    },
+];
+
+/// Syscalls that take a 32-bit `time_t` on 32-bit architectures and their
+/// counterparts that take a 64-bit `time_t`. The arguments are the same, but
+/// the structures they point to are not. On 64-bit architectures, only the
+/// former exist and they take a 64-bit `time_t`.
+const TIME64: &[(&str, &str)] = &[
+    ("clock_adjtime", "clock_adjtime64"),
+    ("clock_getres", "clock_getres_time64"),
+    ("clock_gettime", "clock_gettime64"),
+    ("clock_nanosleep", "clock_nanosleep_time64"),
+    ("clock_settime", "clock_settime64"),
+    ("futex", "futex_time64"),
+    ("io_pgetevents", "io_pgetevents_time64"),
+    ("mq_timedreceive", "mq_timedreceive_time64"),
+    ("mq_timedsend", "mq_timedsend_time64"),
+    ("ppoll", "ppoll_time64"),
+    ("pselect6", "pselect6_time64"),
+    ("recvmmsg", "recvmmsg_time64"),
+    ("rt_sigtimedwait", "rt_sigtimedwait_time64"),
+    ("sched_rr_get_interval", "sched_rr_get_interval_time64"),
+    ("semtimedop", "semtimedop_time64"),
+    ("timer_gettime", "timer_gettime64"),
+    ("timer_settime", "timer_settime64"),
+    ("timerfd_gettime", "timerfd_gettime64"),
+    ("timerfd_settime", "timerfd_settime64"),
+    ("utimensat", "utimensat_time64"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sysno;
+
+    #[test]
+    fn test_same_arch() {
+        for sysno in Sysno::iter() {
+            let sysno = AnySysno::from(sysno);
+            let translation = sysno.translate(Arch::NATIVE).unwrap();
+            assert_eq!(translation.sysno(), sysno);
+            assert!(translation.is_identity());
+        }
+    }
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn test_rules() {
+        // Catches typos in the syscall names.
+        let exists = |name| {
+            Arch::ALL
+                .iter()
+                .any(|arch| AnySysno::from_name(*arch, name).is_some())
+        };
+
+        for rule in RULES {
+            assert!(exists(rule.from), "{}", rule.from);
+            assert!(exists(rule.to), "{}", rule.to);
+            assert!(rule.args.len() <= 6);
+        }
+
+        for (time32, time64) in TIME64 {
+            assert!(exists(time32), "{time32}");
+            assert!(exists(time64), "{time64}");
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let mmap2 = Translation {
+            sysno: AnySysno::from(Sysno::mmap),
+            args: &[Arg(0), Arg(1), Arg(2), Arg(3), Arg(4), Pages(5)],
+        };
+        assert!(!mmap2.is_identity());
+        assert_eq!(
+            mmap2.apply(&SyscallArgs::new(0, 8192, 3, 0x22, 5, 0x3000), 0),
+            Ok(SyscallArgs::new(0, 8192, 3, 0x22, 5, 3))
+        );
+        assert_eq!(
+            mmap2.apply(&SyscallArgs::new(0, 8192, 3, 0x22, 5, 0x3001), 0),
+            Err(Errno::EINVAL)
+        );
+
+        let fstatat = Translation {
+            sysno: AnySysno::from(Sysno::newfstatat),
+            args: &[Arg(0), EmptyPath, Arg(1), Const(AT_EMPTY_PATH)],
+        };
+        assert_eq!(
+            fstatat.apply(&SyscallArgs::new(3, 0x1000, 0, 0, 0, 0), 0x2000),
+            Ok(SyscallArgs::new(3, 0x2000, 0x1000, AT_EMPTY_PATH, 0, 0))
+        );
+    }
+
+    #[cfg(all(feature = "x86_64", feature = "aarch64"))]
+    #[test]
+    fn test_x86_64_to_aarch64() {
+        use crate::x86_64::Sysno;
+
+        let translate = |sysno: Sysno| {
+            let translation =
+                AnySysno::from(sysno).translate(Arch::Aarch64).unwrap();
+            (translation.sysno().name(), translation.args())
+        };
+
+        assert_eq!(translate(Sysno::read), ("read", IDENTITY));
+        assert_eq!(
+            translate(Sysno::open),
+            ("openat", &[Const(AT_FDCWD), Arg(0), Arg(1), Arg(2)][..])
+        );
+        assert_eq!(translate(Sysno::stat).0, "fstatat");
+        assert_eq!(translate(Sysno::newfstatat), ("fstatat", IDENTITY));
+        assert_eq!(translate(Sysno::rmdir).0, "unlinkat");
+        assert_eq!(translate(Sysno::rename).0, "renameat");
+        assert_eq!(
+            translate(Sysno::clock_gettime),
+            ("clock_gettime", IDENTITY)
+        );
+        assert_eq!(
+            AnySysno::from(Sysno::arch_prctl).translate(Arch::Aarch64),
+            None
+        );
+    }
+
+    #[cfg(all(feature = "x86_64", feature = "x86"))]
+    #[test]
+    fn test_x86_64_to_x86() {
+        use crate::{x86, x86_64};
+
+        let mmap = AnySysno::from(x86_64::Sysno::mmap);
+        let mmap2 = mmap.translate(Arch::X86).unwrap();
+        assert_eq!(mmap2.sysno(), AnySysno::from(x86::Sysno::mmap2));
+
+        // `old_mmap` can't be translated.
+        assert_eq!(
+            AnySysno::from(x86::Sysno::mmap).translate(Arch::X86_64),
+            None
+        );
+
+        let mmap = AnySysno::from(x86::Sysno::mmap2)
+            .translate(Arch::X86_64)
+            .unwrap();
+        assert_eq!(mmap.sysno(), AnySysno::from(x86_64::Sysno::mmap));
+        assert_eq!(
+            mmap.apply(&SyscallArgs::new(0, 4096, 3, 0x22, 5, 3), 0),
+            Ok(SyscallArgs::new(0, 4096, 3, 0x22, 5, 0x3000))
+        );
+
+        let fstat = AnySysno::from(x86::Sysno::fstat64)
+            .translate(Arch::X86_64)
+            .unwrap();
+        assert_eq!(fstat.sysno(), AnySysno::from(x86_64::Sysno::fstat));
+
+        let time64 = AnySysno::from(x86::Sysno::clock_gettime64)
+            .translate(Arch::X86_64)
+            .unwrap();
+        assert_eq!(
+            time64.sysno(),
+            AnySysno::from(x86_64::Sysno::clock_gettime)
+        );
+    }
+
+    #[cfg(all(feature = "x86_64", feature = "riscv32"))]
+    #[test]
+    fn test_x86_64_to_riscv32() {
+        use crate::{riscv32, x86_64};
+
+        let translate = |sysno: x86_64::Sysno| {
+            AnySysno::from(sysno)
+                .translate(Arch::Riscv32)
+                .map(|translation| translation.sysno())
+        };
+
+        assert_eq!(
+            translate(x86_64::Sysno::clock_gettime),
+            Some(AnySysno::from(riscv32::Sysno::clock_gettime64))
+        );
+        assert_eq!(
+            translate(x86_64::Sysno::futex),
+            Some(AnySysno::from(riscv32::Sysno::futex_time64))
+        );
+        assert_eq!(
+            translate(x86_64::Sysno::mmap),
+            Some(AnySysno::from(riscv32::Sysno::mmap2))
+        );
+        assert_eq!(
+            translate(x86_64::Sysno::rename),
+            Some(AnySysno::from(riscv32::Sysno::renameat2))
+        );
+    }
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn test_clone() {
+        use crate::x86_64;
+
+        let clone = AnySysno::from(x86_64::Sysno::clone);
+        let args = SyscallArgs::new(0x11, 0x22, 0x33, 0x44, 0x55, 0);
+        let translate = |to| clone.translate(to).unwrap().apply(&args, 0);
+
+        // `tls` comes before `child_tidptr`.
+        for to in [
+            Arch::Aarch64,
+            Arch::X86,
+            Arch::Arm,
+            Arch::PowerPc,
+            Arch::PowerPc64,
+            Arch::Riscv32,
+            Arch::Riscv64,
+        ] {
+            assert_eq!(
+                translate(to),
+                Ok(SyscallArgs::new(0x11, 0x22, 0x33, 0x55, 0x44, 0)),
+                "{to:?}"
+            );
+        }
+
+        // `newsp` comes before `clone_flags`.
+        assert_eq!(
+            translate(Arch::S390x),
+            Ok(SyscallArgs::new(0x22, 0x11, 0x33, 0x44, 0x55, 0))
+        );
+
+        // Same order.
+        assert!(clone.translate(Arch::LoongArch64).unwrap().is_identity());
+        assert!(clone.translate(Arch::X32).unwrap().is_identity());
+
+        // Both pairs are swapped between s390x and the others, and the
+        // translation goes back to the original order.
+        let s390x = AnySysno::from_name(Arch::S390x, "clone").unwrap();
+        let to_arm = s390x.translate(Arch::Arm).unwrap();
+        let swapped = to_arm.apply(&args, 0).unwrap();
+        assert_eq!(swapped, SyscallArgs::new(0x22, 0x11, 0x33, 0x55, 0x44, 0));
+
+        let arm = AnySysno::from_name(Arch::Arm, "clone").unwrap();
+        let back = arm.translate(Arch::S390x).unwrap().apply(&swapped, 0);
+        assert_eq!(back, Ok(args));
+    }
+
+    #[cfg(all(feature = "x86", feature = "aarch64"))]
+    #[test]
+    fn test_time64_on_64_bit() {
+        use crate::{aarch64, x86};
+
+        let clock_gettime = AnySysno::from(x86::Sysno::clock_gettime64)
+            .translate(Arch::Aarch64)
+            .unwrap();
+        assert_eq!(
+            clock_gettime.sysno(),
+            AnySysno::from(aarch64::Sysno::clock_gettime)
+        );
+    }
+
+    #[cfg(all(feature = "x86_64", feature = "s390x"))]
+    #[test]
+    fn test_s390x_mmap() {
+        use crate::{s390x, x86_64};
+
+        // `mmap` on s390x is `old_mmap`, and there is no `mmap2`.
+        assert_eq!(
+            AnySysno::from(x86_64::Sysno::mmap).translate(Arch::S390x),
+            None
+        );
+        assert_eq!(
+            AnySysno::from(s390x::Sysno::mmap).translate(Arch::X86_64),
+            None
+        );
+    }
+
+    #[cfg(feature = "all")]
+    #[test]
+    fn test_pipe() {
+        let pipe = AnySysno::from_name(Arch::X86_64, "pipe").unwrap();
+        let args = SyscallArgs::new(0x1000, 0, 0, 0, 0, 0);
+
+        for arch in [
+            Arch::Mips,
+            Arch::Mips64,
+            Arch::MipsN32,
+            Arch::Sparc,
+            Arch::Sparc64,
+        ] {
+            // The `pipe` of these architectures returns the file descriptors
+            // in registers, so `pipe2` is used instead.
+            let pipe2 = pipe.translate(arch).unwrap();
+            assert_eq!(pipe2.sysno().name(), "pipe2", "{arch:?}");
+            assert_eq!(pipe2.apply(&args, 0), Ok(args), "{arch:?}");
+
+            let other = AnySysno::from_name(arch, "pipe").unwrap();
+            assert!(other.translate(Arch::X86_64).is_none(), "{arch:?}");
+        }
+
+        let x86 = pipe.translate(Arch::X86).unwrap();
+        assert_eq!(x86.sysno().name(), "pipe");
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt;
+use core::str::FromStr;
+
+/// A Linux kernel version, as `(major, minor)`.
+///
+/// This is what [`Sysno::since`](crate::Sysno::since) returns and what
+/// [`SysnoSet::available_in`](crate::SysnoSet::available_in) takes.
+///
+/// # Example
+///
+/// ```
+/// use syscalls::KernelVersion;
+///
+/// let version: KernelVersion = "6.8.0-45-generic".parse().unwrap();
+/// assert_eq!(version, KernelVersion(6, 8));
+/// assert!(version >= KernelVersion(5, 6));
+/// assert_eq!(version.to_string(), "6.8");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernelVersion(pub u8, pub u8);
+
+impl KernelVersion {
+    /// The oldest version that [`Sysno::since`](crate::Sysno::since)
+    /// returns. Syscalls that are older than this report this version.
+    pub const OLDEST: Self = Self(3, 0);
+
+    /// Returns true if this version is the same as or newer than `other`.
+    /// This is the same as `self >= other`, but usable in a `const fn`.
+    pub const fn is_at_least(&self, other: Self) -> bool {
+        self.0 > other.0 || (self.0 == other.0 && self.1 >= other.1)
+    }
+}
+
+impl fmt::Display for KernelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+impl FromStr for KernelVersion {
+    type Err = ();
+
+    /// Parses the major and minor version at the start of a kernel release,
+    /// such as `5.6` or `6.8.0-45-generic`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '.');
+        let major = parts.next().ok_or(())?;
+        let minor = parts.next().ok_or(())?;
+
+        // The minor version may be followed by a suffix, such as `-rc1`.
+        let end = minor
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(minor.len());
+
+        Ok(Self(parse_number(major)?, parse_number(&minor[..end])?))
+    }
+}
+
+fn parse_number(s: &str) -> Result<u8, ()> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(());
+    }
+
+    s.parse().map_err(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("5.6".parse(), Ok(KernelVersion(5, 6)));
+        assert_eq!("4.19.0".parse(), Ok(KernelVersion(4, 19)));
+        assert_eq!("6.10-rc1".parse(), Ok(KernelVersion(6, 10)));
+        assert_eq!("6.8.0-45-generic".parse(), Ok(KernelVersion(6, 8)));
+        assert_eq!("6".parse::<KernelVersion>(), Err(()));
+        assert_eq!("6.".parse::<KernelVersion>(), Err(()));
+        assert_eq!("+6.1".parse::<KernelVersion>(), Err(()));
+        assert_eq!("a.b".parse::<KernelVersion>(), Err(()));
+        assert_eq!("256.0".parse::<KernelVersion>(), Err(()));
+    }
+
+    #[test]
+    fn test_is_at_least() {
+        let v = KernelVersion(4, 19);
+        assert!(v.is_at_least(KernelVersion(4, 19)));
+        assert!(v.is_at_least(KernelVersion(4, 5)));
+        assert!(v.is_at_least(KernelVersion(3, 20)));
+        assert!(!v.is_at_least(KernelVersion(4, 20)));
+        assert!(!v.is_at_least(KernelVersion(5, 0)));
+
+        for a in [KernelVersion(3, 0), KernelVersion(5, 6), v] {
+            for b in [KernelVersion(3, 0), KernelVersion(5, 6), v] {
+                assert_eq!(a.is_at_least(b), a >= b);
+            }
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//! Syscall classes, as understood by `strace -e trace=%class`.
+//!
+//! The classes are defined by name here and are then intersected with each
+//! architecture's syscall table. Syscalls that don't exist on an architecture
+//! (or aren't implemented there) are simply left out.
+
+use crate::tables::TableEntry;
+use std::fmt;
+
+pub struct Class {
+    /// Name of the generated constant.
+    pub name: &'static str,
+    /// Doc comment for the generated constant.
+    pub doc: &'static str,
+    /// Syscalls that belong to this class.
+    pub syscalls: &'static [&'static str],
+}
+
+pub const CLASSES: &[Class] = &[
+    Class {
+        name: "FILE",
+        doc: "Syscalls that take a file name as an argument (`%file`).",
+        syscalls: &[
+            "access",
+            "acct",
+            "chdir",
+            "chmod",
+            "chown",
+            "chown32",
+            "chroot",
+            "creat",
+            "execv",
+            "execve",
+            "execveat",
+            "faccessat",
+            "faccessat2",
+            "fanotify_mark",
+            "fchmodat",
+            "fchmodat2",
+            "fchownat",
+            "file_getattr",
+            "file_setattr",
+            "fspick",
+            "fstatat",
+            "fstatat64",
+            "futimesat",
+            "getxattr",
+            "getxattrat",
+            "inotify_add_watch",
+            "lchown",
+            "lchown32",
+            "lgetxattr",
+            "link",
+            "linkat",
+            "listxattr",
+            "listxattrat",
+            "llistxattr",
+            "lremovexattr",
+            "lsetxattr",
+            "lstat",
+            "lstat64",
+            "mkdir",
+            "mkdirat",
+            "mknod",
+            "mknodat",
+            "mount",
+            "mount_setattr",
+            "move_mount",
+            "name_to_handle_at",
+            "newfstatat",
+            "oldlstat",
+            "oldstat",
+            "open",
+            "open_tree",
+            "open_tree_attr",
+            "openat",
+            "openat2",
+            "pivot_root",
+            "quotactl",
+            "readlink",
+            "readlinkat",
+            "removexattr",
+            "removexattrat",
+            "rename",
+            "renameat",
+            "renameat2",
+            "rmdir",
+            "setxattr",
+            "setxattrat",
+            "spu_create",
+            "stat",
+            "stat64",
+            "statfs",
+            "statfs64",
+            "statx",
+            "swapoff",
+            "swapon",
+            "symlink",
+            "symlinkat",
+            "truncate",
+            "truncate64",
+            "umount",
+            "umount2",
+            "unlink",
+            "unlinkat",
+            "uselib",
+            "utime",
+            "utimensat",
+            "utimensat_time64",
+            "utimes",
+        ],
+    },
+    Class {
+        name: "DESC",
+        doc: "Syscalls that take or return a file descriptor (`%desc`).",
+        syscalls: &[
+            "_llseek",
+            "_newselect",
+            "arm_fadvise64_64",
+            "arm_sync_file_range",
+            "bpf",
+            "cachestat",
+            "close",
+            "close_range",
+            "copy_file_range",
+            "creat",
+            "dup",
+            "dup2",
+            "dup3",
+            "epoll_create",
+            "epoll_create1",
+            "epoll_ctl",
+            "epoll_ctl_old",
+            "epoll_pwait",
+            "epoll_pwait2",
+            "epoll_wait",
+            "epoll_wait_old",
+            "eventfd",
+            "eventfd2",
+            "execveat",
+            "faccessat",
+            "faccessat2",
+            "fadvise64",
+            "fadvise64_64",
+            "fallocate",
+            "fanotify_init",
+            "fanotify_mark",
+            "fchdir",
+            "fchmod",
+            "fchmodat",
+            "fchmodat2",
+            "fchown",
+            "fchown32",
+            "fchownat",
+            "fcntl",
+            "fcntl64",
+            "fdatasync",
+            "fgetxattr",
+            "file_getattr",
+            "file_setattr",
+            "finit_module",
+            "flistxattr",
+            "flock",
+            "fremovexattr",
+            "fsconfig",
+            "fsetxattr",
+            "fsmount",
+            "fsopen",
+            "fspick",
+            "fstat",
+            "fstat64",
+            "fstatat",
+            "fstatat64",
+            "fstatfs",
+            "fstatfs64",
+            "fsync",
+            "ftruncate",
+            "ftruncate64",
+            "futimesat",
+            "getdents",
+            "getdents64",
+            "getxattrat",
+            "inotify_add_watch",
+            "inotify_init",
+            "inotify_init1",
+            "inotify_rm_watch",
+            "io_uring_enter",
+            "io_uring_register",
+            "io_uring_setup",
+            "ioctl",
+            "kexec_file_load",
+            "landlock_add_rule",
+            "landlock_create_ruleset",
+            "landlock_restrict_self",
+            "linkat",
+            "listxattrat",
+            "llseek",
+            "lseek",
+            "memfd_create",
+            "memfd_secret",
+            "mkdirat",
+            "mknodat",
+            "mmap",
+            "mmap2",
+            "mount_setattr",
+            "move_mount",
+            "mq_getsetattr",
+            "mq_notify",
+            "mq_open",
+            "mq_timedreceive",
+            "mq_timedreceive_time64",
+            "mq_timedsend",
+            "mq_timedsend_time64",
+            "name_to_handle_at",
+            "newfstatat",
+            "oldfstat",
+            "open",
+            "open_by_handle_at",
+            "open_tree",
+            "open_tree_attr",
+            "openat",
+            "openat2",
+            "perf_event_open",
+            "pidfd_getfd",
+            "pidfd_open",
+            "pidfd_send_signal",
+            "pipe",
+            "pipe2",
+            "poll",
+            "ppoll",
+            "ppoll_time64",
+            "pread64",
+            "preadv",
+            "preadv2",
+            "process_madvise",
+            "process_mrelease",
+            "pselect6",
+            "pselect6_time64",
+            "pwrite64",
+            "pwritev",
+            "pwritev2",
+            "quotactl_fd",
+            "read",
+            "readahead",
+            "readdir",
+            "readlinkat",
+            "readv",
+            "removexattrat",
+            "renameat",
+            "renameat2",
+            "select",
+            "sendfile",
+            "sendfile64",
+            "setns",
+            "setxattrat",
+            "signalfd",
+            "signalfd4",
+            "splice",
+            "spu_create",
+            "spu_run",
+            "statx",
+            "symlinkat",
+            "sync_file_range",
+            "sync_file_range2",
+            "syncfs",
+            "tee",
+            "timerfd_create",
+            "timerfd_gettime",
+            "timerfd_gettime64",
+            "timerfd_settime",
+            "timerfd_settime64",
+            "unlinkat",
+            "userfaultfd",
+            "utimensat",
+            "utimensat_time64",
+            "vmsplice",
+            "write",
+            "writev",
+        ],
+    },
+    Class {
+        name: "NETWORK",
+        doc: "Network-related syscalls (`%network`).",
+        syscalls: &[
+            "accept",
+            "accept4",
+            "bind",
+            "connect",
+            "getpeername",
+            "getsockname",
+            "getsockopt",
+            "listen",
+            "recv",
+            "recvfrom",
+            "recvmmsg",
+            "recvmmsg_time64",
+            "recvmsg",
+            "send",
+            "sendmmsg",
+            "sendmsg",
+            "sendto",
+            "setsockopt",
+            "shutdown",
+            "socket",
+            "socketcall",
+            "socketpair",
+        ],
+    },
+    Class {
+        name: "PROCESS",
+        doc: "Syscalls related to the process lifecycle (`%process`).",
+        syscalls: &[
+            "clone",
+            "clone3",
+            "execv",
+            "execve",
+            "execveat",
+            "exit",
+            "exit_group",
+            "fork",
+            "kill",
+            "pidfd_open",
+            "pidfd_send_signal",
+            "rt_sigqueueinfo",
+            "rt_tgsigqueueinfo",
+            "tgkill",
+            "tkill",
+            "unshare",
+            "vfork",
+            "wait4",
+            "waitid",
+            "waitpid",
+        ],
+    },
+    Class {
+        name: "SIGNAL",
+        doc: "Signal-related syscalls (`%signal`).",
+        syscalls: &[
+            "kill",
+            "pause",
+            "pidfd_send_signal",
+            "rt_sigaction",
+            "rt_sigpending",
+            "rt_sigprocmask",
+            "rt_sigqueueinfo",
+            "rt_sigreturn",
+            "rt_sigsuspend",
+            "rt_sigtimedwait",
+            "rt_sigtimedwait_time64",
+            "rt_tgsigqueueinfo",
+            "sgetmask",
+            "sigaction",
+            "sigaltstack",
+            "signal",
+            "signalfd",
+            "signalfd4",
+            "sigpending",
+            "sigprocmask",
+            "sigreturn",
+            "sigsuspend",
+            "ssetmask",
+            "tgkill",
+            "tkill",
+        ],
+    },
+    Class {
+        name: "IPC",
+        doc: "System V IPC-related syscalls (`%ipc`).",
+        syscalls: &[
+            "ipc",
+            "msgctl",
+            "msgget",
+            "msgrcv",
+            "msgsnd",
+            "semctl",
+            "semget",
+            "semop",
+            "semtimedop",
+            "semtimedop_time64",
+            "shmat",
+            "shmctl",
+            "shmdt",
+            "shmget",
+        ],
+    },
+    Class {
+        name: "MEMORY",
+        doc: "Memory mapping-related syscalls (`%memory`).",
+        syscalls: &[
+            "brk",
+            "get_mempolicy",
+            "madvise",
+            "map_shadow_stack",
+            "mbind",
+            "migrate_pages",
+            "mincore",
+            "mlock",
+            "mlock2",
+            "mlockall",
+            "mmap",
+            "mmap2",
+            "move_pages",
+            "mprotect",
+            "mremap",
+            "mseal",
+            "msync",
+            "munlock",
+            "munlockall",
+            "munmap",
+            "pkey_mprotect",
+            "remap_file_pages",
+            "set_mempolicy",
+            "set_mempolicy_home_node",
+            "shmat",
+            "shmdt",
+            "subpage_prot",
+        ],
+    },
+    Class {
+        name: "CREDS",
+        doc: "Syscalls that read or modify credentials (`%creds`).",
+        syscalls: &[
+            "capget",
+            "capset",
+            "getegid",
+            "getegid32",
+            "geteuid",
+            "geteuid32",
+            "getgid",
+            "getgid32",
+            "getgroups",
+            "getgroups32",
+            "getresgid",
+            "getresgid32",
+            "getresuid",
+            "getresuid32",
+            "getuid",
+            "getuid32",
+            "prctl",
+            "setfsgid",
+            "setfsgid32",
+            "setfsuid",
+            "setfsuid32",
+            "setgid",
+            "setgid32",
+            "setgroups",
+            "setgroups32",
+            "setregid",
+            "setregid32",
+            "setresgid",
+            "setresgid32",
+            "setresuid",
+            "setresuid32",
+            "setreuid",
+            "setreuid32",
+            "setuid",
+            "setuid32",
+        ],
+    },
+    Class {
+        name: "CLOCK",
+        doc: "Syscalls that read or modify system clocks (`%clock`).",
+        syscalls: &[
+            "adjtimex",
+            "clock_adjtime",
+            "clock_adjtime64",
+            "clock_getres",
+            "clock_getres_time64",
+            "clock_gettime",
+            "clock_gettime64",
+            "clock_settime",
+            "clock_settime64",
+            "gettimeofday",
+            "settimeofday",
+            "stime",
+            "time",
+        ],
+    },
+    Class {
+        name: "STAT",
+        doc: "Variants of the `stat` syscall (`%stat`).",
+        syscalls: &["oldstat", "stat", "stat64"],
+    },
+    Class {
+        name: "STATFS",
+        doc: "Variants of the `statfs` syscall (`%statfs`).",
+        syscalls: &["statfs", "statfs64"],
+    },
+    Class {
+        name: "PURE",
+        doc: "Syscalls that always succeed and have no arguments (`%pure`).",
+        syscalls: &[
+            "getegid",
+            "getegid32",
+            "geteuid",
+            "geteuid32",
+            "getgid",
+            "getgid32",
+            "getpagesize",
+            "getpgrp",
+            "getpid",
+            "getppid",
+            "gettid",
+            "getuid",
+            "getuid32",
+            "kern_features",
+            "sgetmask",
+        ],
+    },
+];
+
+/// Maximum width of the generated lines.
+pub const MAX_WIDTH: usize = 80;
+
+/// Writes out the classes for the given syscall table.
+pub struct ClassList<'a>(pub &'a [TableEntry]);
+
+impl fmt::Display for ClassList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "syscall_classes! {{")?;
+        for (i, class) in CLASSES.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "    /// {}", class.doc)?;
+            writeln!(f, "    {} = [", class.name)?;
+
+            let mut line = String::new();
+            for entry in self.0 {
+                // Only include syscalls that are actually implemented.
+                if entry.entry_point.is_none()
+                    || !class.syscalls.contains(&entry.name.as_str())
+                {
+                    continue;
+                }
+
+                let ident = entry.ident();
+                if !line.is_empty() && line.len() + ident.len() + 2 > MAX_WIDTH
+                {
+                    writeln!(f, "{line}")?;
+                    line.clear();
+                }
+
+                if line.is_empty() {
+                    line.push_str("       ");
+                }
+
+                line.push(' ');
+                line.push_str(&ident);
+                line.push(',');
+            }
+
+            if !line.is_empty() {
+                writeln!(f, "{line}")?;
+            }
+
+            writeln!(f, "    ];")?;
+        }
+        writeln!(f, "}}")
+    }
+}
@@ -0,0 +1,209 @@
+//! The errors that the man page of each syscall documents.
+//!
+//! These come from the ERRORS section of the page in section 2 of the man
+//! pages, which is what the doc comment of each syscall links to. Pages that
+//! only refer to another page for their errors (such as `wait4(2)`) are
+//! followed. The `*_time64` and `*64` variants of syscalls use the page of the
+//! syscall that they replace if they don't have their own.
+
+use crate::classes::MAX_WIDTH;
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// The man pages in section 2. This is the same version as the one that the
+/// syscall versions come from.
+static MAN_PAGES: &str =
+    "https://git.kernel.org/pub/scm/docs/man-pages/man-pages.git/plain/man2";
+
+static MAN_PAGES_VERSION: &str = "man-pages-6.03";
+
+/// The number of man pages to fetch at the same time.
+const CONCURRENCY: usize = 16;
+
+/// How many `.so` links or references to other pages to follow.
+const MAX_DEPTH: usize = 3;
+
+/// Fetches the man page of a syscall, following `.so` links. Returns `None`
+/// if there isn't one.
+async fn fetch_page(name: &str) -> Result<Option<String>> {
+    static RE_SO: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?m)^\.so\s+man2/(\w+)\.2").unwrap());
+
+    let mut name = name.to_string();
+
+    for _ in 0..MAX_DEPTH {
+        let url = format!("{MAN_PAGES}/{name}.2?h={MAN_PAGES_VERSION}");
+        let response = reqwest::get(&url)
+            .await
+            .wrap_err_with(|| eyre!("Failed to fetch URL '{url}'"))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let contents = response
+            .error_for_status()?
+            .text()
+            .await
+            .wrap_err_with(|| {
+                eyre!("Failed to parse contents of URL '{url}'")
+            })?;
+
+        match RE_SO.captures(&contents) {
+            Some(cap) => name = cap[1].to_string(),
+            None => return Ok(Some(contents)),
+        }
+    }
+
+    Ok(None)
+}
+
+/// The errors in the ERRORS section of a man page, and the first page that
+/// the section refers to.
+fn parse_errors(
+    contents: &str,
+    known: &BTreeSet<String>,
+) -> (Vec<String>, Option<String>) {
+    static RE_ERRNO: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\bE[A-Z0-9_]+\b").unwrap());
+    static RE_REF: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^\.BR (\w+) \(2\)").unwrap());
+
+    let mut errors = Vec::new();
+    let mut reference = None;
+    let mut in_errors = false;
+    let mut prev = "";
+
+    for line in contents.lines() {
+        if let Some(section) = line.strip_prefix(".SH") {
+            in_errors = section.trim() == "ERRORS";
+        } else if in_errors {
+            // Each error is the tag of a `.TP` paragraph.
+            if prev.starts_with(".TP") {
+                for m in RE_ERRNO.find_iter(line) {
+                    let name = m.as_str().to_string();
+                    if known.contains(&name) && !errors.contains(&name) {
+                        errors.push(name);
+                    }
+                }
+            }
+
+            if reference.is_none() {
+                if let Some(cap) = RE_REF.captures(line) {
+                    reference = Some(cap[1].to_string());
+                }
+            }
+        }
+
+        prev = line;
+    }
+
+    (errors, reference)
+}
+
+/// Returns the documented errors of a syscall.
+async fn fetch_errors(
+    name: &str,
+    known: &BTreeSet<String>,
+) -> Result<Vec<String>> {
+    let mut page = fetch_page(name).await?;
+
+    if page.is_none() {
+        if let Some(base) = name
+            .strip_suffix("_time64")
+            .or_else(|| name.strip_suffix("64"))
+        {
+            page = fetch_page(base).await?;
+        }
+    }
+
+    for _ in 0..MAX_DEPTH {
+        let Some(contents) = page else {
+            break;
+        };
+
+        let (errors, reference) = parse_errors(&contents, known);
+        match reference {
+            Some(reference) if errors.is_empty() => {
+                page = fetch_page(&reference).await?;
+            }
+            _ => return Ok(errors),
+        }
+    }
+
+    Ok(Vec::new())
+}
+
+/// Generates `src/errno/documented.rs` for the given syscalls. Only errors
+/// in `known` are included.
+pub async fn generate(
+    path: &Path,
+    syscalls: &BTreeSet<&str>,
+    known: &BTreeSet<String>,
+) -> Result<()> {
+    let errors: BTreeMap<&str, Vec<String>> = stream::iter(syscalls.iter())
+        .map(|name| async move {
+            let errors = fetch_errors(name, known).await?;
+            Ok::<_, color_eyre::Report>((*name, errors))
+        })
+        .buffered(CONCURRENCY)
+        .try_filter(|(_, errors)| std::future::ready(!errors.is_empty()))
+        .try_collect()
+        .await?;
+
+    let mut file = File::create(path)
+        .wrap_err_with(|| eyre!("Failed to create file {}", path.display()))?;
+    write!(file, "{}", DocumentedFile(&errors))?;
+
+    println!("Generated documented errors at {}", path.display());
+    Ok(())
+}
+
+struct DocumentedFile<'a>(&'a BTreeMap<&'a str, Vec<String>>);
+
+impl fmt::Display for DocumentedFile<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "// This file is automatically generated. Do not edit!")?;
+        writeln!(f)?;
+
+        writeln!(f, "documented_errors! {{")?;
+        for (i, (name, errors)) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "    {name} = [")?;
+
+            let mut line = String::new();
+            for errno in errors {
+                if !line.is_empty() && line.len() + errno.len() + 2 > MAX_WIDTH
+                {
+                    writeln!(f, "{line}")?;
+                    line.clear();
+                }
+
+                if line.is_empty() {
+                    line.push_str("       ");
+                }
+
+                line.push(' ');
+                line.push_str(errno);
+                line.push(',');
+            }
+
+            if !line.is_empty() {
+                writeln!(f, "{line}")?;
+            }
+
+            writeln!(f, "    ];")?;
+        }
+        writeln!(f, "}}")
+    }
+}
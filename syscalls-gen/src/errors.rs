@@ -1,51 +1,123 @@
 use crate::fetch_path;
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use futures::future::try_join_all;
-use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Error codes that are private to the kernel, but are still useful when
+/// ptracing. These are the same on every architecture.
+static KERNEL_ERRNO: &str = "include/linux/errno.h";
+
+/// Aliases that libc defines, but the kernel headers don't.
+static LIBC_ALIASES: &[(&str, &str)] = &[("ENOTSUP", "EOPNOTSUPP")];
+
+/// An errno table that is shared by one or more architectures.
+struct ErrnoTable<'a> {
+    /// The name of the module.
+    name: &'a str,
+    /// The architectures that use this table natively.
+    cfg: &'a str,
+    /// The headers that define the error codes, in include order. A later
+    /// definition replaces an earlier one with the same name.
+    headers: &'a [&'a str],
+}
+
+/// The errno tables. Most architectures use `asm-generic/errno.h`, but a few
+/// have their own numbering.
+static TABLES: &[ErrnoTable] = &[
+    ErrnoTable {
+        name: "generic",
+        cfg: r#"not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ))"#,
+        headers: &[
+            "include/uapi/asm-generic/errno-base.h",
+            "include/uapi/asm-generic/errno.h",
+        ],
+    },
+    ErrnoTable {
+        name: "mips",
+        cfg: r#"any(target_arch = "mips", target_arch = "mips64")"#,
+        headers: &[
+            "include/uapi/asm-generic/errno-base.h",
+            "arch/mips/include/uapi/asm/errno.h",
+        ],
+    },
+    ErrnoTable {
+        name: "powerpc",
+        cfg: r#"any(target_arch = "powerpc", target_arch = "powerpc64")"#,
+        headers: &[
+            "include/uapi/asm-generic/errno-base.h",
+            "include/uapi/asm-generic/errno.h",
+            "arch/powerpc/include/uapi/asm/errno.h",
+        ],
+    },
+    ErrnoTable {
+        name: "sparc",
+        cfg: r#"any(target_arch = "sparc", target_arch = "sparc64")"#,
+        headers: &[
+            "include/uapi/asm-generic/errno-base.h",
+            "arch/sparc/include/uapi/asm/errno.h",
+        ],
+    },
+];
 
-pub struct ErrnoFile<'a>(&'a Vec<Errno>);
+pub struct ErrnoFile<'a>(&'a str, &'a [Errno]);
 
-impl<'a> fmt::Display for ErrnoFile<'a> {
+impl fmt::Display for ErrnoFile<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self(cfg, errnos) = self;
+
         writeln!(f, "// This file is automatically generated. Do not edit!")?;
         writeln!(f)?;
 
-        writeln!(f, "errno_enum! {{")?;
-        writeln!(f, "    pub enum Errno {{")?;
-
-        for value in self.0.iter() {
-            match value {
-                Errno::Definition {
-                    name,
-                    num,
-                    description,
-                } => {
-                    let description = description.as_ref().map_or_else(
-                        || {
-                            // Try to make a best-effort guess for error codes that
-                            // don't have a description.
-                            match name.as_str() {
-                                "ERESTARTSYS" => "Restart syscall",
-                                "ERESTARTNOINTR" => "Restart if no interrupt",
-                                _ => panic!(
-                                    "Could not find a description for {name}"
-                                ),
-                            }
-                        },
-                        |s| s.as_str(),
-                    );
-
-                    writeln!(f, r#"        {name}({num}) = "{description}","#)?;
-                }
-                Errno::Alias { .. } => {
-                    // Don't include aliases since it makes our macro more
-                    // complicated. We can add these manually later.
-                }
+        writeln!(f, "errno_table! {{")?;
+        writeln!(f, "    #[cfg({cfg})]")?;
+        writeln!(f, "    errors {{")?;
+
+        for value in *errnos {
+            if let Errno::Definition {
+                name,
+                num,
+                description,
+            } = value
+            {
+                let description = description.as_ref().map_or_else(
+                    || {
+                        // Try to make a best-effort guess for error codes that
+                        // don't have a description.
+                        match name.as_str() {
+                            "ERESTARTSYS" => "Restart syscall",
+                            "ERESTARTNOINTR" => "Restart if no interrupt",
+                            _ => panic!(
+                                "Could not find a description for {name}"
+                            ),
+                        }
+                    },
+                    |s| s.as_str(),
+                );
+
+                writeln!(f, r#"        {name}({num}) = "{description}","#)?;
+            }
+        }
+
+        writeln!(f, "    }}")?;
+        writeln!(f)?;
+        writeln!(f, "    aliases {{")?;
+
+        for value in *errnos {
+            if let Errno::Alias { alias, name, .. } = value {
+                writeln!(f, "        {alias} = {name},")?;
             }
         }
 
@@ -56,21 +128,47 @@ impl<'a> fmt::Display for ErrnoFile<'a> {
     }
 }
 
-pub async fn generate_errno(path: PathBuf) -> Result<()> {
-    let table = fetch_errno(&[
-        "include/uapi/asm-generic/errno-base.h",
-        "include/uapi/asm-generic/errno.h",
-        // error codes private to the Kernel, but are still useful when
-        // ptracing.
-        "include/linux/errno.h",
-    ])
-    .await?;
-
-    let mut file = File::create(&path)
-        .wrap_err_with(|| eyre!("Failed to create file {}", &path.display()))?;
-    write!(file, "{}", ErrnoFile(&table))?;
-
-    println!("Generated errno table at {}", &path.display());
+/// Generates the errno tables. Returns the names that are in every table.
+pub async fn generate_errno(dir: &Path) -> Result<BTreeSet<String>> {
+    let futures = TABLES.iter().map(|table| async move {
+        let mut paths = table.headers.to_vec();
+        paths.push(KERNEL_ERRNO);
+
+        let mut errnos = fetch_errno(&paths).await?;
+        for (alias, name) in LIBC_ALIASES {
+            if !errnos.iter().any(|e| e.name() == *alias) {
+                errnos.push(Errno::Alias {
+                    alias: (*alias).to_string(),
+                    name: (*name).to_string(),
+                    description: None,
+                });
+            }
+        }
+
+        let path = dir.join(format!("{}.rs", table.name));
+        write_errno(&path, table.cfg, &errnos)?;
+
+        Ok::<_, color_eyre::Report>(
+            errnos
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect::<BTreeSet<_>>(),
+        )
+    });
+
+    let tables = try_join_all(futures).await?;
+    Ok(tables
+        .into_iter()
+        .reduce(|a, b| a.intersection(&b).cloned().collect())
+        .unwrap_or_default())
+}
+
+fn write_errno(path: &Path, cfg: &str, errnos: &[Errno]) -> Result<()> {
+    let mut file = File::create(path)
+        .wrap_err_with(|| eyre!("Failed to create file {}", path.display()))?;
+    write!(file, "{}", ErrnoFile(cfg, errnos))?;
+
+    println!("Generated errno table at {}", path.display());
     Ok(())
 }
 
@@ -89,6 +187,15 @@ pub enum Errno {
     },
 }
 
+impl Errno {
+    fn name(&self) -> &str {
+        match self {
+            Self::Definition { name, .. } => name,
+            Self::Alias { alias, .. } => alias,
+        }
+    }
+}
+
 async fn fetch_errno(paths: &[&str]) -> Result<Vec<Errno>> {
     let futures: Vec<_> = paths.iter().map(|path| fetch_path(path)).collect();
 
@@ -101,34 +208,46 @@ async fn fetch_errno(paths: &[&str]) -> Result<Vec<Errno>> {
 }
 
 fn parse_errno(contents: &str, errnos: &mut Vec<Errno>) -> Result<()> {
-    lazy_static! {
-        // Pattern for matching the errno definition
-        static ref RE_DEFINITION: Regex = Regex::new(r"^#define\s+(E\w+)\s+(\d+)(?:\s+/\*([^\\*]+)\*/)?").unwrap();
-        // Pattern for matching errno aliases
-        static ref RE_ALIAS: Regex = Regex::new(r#"^#define\s+(E\w+)\s+(E\w+)(?:\s+/\*([^\\*]+)\*/)?"#).unwrap();
-    }
+    // Pattern for matching the errno definition
+    static RE_DEFINITION: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+(E\w+)\s+(\d+)(?:\s+/\*([^\\*]+)\*/)?").unwrap()
+    });
+    // Pattern for matching errno aliases
+    static RE_ALIAS: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+(E\w+)\s+(E\w+)(?:\s+/\*([^\\*]+)\*/)?")
+            .unwrap()
+    });
 
     for line in contents.lines() {
-        if let Some(cap) = RE_DEFINITION.captures(line) {
+        let errno = if let Some(cap) = RE_DEFINITION.captures(line) {
             let name = cap[1].into();
             let num: u32 = cap[2].parse()?;
             let description = cap.get(3).map(|m| m.as_str().trim().to_string());
 
-            errnos.push(Errno::Definition {
+            Errno::Definition {
                 name,
                 num,
                 description,
-            });
+            }
         } else if let Some(cap) = RE_ALIAS.captures(line) {
             let alias = cap[1].into();
             let name = cap[2].into();
             let description = cap.get(3).map(|m| m.as_str().trim().to_string());
 
-            errnos.push(Errno::Alias {
+            Errno::Alias {
                 alias,
                 name,
                 description,
-            });
+            }
+        } else {
+            continue;
+        };
+
+        // Architectures may redefine a generic error code, such as
+        // `EDEADLOCK` on powerpc. Keep it in place of the original.
+        match errnos.iter_mut().find(|e| e.name() == errno.name()) {
+            Some(existing) => *existing = errno,
+            None => errnos.push(errno),
         }
     }
 
@@ -1,16 +1,20 @@
 #![deny(clippy::all, clippy::pedantic)]
 #![allow(clippy::upper_case_acronyms)]
 
+use crate::signatures::Prototypes;
+use crate::since::Versions;
 use crate::tables::Source;
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use futures::future::try_join_all;
-use lazy_static::lazy_static;
-use std::future::Future;
 use std::path::Path;
-use std::pin::Pin;
+use std::sync::LazyLock;
 use tables::{Header, Table};
 
+mod classes;
+mod documented;
 mod errors;
+mod signatures;
+mod since;
 mod tables;
 
 /// URL of the Linux repository to pull the syscall tables from.
@@ -19,23 +23,32 @@ static LINUX_REPO: &str = "https://raw.githubusercontent.com/torvalds/linux";
 /// Linux version to pull the syscall tables from.
 static LINUX_VERSION: &str = "v7.0";
 
-lazy_static! {
-    /// List of syscall tables for each architecture.
-    static ref SOURCES: Vec<Source<'static>> = vec![
+/// List of syscall tables for each architecture.
+static SOURCES: LazyLock<Vec<Source<'static>>> = LazyLock::new(|| {
+    vec![
         Source::Table(Table {
             arch: "x86",
             path: "arch/x86/entry/syscalls/syscall_32.tbl",
             abi: &[ABI::I386],
+            private: None,
         }),
         Source::Table(Table {
             arch: "x86_64",
             path: "arch/x86/entry/syscalls/syscall_64.tbl",
             abi: &[ABI::COMMON, ABI::B64],
+            private: None,
+        }),
+        Source::Table(Table {
+            arch: "x32",
+            path: "arch/x86/entry/syscalls/syscall_64.tbl",
+            abi: &[ABI::X32_COMMON, ABI::X32],
+            private: None,
         }),
         Source::Table(Table {
             arch: "arm",
             path: "arch/arm/tools/syscall.tbl",
             abi: &[ABI::COMMON],
+            private: Some("arch/arm/include/uapi/asm/unistd.h"),
         }),
         // NOTE: arm64/aarch64 is a little different from all the other tables.
         // These are defined in `unistd.h`, which is supposed to be the method
@@ -56,46 +69,73 @@ lazy_static! {
             arch: "sparc",
             path: "arch/sparc/kernel/syscalls/syscall.tbl",
             abi: &[ABI::COMMON, ABI::B32],
+            private: None,
         }),
         Source::Table(Table {
             arch: "sparc64",
             path: "arch/sparc/kernel/syscalls/syscall.tbl",
             abi: &[ABI::COMMON, ABI::B64],
+            private: None,
         }),
         Source::Table(Table {
             arch: "powerpc",
             path: "arch/powerpc/kernel/syscalls/syscall.tbl",
             abi: &[ABI::COMMON, ABI::NOSPU, ABI::B32],
+            private: None,
         }),
         Source::Table(Table {
             arch: "powerpc64",
             path: "arch/powerpc/kernel/syscalls/syscall.tbl",
             abi: &[ABI::COMMON, ABI::NOSPU, ABI::B64],
+            private: None,
         }),
         Source::Table(Table {
             arch: "mips",
             path: "arch/mips/kernel/syscalls/syscall_o32.tbl",
             abi: &[ABI::O32],
+            private: None,
         }),
         Source::Table(Table {
             arch: "mips64",
             path: "arch/mips/kernel/syscalls/syscall_n64.tbl",
             abi: &[ABI::N64],
+            private: None,
+        }),
+        Source::Table(Table {
+            arch: "mipsn32",
+            path: "arch/mips/kernel/syscalls/syscall_n32.tbl",
+            abi: &[ABI::N32],
+            private: None,
         }),
         Source::Table(Table {
             arch: "s390x",
             path: "arch/s390/kernel/syscalls/syscall.tbl",
             abi: &[ABI::COMMON, ABI::B64],
+            private: None,
         }),
         Source::Table(Table {
             arch: "riscv32",
             path: "scripts/syscall.tbl",
-            abi: &[ABI::COMMON, ABI::RISCV, ABI::B32, ABI::MEMFD_SECRET, ABI::RLIMIT],
+            abi: &[
+                ABI::COMMON,
+                ABI::RISCV,
+                ABI::B32,
+                ABI::MEMFD_SECRET,
+                ABI::RLIMIT,
+            ],
+            private: None,
         }),
         Source::Table(Table {
             arch: "riscv64",
             path: "scripts/syscall.tbl",
-            abi: &[ABI::COMMON, ABI::RISCV, ABI::B64, ABI::MEMFD_SECRET, ABI::RLIMIT],
+            abi: &[
+                ABI::COMMON,
+                ABI::RISCV,
+                ABI::B64,
+                ABI::MEMFD_SECRET,
+                ABI::RLIMIT,
+            ],
+            private: None,
         }),
         Source::Header(Header {
             arch: "loongarch64",
@@ -108,8 +148,8 @@ lazy_static! {
                 "sync_file_range2",
             ],
         }),
-    ];
-}
+    ]
+});
 
 pub struct ABI<'a> {
     name: &'a str,
@@ -118,8 +158,8 @@ pub struct ABI<'a> {
 
 impl<'a> ABI<'a> {
     // Different syscall ABIs have different offsets. This currently only
-    // applies to MIPS and ia64. (Search for `__NR_Linux` in the kernel source
-    // to find syscall offsets.)
+    // applies to MIPS, ia64, and x32. (Search for `__NR_Linux` and
+    // `__X32_SYSCALL_BIT` in the kernel source to find syscall offsets.)
     pub const COMMON: Self = Self::new("common", 0);
     pub const I386: Self = Self::new("i386", 0);
     pub const RISCV: Self = Self::new("riscv", 0);
@@ -130,6 +170,9 @@ impl<'a> ABI<'a> {
     pub const B64: Self = Self::new("64", 0);
     pub const O32: Self = Self::new("o32", 4000);
     pub const N64: Self = Self::new("n64", 5000);
+    pub const N32: Self = Self::new("n32", 6000);
+    pub const X32_COMMON: Self = Self::new("common", 0x4000_0000);
+    pub const X32: Self = Self::new("x32", 0x4000_0000);
 
     #[must_use]
     pub const fn new(name: &'a str, offset: u32) -> Self {
@@ -157,18 +200,34 @@ async fn main() -> Result<()> {
     color_eyre::install()?;
 
     let base_dir = Path::new("..");
+    let errno_dir = base_dir.join("src/errno");
 
-    let mut futures: Vec<Pin<Box<dyn Future<Output = Result<()>>>>> =
-        Vec::new();
-
-    for source in SOURCES.iter() {
-        futures.push(Box::pin(source.generate(base_dir)));
-    }
-
-    let errno = base_dir.join("src/errno/generated.rs");
-    futures.push(Box::pin(errors::generate_errno(errno)));
-
-    try_join_all(futures).await?;
+    // Syscall prototypes are shared by all architectures, so only fetch them
+    // once.
+    let protos = Prototypes::fetch()
+        .await
+        .wrap_err("Failed fetching syscall prototypes")?;
+    let versions = Versions::fetch()
+        .await
+        .wrap_err("Failed fetching syscall versions")?;
+
+    let tables = try_join_all(
+        SOURCES
+            .iter()
+            .map(|source| source.generate(base_dir, &protos, &versions)),
+    )
+    .await?;
+
+    let known = errors::generate_errno(&errno_dir).await?;
+
+    let syscalls = tables
+        .iter()
+        .flatten()
+        .map(|entry| entry.name.as_str())
+        .collect();
+    documented::generate(&errno_dir.join("documented.rs"), &syscalls, &known)
+        .await
+        .wrap_err("Failed generating documented errors")?;
 
     Ok(())
 }
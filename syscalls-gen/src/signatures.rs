@@ -0,0 +1,456 @@
+use crate::fetch_path;
+use crate::tables::TableEntry;
+use color_eyre::eyre::Result;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Header containing the prototypes of (almost) all syscalls.
+const SYSCALLS_HEADER: &str = "include/linux/syscalls.h";
+
+/// Prototypes for architecture-specific syscalls that aren't declared in
+/// `include/linux/syscalls.h`. These are only defined via `SYSCALL_DEFINEn`
+/// under `arch/`, so we list them here instead of fetching every source file.
+const ARCH_PROTOTYPES: &str = r"
+asmlinkage long sys_mmap(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long off);
+asmlinkage long sys_mmap2(unsigned long addr, unsigned long len, unsigned long prot, unsigned long flags, unsigned long fd, unsigned long pgoff);
+asmlinkage long sys_arch_prctl(int option, unsigned long arg2);
+asmlinkage long sys_rt_sigreturn(void);
+asmlinkage long sys_sigreturn(void);
+asmlinkage long sys_iopl(unsigned int level);
+asmlinkage long sys_modify_ldt(int func, void __user *ptr, unsigned long bytecount);
+asmlinkage long sys_set_thread_area(struct user_desc __user *u_info);
+asmlinkage long sys_get_thread_area(struct user_desc __user *u_info);
+asmlinkage long sys_vm86old(struct vm86_struct __user *user_vm86);
+asmlinkage long sys_vm86(unsigned long cmd, unsigned long arg);
+asmlinkage long sys_uprobe(void);
+asmlinkage long sys_uretprobe(void);
+asmlinkage long sys_arm_fadvise64_64(int fd, int advice, loff_t offset, loff_t len);
+asmlinkage long sys_arm_breakpoint(void);
+asmlinkage long sys_arm_cacheflush(unsigned long start, unsigned long end, int flags);
+asmlinkage long sys_arm_usr26(void);
+asmlinkage long sys_arm_usr32(void);
+asmlinkage long sys_arm_set_tls(unsigned long val);
+asmlinkage long sys_arm_get_tls(void);
+asmlinkage long sys_cacheflush(unsigned long addr, unsigned long bytes, unsigned int cache);
+asmlinkage long sys_cachectl(char __user *addr, int nbytes, int op);
+asmlinkage long sys_sysmips(long cmd, long arg1, long arg2);
+asmlinkage long sys_riscv_flush_icache(uintptr_t start, uintptr_t end, uintptr_t flags);
+asmlinkage long sys_riscv_hwprobe(struct riscv_hwprobe __user *pairs, size_t pair_count, size_t cpusetsize, unsigned long __user *cpus, unsigned int flags);
+asmlinkage long sys_s390_runtime_instr(int command, int signum);
+asmlinkage long sys_s390_guarded_storage(int command, struct gs_cb __user *gs_cb);
+asmlinkage long sys_s390_pci_mmio_read(unsigned long mmio_addr, void __user *user_buffer, size_t length);
+asmlinkage long sys_s390_pci_mmio_write(unsigned long mmio_addr, void __user *user_buffer, size_t length);
+asmlinkage long sys_s390_sthyi(unsigned long function_code, void __user *buffer, u64 __user *return_code, unsigned long flags);
+asmlinkage long sys_rtas(struct rtas_args __user *uargs);
+asmlinkage long sys_subpage_prot(unsigned long addr, unsigned long len, u32 __user *map);
+asmlinkage long sys_swapcontext(struct ucontext __user *old_ctx, struct ucontext __user *new_ctx, long ctx_size);
+asmlinkage long sys_debug_setcontext(struct ucontext __user *ctx, int ndbg, struct sig_dbg_op __user *dbg);
+asmlinkage long sys_switch_endian(void);
+asmlinkage long sys_kern_features(void);
+asmlinkage long sys_memory_ordering(unsigned long model);
+asmlinkage long sys_utrap_install(utrap_entry_t type, utrap_handler_t new_p, utrap_handler_t new_d, utrap_handler_t __user *old_p, utrap_handler_t __user *old_d);
+asmlinkage long sys_getpagesize(void);
+asmlinkage long sys_getdomainname(char __user *name, int len);
+asmlinkage long sys_execv(const char __user *filename, const char __user *const __user *argv);
+asmlinkage long sys_perfctr(int opcode, unsigned long arg0, unsigned long arg1, unsigned long arg2);
+asmlinkage long sys_clone_backwards(unsigned long clone_flags, unsigned long newsp, int __user *parent_tidptr, unsigned long tls, int __user *child_tidptr);
+asmlinkage long sys_clone_backwards2(unsigned long newsp, unsigned long clone_flags, int __user *parent_tidptr, int __user *child_tidptr, unsigned long tls);
+";
+
+/// Architectures that select `CONFIG_CLONE_BACKWARDS`, where `clone` takes
+/// `tls` before `child_tidptr`. Sparc doesn't select it, but its `sparc_clone`
+/// reads the arguments in the same order.
+const CLONE_BACKWARDS: &[&str] = &[
+    "aarch64",
+    "arm",
+    "mips",
+    "mips64",
+    "mipsn32",
+    "powerpc",
+    "powerpc64",
+    "riscv32",
+    "riscv64",
+    "sparc",
+    "sparc64",
+    "x86",
+];
+
+/// Architectures that select `CONFIG_CLONE_BACKWARDS2`, where `clone` takes
+/// `newsp` before `clone_flags`.
+const CLONE_BACKWARDS2: &[&str] = &["s390x"];
+
+/// Entry points in `asm-generic/unistd.h` that don't follow the `sys_{name}`
+/// naming convention. Header-based tables don't include the entry point, so
+/// these need to be filled in by hand.
+const GENERIC_ENTRY_POINTS: &[(&str, &str)] =
+    &[("fstat", "sys_newfstat"), ("uname", "sys_newuname")];
+
+/// Syscall names whose entry point doesn't match any prototype, mapped to the
+/// name of the prototype to use instead. This typically happens when an
+/// architecture uses its own wrapper (e.g., `sys_ia32_pread64` or
+/// `ppc_select`) around a generic syscall.
+const ALIASES: &[(&str, &str)] = &[
+    ("_llseek", "llseek"),
+    ("_newselect", "select"),
+    ("arm_sync_file_range", "sync_file_range2"),
+    ("fstatat", "newfstatat"),
+    ("readdir", "old_readdir"),
+    ("sys_debug_setcontext", "debug_setcontext"),
+    ("ugetrlimit", "getrlimit"),
+    ("umount2", "umount"),
+];
+
+/// Names for the arguments of prototypes in `include/linux/syscalls.h` that
+/// omit them. These are taken from the corresponding `SYSCALL_DEFINEn`.
+const ARG_NAMES: &[(&str, &[&str])] = &[
+    ("accept", &["fd", "upeer_sockaddr", "upeer_addrlen"]),
+    (
+        "accept4",
+        &["fd", "upeer_sockaddr", "upeer_addrlen", "flags"],
+    ),
+    ("bind", &["fd", "umyaddr", "addrlen"]),
+    (
+        "clone",
+        &[
+            "clone_flags",
+            "newsp",
+            "parent_tidptr",
+            "child_tidptr",
+            "tls",
+        ],
+    ),
+    ("connect", &["fd", "uservaddr", "addrlen"]),
+    ("getpeername", &["fd", "usockaddr", "usockaddr_len"]),
+    ("getsockname", &["fd", "usockaddr", "usockaddr_len"]),
+    ("io_submit", &["ctx_id", "nr", "iocbpp"]),
+    ("listen", &["fd", "backlog"]),
+    ("old_readdir", &["fd", "dirent", "count"]),
+    ("olduname", &["name"]),
+    ("ppoll", &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"]),
+    (
+        "ppoll_time64",
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"],
+    ),
+    ("pselect6", &["n", "inp", "outp", "exp", "tsp", "sig"]),
+    (
+        "pselect6_time64",
+        &["n", "inp", "outp", "exp", "tsp", "sig"],
+    ),
+    ("recv", &["fd", "ubuf", "size", "flags"]),
+    (
+        "recvfrom",
+        &["fd", "ubuf", "size", "flags", "addr", "addr_len"],
+    ),
+    ("rt_sigaction", &["sig", "act", "oact", "sigsetsize"]),
+    ("send", &["fd", "buff", "len", "flags"]),
+    (
+        "sendto",
+        &["fd", "buff", "len", "flags", "addr", "addr_len"],
+    ),
+    ("shutdown", &["fd", "how"]),
+    ("sigaction", &["sig", "act", "oact"]),
+    ("socket", &["family", "type", "protocol"]),
+    ("socketpair", &["family", "type", "protocol", "usockvec"]),
+    ("uname", &["name"]),
+];
+
+/// Syscalls that return a new file descriptor on success.
+const RETURNS_FD: &[&str] = &[
+    "accept",
+    "accept4",
+    "creat",
+    "dup",
+    "dup2",
+    "dup3",
+    "epoll_create",
+    "epoll_create1",
+    "eventfd",
+    "eventfd2",
+    "fanotify_init",
+    "fsmount",
+    "fsopen",
+    "fspick",
+    "inotify_init",
+    "inotify_init1",
+    "io_uring_setup",
+    "landlock_create_ruleset",
+    "memfd_create",
+    "memfd_secret",
+    "mq_open",
+    "open",
+    "open_by_handle_at",
+    "open_tree",
+    "open_tree_attr",
+    "openat",
+    "openat2",
+    "perf_event_open",
+    "pidfd_getfd",
+    "pidfd_open",
+    "signalfd",
+    "signalfd4",
+    "socket",
+    "spu_create",
+    "timerfd_create",
+    "userfaultfd",
+];
+
+/// Syscalls that return an address on success.
+const RETURNS_PTR: &[&str] = &[
+    "brk",
+    "mmap",
+    "mmap2",
+    "mremap",
+    "shmat",
+    "map_shadow_stack",
+    "get_tls",
+];
+
+/// Typedefs that are actually pointers.
+const POINTER_TYPEDEFS: &[&str] = &[
+    "__sighandler_t",
+    "cap_user_data_t",
+    "cap_user_header_t",
+    "utrap_handler_t",
+];
+
+/// Integer types that are unsigned.
+const UNSIGNED_TYPES: &[&str] = &[
+    "__u32",
+    "__u64",
+    "aio_context_t",
+    "compat_size_t",
+    "gid_t",
+    "old_gid_t",
+    "old_sigset_t",
+    "old_uid_t",
+    "qid_t",
+    "size_t",
+    "u32",
+    "u64",
+    "uid_t",
+    "uint32_t",
+    "uintptr_t",
+    "umode_t",
+];
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    pub name: String,
+    pub ty: String,
+}
+
+impl Arg {
+    /// Returns the name of the `ArgKind` variant for this argument.
+    fn kind(&self) -> &'static str {
+        let ty = self.ty.strip_prefix("const ").unwrap_or(&self.ty);
+
+        if ty.contains('*') || POINTER_TYPEDEFS.contains(&ty) {
+            // Strings are only passed as `char *`. Some buffers are also
+            // declared that way, so we also look at the name.
+            let is_char = ty == "char *";
+            let is_const = self.ty.starts_with("const ");
+            let name = self.name.as_str();
+            let is_buffer =
+                ["buf", "msg", "optval"].iter().any(|x| name.contains(x));
+            let is_path = name.contains("name")
+                || name.contains("path")
+                || name == "type";
+
+            if is_char && (is_const || is_path) && !is_buffer {
+                "Str"
+            } else {
+                "Ptr"
+            }
+        } else if self.name.starts_with("fd")
+            || self.name.ends_with("fd")
+            || self.name == "fildes"
+            || self.name == "mqdes"
+        {
+            "Fd"
+        } else if ty.starts_with("unsigned") || UNSIGNED_TYPES.contains(&ty) {
+            "UInt"
+        } else {
+            "Int"
+        }
+    }
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {:?} = {}", self.name, self.ty, self.kind())
+    }
+}
+
+/// A map of syscall prototypes, keyed by the name of the entry point without
+/// the `sys_` prefix.
+pub struct Prototypes(HashMap<String, Vec<Arg>>);
+
+impl Prototypes {
+    /// Fetches all syscall prototypes.
+    pub async fn fetch() -> Result<Self> {
+        let contents = fetch_path(SYSCALLS_HEADER).await?;
+
+        let mut protos = HashMap::new();
+
+        // Prototypes in syscalls.h can be overridden by the architecture
+        // specific ones, so parse those last.
+        parse_prototypes(&contents, &mut protos);
+        parse_prototypes(ARCH_PROTOTYPES, &mut protos);
+
+        Ok(Self(protos))
+    }
+
+    /// Finds the prototype for the given syscall table entry of `arch`.
+    pub fn get(&self, arch: &str, entry: &TableEntry) -> Option<&[Arg]> {
+        // Syscalls without an entry point are not implemented.
+        let entry_point = entry.entry_point.as_deref()?;
+        let entry_point =
+            entry_point.strip_prefix("sys_").unwrap_or(entry_point);
+
+        if let Some(name) = arch_prototype(arch, &entry.name) {
+            return self.0.get(name).map(Vec::as_slice);
+        }
+
+        self.0
+            .get(entry_point)
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(name, _)| *name == entry.name)
+                    .and_then(|(_, alias)| self.0.get(*alias))
+            })
+            .or_else(|| self.0.get(&entry.name))
+            .map(Vec::as_slice)
+    }
+}
+
+/// Returns the name of the prototype to use for a syscall whose arguments
+/// depend on the architecture, since `include/linux/syscalls.h` only declares
+/// one of them.
+fn arch_prototype(arch: &str, name: &str) -> Option<&'static str> {
+    match name {
+        "clone" if CLONE_BACKWARDS.contains(&arch) => Some("clone_backwards"),
+        "clone" if CLONE_BACKWARDS2.contains(&arch) => Some("clone_backwards2"),
+        _ => None,
+    }
+}
+
+/// Returns the entry point of a syscall defined in `asm-generic/unistd.h`.
+pub fn generic_entry_point(name: &str) -> String {
+    GENERIC_ENTRY_POINTS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map_or_else(|| format!("sys_{name}"), |(_, ep)| (*ep).to_string())
+}
+
+/// Returns the name of the `ArgKind` variant for the return value of the
+/// given syscall.
+pub fn return_kind(name: &str) -> &'static str {
+    if RETURNS_FD.contains(&name) {
+        "Fd"
+    } else if RETURNS_PTR.contains(&name) {
+        "Ptr"
+    } else {
+        "Int"
+    }
+}
+
+fn parse_prototypes(contents: &str, protos: &mut HashMap<String, Vec<Arg>>) {
+    // Pattern for matching a syscall prototype. Prototypes may span multiple
+    // lines.
+    static RE_PROTOTYPE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"asmlinkage\s+long\s+sys_(\w+)\s*\(([^)]*)\)\s*;").unwrap()
+    });
+
+    for cap in RE_PROTOTYPE.captures_iter(contents) {
+        let name = &cap[1];
+
+        // Names of `ni_syscall` and friends are not real syscalls.
+        if name.ends_with("ni_syscall") {
+            continue;
+        }
+
+        let params: Vec<&str> = cap[2]
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty() && *p != "void")
+            .collect();
+
+        let names = ARG_NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, names)| *names);
+
+        let args = params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let (ty, arg_name) = parse_param(param);
+                let arg_name = arg_name
+                    .map(String::from)
+                    .or_else(|| {
+                        names.and_then(|n| n.get(i)).map(|n| (*n).into())
+                    })
+                    .unwrap_or_else(|| format!("arg{i}"));
+                Arg { name: arg_name, ty }
+            })
+            .collect();
+
+        protos.insert(name.into(), args);
+    }
+}
+
+/// Splits a parameter declaration into its type and name, stripping any
+/// `__user` annotations. Returns `None` for the name if the parameter is
+/// unnamed.
+fn parse_param(param: &str) -> (String, Option<&str>) {
+    const TYPE_KEYWORDS: &[&str] =
+        &["char", "int", "long", "short", "signed", "unsigned", "void"];
+
+    let param = param.trim();
+
+    let tokens: Vec<&str> = param
+        .split(|c: char| c.is_whitespace() || c == '*')
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    let name = match tokens.last() {
+        Some(last)
+            if tokens.len() > 1
+                && !param.ends_with('*')
+                && !TYPE_KEYWORDS.contains(last) =>
+        {
+            Some(*last)
+        }
+        _ => None,
+    };
+
+    let ty = match name {
+        Some(name) => &param[..param.len() - name.len()],
+        None => param,
+    };
+
+    (normalize_type(ty), name)
+}
+
+/// Removes `__user` annotations and normalizes whitespace so that pointers are
+/// written like `char *`, `struct iocb **`, or `const char *const *`.
+fn normalize_type(ty: &str) -> String {
+    let ty = ty.replace("__user", "").replace('*', " * ");
+
+    let mut out = String::new();
+    for token in ty.split_whitespace() {
+        if token == "*" {
+            if !out.ends_with('*') {
+                out.push(' ');
+            }
+        } else if !out.is_empty() && !out.ends_with('*') {
+            out.push(' ');
+        }
+        out.push_str(token);
+    }
+
+    out
+}
@@ -0,0 +1,355 @@
+//! The Linux versions that introduced each syscall.
+//!
+//! Most versions come from the table in `syscalls(2)`, which lists every
+//! syscall with the version that added it. That table doesn't say when an
+//! architecture gained a syscall that other architectures already had, so
+//! those are listed here instead.
+//!
+//! Versions before 3.0 aren't distinguished and are reported as 3.0.
+//!
+//! Generation fails for syscalls that aren't in `syscalls(2)`, [`EXTRA`] or
+//! [`OLD`], so that a new syscall can't silently be reported as 3.0.
+
+use crate::classes::MAX_WIDTH;
+use crate::tables::TableEntry;
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::LazyLock;
+
+/// The `syscalls(2)` man page.
+static MAN_PAGE: &str = "https://git.kernel.org/pub/scm/docs/man-pages/man-pages.git/plain/man2/syscalls.2?h=man-pages-6.03";
+
+/// A Linux version, as `(major, minor)`.
+type Version = (u8, u8);
+
+/// The oldest version that is reported.
+const OLDEST: Version = (3, 0);
+
+/// Syscalls that are missing from `syscalls(2)`, mostly because they are newer
+/// than it, or that have the wrong version there.
+const EXTRA: &[(Version, &[&str])] = &[
+    ((4, 3), &["membarrier"]),
+    ((4, 9), &["pkey_alloc", "pkey_free", "pkey_mprotect"]),
+    (
+        (5, 1),
+        &[
+            "clock_adjtime64",
+            "clock_getres_time64",
+            "clock_gettime64",
+            "clock_nanosleep_time64",
+            "clock_settime64",
+            "futex_time64",
+            "io_pgetevents_time64",
+            "mq_timedreceive_time64",
+            "mq_timedsend_time64",
+            "ppoll_time64",
+            "pselect6_time64",
+            "recvmmsg_time64",
+            "rt_sigtimedwait_time64",
+            "sched_rr_get_interval_time64",
+            "semtimedop_time64",
+            "timer_gettime64",
+            "timer_settime64",
+            "timerfd_gettime64",
+            "timerfd_settime64",
+            "utimensat_time64",
+        ],
+    ),
+    ((5, 12), &["mount_setattr"]),
+    ((5, 15), &["process_mrelease"]),
+    ((5, 16), &["futex_waitv"]),
+    ((5, 17), &["set_mempolicy_home_node"]),
+    ((6, 4), &["riscv_hwprobe"]),
+    ((6, 5), &["cachestat"]),
+    ((6, 6), &["fchmodat2", "map_shadow_stack"]),
+    ((6, 7), &["futex_requeue", "futex_wait", "futex_wake"]),
+    (
+        (6, 8),
+        &[
+            "listmount",
+            "lsm_get_self_attr",
+            "lsm_list_modules",
+            "lsm_set_self_attr",
+            "statmount",
+        ],
+    ),
+    ((6, 10), &["mseal"]),
+    ((6, 11), &["uretprobe"]),
+    (
+        (6, 13),
+        &["getxattrat", "listxattrat", "removexattrat", "setxattrat"],
+    ),
+    ((6, 15), &["open_tree_attr"]),
+    ((6, 17), &["file_getattr", "file_setattr"]),
+    ((6, 18), &["listns", "uprobe"]),
+    ((7, 0), &["rseq_slice_yield"]),
+];
+
+/// Syscalls that are missing from `syscalls(2)` and are older than [`OLDEST`].
+/// These are mostly unimplemented or architecture-specific, or have a
+/// different name in the syscall table.
+const OLD: &[&str] = &[
+    "afs_syscall",
+    "arm_fadvise64_64",
+    "arm_sync_file_range",
+    "break",
+    "cachectl",
+    "epoll_ctl_old",
+    "epoll_wait_old",
+    "fstatat",
+    "ftime",
+    "getpmsg",
+    "gtty",
+    "idle",
+    "llseek",
+    "lock",
+    "mpx",
+    "multiplexer",
+    "pread64",
+    "prof",
+    "profil",
+    "putpmsg",
+    "pwrite64",
+    "reserved177",
+    "reserved193",
+    "reserved221",
+    "reserved82",
+    "security",
+    "stty",
+    "timerfd",
+    "tuxcall",
+    "ulimit",
+    "unused109",
+    "unused150",
+    "unused18",
+    "unused28",
+    "unused59",
+    "unused84",
+    "vserver",
+];
+
+/// The versions that added each architecture, if newer than [`OLDEST`].
+const ARCHES: &[(&str, Version)] = &[
+    ("x32", (3, 4)),
+    ("aarch64", (3, 7)),
+    ("riscv32", (4, 15)),
+    ("riscv64", (4, 15)),
+    ("loongarch64", (5, 19)),
+];
+
+/// The socket syscalls that used to be only available through `socketcall`.
+const SOCKETCALLS: &[&str] = &[
+    "socket",
+    "socketpair",
+    "bind",
+    "connect",
+    "listen",
+    "accept4",
+    "getsockopt",
+    "setsockopt",
+    "getsockname",
+    "getpeername",
+    "sendto",
+    "sendmsg",
+    "recvfrom",
+    "recvmsg",
+    "shutdown",
+];
+
+/// The memory protection key syscalls.
+const PKEYS: &[&str] = &["pkey_alloc", "pkey_free", "pkey_mprotect"];
+
+/// The IPC syscalls that used to be only available through `ipc`.
+const IPCCALLS: &[&str] = &[
+    "semtimedop",
+    "semget",
+    "semctl",
+    "shmget",
+    "shmctl",
+    "shmat",
+    "shmdt",
+    "msgget",
+    "msgsnd",
+    "msgrcv",
+    "msgctl",
+];
+
+/// Syscalls that were added to an architecture after they were added to the
+/// kernel.
+const LATE: &[(&str, Version, &[&str])] = &[
+    ("powerpc", (3, 8), &["kcmp"]),
+    ("powerpc64", (3, 8), &["kcmp"]),
+    ("s390x", (3, 8), &["kcmp"]),
+    ("sparc", (3, 8), &["kcmp"]),
+    ("sparc64", (3, 8), &["kcmp"]),
+    ("mips64", (3, 10), &["getdents64"]),
+    ("x86", (4, 3), SOCKETCALLS),
+    ("s390x", (4, 3), SOCKETCALLS),
+    // Unlike x86, s390x didn't have these outside of `socketcall` either.
+    ("s390x", (4, 3), &["recvmmsg", "sendmmsg"]),
+    ("sparc", (4, 4), &["bind", "listen", "setsockopt"]),
+    ("sparc64", (4, 4), &["bind", "listen", "setsockopt"]),
+    ("powerpc", (4, 10), &["kexec_file_load"]),
+    ("powerpc64", (4, 10), &["kexec_file_load"]),
+    ("x86", (4, 12), &["arch_prctl"]),
+    ("s390x", (4, 12), &["statx"]),
+    ("powerpc", (4, 16), PKEYS),
+    ("powerpc64", (4, 16), PKEYS),
+    ("s390x", (4, 17), &["kexec_file_load"]),
+    ("aarch64", (5, 0), &["kexec_file_load"]),
+    ("riscv32", (5, 0), &["kexec_file_load"]),
+    ("riscv64", (5, 0), &["kexec_file_load"]),
+    ("arm", (5, 1), &["kexec_file_load", "migrate_pages"]),
+    ("s390x", (5, 1), PKEYS),
+    ("sparc", (5, 1), PKEYS),
+    ("sparc", (5, 1), &["rseq"]),
+    ("sparc64", (5, 1), PKEYS),
+    ("sparc64", (5, 1), &["rseq"]),
+    ("x86", (5, 1), IPCCALLS),
+    ("s390x", (5, 1), IPCCALLS),
+    ("powerpc", (5, 1), IPCCALLS),
+    ("powerpc64", (5, 1), IPCCALLS),
+    ("sparc", (5, 1), IPCCALLS),
+    ("sparc64", (5, 1), IPCCALLS),
+    ("mips", (5, 1), IPCCALLS),
+    // These were removed and then added back.
+    ("loongarch64", (6, 11), &["fstat", "fstatat"]),
+];
+
+/// The versions that added each syscall, regardless of the architecture.
+pub struct Versions(HashMap<String, Version>);
+
+impl Versions {
+    /// Fetches the versions from `syscalls(2)`.
+    pub async fn fetch() -> Result<Self> {
+        println!("Fetching {MAN_PAGE}");
+        let contents = reqwest::get(MAN_PAGE)
+            .await
+            .wrap_err_with(|| eyre!("Failed to fetch URL '{MAN_PAGE}'"))?
+            .text()
+            .await
+            .wrap_err_with(|| {
+                eyre!("Failed to parse contents of URL '{MAN_PAGE}'")
+            })?;
+
+        Ok(Self::parse(&contents))
+    }
+
+    fn parse(contents: &str) -> Self {
+        // Rows of the table look like `\fBopenat2\fP(2)	5.6`.
+        static RE_ROW: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^\\fB(\w+)\\fP\(2\)\t(\d+)\.(\d+)").unwrap()
+        });
+
+        let mut versions = HashMap::new();
+
+        for line in contents.lines() {
+            if let Some(cap) = RE_ROW.captures(line) {
+                let version =
+                    (cap[2].parse().unwrap(), cap[3].parse().unwrap());
+                versions.insert(cap[1].to_string(), version);
+            }
+        }
+
+        for (version, names) in EXTRA {
+            for name in *names {
+                versions.insert((*name).to_string(), *version);
+            }
+        }
+
+        Self(versions)
+    }
+
+    /// Fails if the version of any syscall in the table isn't known.
+    pub fn check(&self, arch: &str, table: &[TableEntry]) -> Result<()> {
+        let unknown: Vec<&str> = table
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .filter(|name| !self.0.contains_key(*name) && !OLD.contains(name))
+            .collect();
+
+        if !unknown.is_empty() {
+            bail!(
+                "Unknown versions of {arch} syscalls: {}. Add them to \
+                 `EXTRA` or `OLD`.",
+                unknown.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the version that added the syscall to the architecture. The
+    /// table must have been checked with [`Versions::check`].
+    pub fn get(&self, arch: &str, name: &str) -> Version {
+        let mut version =
+            self.0.get(name).copied().unwrap_or(OLDEST).max(OLDEST);
+
+        for (a, since) in ARCHES {
+            if *a == arch {
+                version = version.max(*since);
+            }
+        }
+
+        for (a, since, names) in LATE {
+            if *a == arch && names.contains(&name) {
+                version = version.max(*since);
+            }
+        }
+
+        version
+    }
+}
+
+/// Writes out the versions for the given syscall table.
+pub struct SinceList<'a>(pub &'a str, pub &'a [TableEntry], pub &'a Versions);
+
+impl fmt::Display for SinceList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self(arch, table, versions) = self;
+
+        let mut groups: BTreeMap<Version, Vec<&TableEntry>> = BTreeMap::new();
+        for entry in *table {
+            groups
+                .entry(versions.get(arch, &entry.name))
+                .or_default()
+                .push(entry);
+        }
+
+        writeln!(f, "syscall_since! {{")?;
+        for (i, ((major, minor), entries)) in groups.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "    ({major}, {minor}) = [")?;
+
+            let mut line = String::new();
+            for entry in entries {
+                let ident = entry.ident();
+                if !line.is_empty() && line.len() + ident.len() + 2 > MAX_WIDTH
+                {
+                    writeln!(f, "{line}")?;
+                    line.clear();
+                }
+
+                if line.is_empty() {
+                    line.push_str("       ");
+                }
+
+                line.push(' ');
+                line.push_str(&ident);
+                line.push(',');
+            }
+
+            if !line.is_empty() {
+                writeln!(f, "{line}")?;
+            }
+
+            writeln!(f, "    ];")?;
+        }
+        writeln!(f, "}}")
+    }
+}
@@ -1,17 +1,23 @@
+use crate::classes::ClassList;
+use crate::signatures::{self, Prototypes};
+use crate::since::{SinceList, Versions};
 use crate::{fetch_path, ABI};
 use color_eyre::eyre::{bail, eyre, Result, WrapErr};
-use lazy_static::lazy_static;
 use regex::Regex;
 use std::borrow::Cow;
 use std::fmt;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::sync::LazyLock;
 
 pub struct Table<'a> {
     pub arch: &'a str,
     pub path: &'a str,
     pub abi: &'a [ABI<'a>],
+    /// A `unistd.h` header with architecture-private syscalls, which aren't
+    /// listed in the table. These are numbered from `__{ARCH}_NR_BASE`.
+    pub private: Option<&'a str>,
 }
 
 pub struct Header<'a> {
@@ -32,10 +38,12 @@ pub struct TableEntry {
     pub id: u32,
     pub name: String,
     pub entry_point: Option<String>,
+    /// True if this is an architecture-private syscall.
+    pub private: bool,
 }
 
 impl TableEntry {
-    fn ident(&self) -> Cow<'_, str> {
+    pub fn ident(&self) -> Cow<'_, str> {
         if self.name.as_str() == "break" {
             Cow::Owned(format!("r#{}", self.name))
         } else {
@@ -44,7 +52,7 @@ impl TableEntry {
     }
 }
 
-impl<'a> Table<'a> {
+impl Table<'_> {
     async fn fetch_table(&self) -> Result<Vec<TableEntry>> {
         let contents = fetch_path(self.path).await?;
 
@@ -83,12 +91,17 @@ impl<'a> Table<'a> {
                         id: id + abi.offset,
                         name,
                         entry_point,
+                        private: false,
                     });
                     break;
                 }
             }
         }
 
+        if let Some(path) = self.private {
+            table.extend(fetch_private(self.arch, path).await?);
+        }
+
         // The table should already be sorted, but lets make sure.
         table.sort();
 
@@ -96,13 +109,58 @@ impl<'a> Table<'a> {
     }
 }
 
-impl<'a> Header<'a> {
-    async fn fetch_table(&self) -> Result<Vec<TableEntry>> {
-        lazy_static! {
-            // Pattern for matching the syscall definition.
-            static ref RE_SYSCALLNR: Regex = Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+(\d+)").unwrap();
-            static ref RE_SYSCALLNR_ARCH: Regex = Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+\(__NR_arch_specific_syscall\s*\+\s*(\d+)\)").unwrap();
+/// Fetches the architecture-private syscalls from a `unistd.h` header, such as
+/// `__ARM_NR_cacheflush`. These are numbered far above the other syscalls.
+async fn fetch_private(arch: &str, path: &str) -> Result<Vec<TableEntry>> {
+    static RE_BASE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+__[A-Z0-9]+_NR_BASE\s+\(__NR_SYSCALL_BASE\s*\+\s*0x([0-9a-fA-F]+)\)").unwrap()
+    });
+    static RE_SYSCALLNR: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+__[A-Z0-9]+_NR_([a-z0-9_]+)\s+\(__[A-Z0-9]+_NR_BASE\s*\+\s*(\d+)\)").unwrap()
+    });
+
+    let contents = fetch_path(path).await?;
+
+    let mut base: Option<u32> = None;
+    let mut table = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+
+        if let Some(cap) = RE_BASE.captures(line) {
+            base = Some(u32::from_str_radix(&cap[1], 16)?);
+        } else if let Some(cap) = RE_SYSCALLNR.captures(line) {
+            let Some(base) = base else {
+                bail!("Private syscall defined before its base in {path}");
+            };
+
+            let name = &cap[1];
+            let id: u32 = cap[2].parse()?;
+
+            table.push(TableEntry {
+                id: base + id,
+                name: name.into(),
+                // These are all handled by one function in the kernel, so
+                // give each its own name for looking up the prototype.
+                entry_point: Some(format!("sys_{arch}_{name}")),
+                private: true,
+            });
         }
+    }
+
+    Ok(table)
+}
+
+impl Header<'_> {
+    async fn fetch_table(&self) -> Result<Vec<TableEntry>> {
+        // Pattern for matching the syscall definition.
+        static RE_SYSCALLNR: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+(\d+)")
+                .unwrap()
+        });
+        static RE_SYSCALLNR_ARCH: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+\(__NR_arch_specific_syscall\s*\+\s*(\d+)\)").unwrap()
+        });
 
         let mut table = Vec::new();
         let mut arch_specific_syscall: Option<u32> = None;
@@ -141,7 +199,10 @@ impl<'a> Header<'a> {
                     table.push(TableEntry {
                         id,
                         name: name.into(),
-                        entry_point: Some(format!("sys_{name}")),
+                        entry_point: Some(signatures::generic_entry_point(
+                            name,
+                        )),
+                        private: false,
                     });
                 } else if let Some(cap) = RE_SYSCALLNR_ARCH.captures(line) {
                     if let Some(offset) = arch_specific_syscall {
@@ -155,8 +216,11 @@ impl<'a> Header<'a> {
                         table.push(TableEntry {
                             id: id + offset,
                             name: name.into(),
-                            entry_point: Some(format!("sys_{name}")),
-                        })
+                            entry_point: Some(signatures::generic_entry_point(
+                                name,
+                            )),
+                            private: false,
+                        });
                     } else {
                         bail!("__NR_arch_specific_syscall definition not found before usage. \
                             Try reordering `Header::headers`?");
@@ -187,14 +251,21 @@ impl<'a> Source<'a> {
         }
     }
 
-    /// Generates the source file.
-    pub(crate) async fn generate(&self, dir: &Path) -> Result<()> {
+    /// Generates the source file. Returns the syscall table.
+    pub(crate) async fn generate(
+        &self,
+        dir: &Path,
+        protos: &Prototypes,
+        versions: &Versions,
+    ) -> Result<Vec<TableEntry>> {
         let arch = self.arch();
         let table = self
             .fetch_table()
             .await
             .wrap_err_with(|| eyre!("Failed fetching table for {arch}"))?;
 
+        versions.check(arch, &table)?;
+
         // Generate `src/arch/{arch}.rs`
         let path = dir.join(format!("src/arch/{arch}.rs"));
 
@@ -202,24 +273,37 @@ impl<'a> Source<'a> {
             eyre!("Failed to create file {}", path.display())
         })?;
         writeln!(file, "//! Syscalls for the `{arch}` architecture.\n")?;
-        write!(file, "{}", SyscallFile(&table))?;
+        write!(file, "{}", SyscallFile(arch, &table, protos, versions))?;
+        writeln!(file, "\npub use crate::arch::markers::{arch}::*;")?;
+        writeln!(file, "pub use crate::regs::{arch}::Regs;")?;
 
         println!("Generated syscalls for {arch} at {}", path.display());
-        Ok(())
+        Ok(table)
     }
 }
 
-struct SyscallFile<'a>(&'a [TableEntry]);
+struct SyscallFile<'a>(&'a str, &'a [TableEntry], &'a Prototypes, &'a Versions);
 
-impl<'a> fmt::Display for SyscallFile<'a> {
+impl fmt::Display for SyscallFile<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "// This file is automatically generated. Do not edit!")?;
         writeln!(f)?;
 
         writeln!(f, "syscall_enum! {{")?;
         writeln!(f, "    pub enum Sysno {{")?;
-        for entry in self.0 {
-            if entry.entry_point.is_some() {
+        for entry in self.1 {
+            if entry.private {
+                writeln!(
+                    f,
+                    "        /// Architecture-private syscall. Not in the syscall table.",
+                )?;
+                writeln!(
+                    f,
+                    "        {name} = {id},",
+                    name = entry.ident(),
+                    id = entry.id
+                )?;
+            } else if entry.entry_point.is_some() {
                 writeln!(
                     f,
                     "        /// See [{name}(2)](https://man7.org/linux/man-pages/man2/{name}.2.html) for more info on this syscall.",
@@ -251,9 +335,36 @@ impl<'a> fmt::Display for SyscallFile<'a> {
             }
         }
         writeln!(f, "    }}")?;
-        writeln!(f, "    LAST: {};", self.0.last().unwrap().ident())?;
+        writeln!(f, "    LAST: {};", self.1.last().unwrap().ident())?;
+        if let Some(entry) = self.1.iter().find(|entry| entry.private) {
+            writeln!(f, "    PRIVATE: {};", entry.ident())?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        writeln!(f, "syscall_signatures! {{")?;
+        writeln!(f, "    impl Sysno {{")?;
+        for entry in self.1 {
+            // Syscalls without a known prototype are left out. Their
+            // signature is simply unknown.
+            if let Some(args) = self.2.get(self.0, entry) {
+                let args: Vec<_> =
+                    args.iter().map(ToString::to_string).collect();
+                writeln!(
+                    f,
+                    "        {name}({args}) -> {ret};",
+                    name = entry.ident(),
+                    args = args.join(", "),
+                    ret = signatures::return_kind(&entry.name),
+                )?;
+            }
+        }
+        writeln!(f, "    }}")?;
         writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        writeln!(f, "{}", SinceList(self.0, self.1, self.3))?;
 
-        Ok(())
+        write!(f, "{}", ClassList(self.1))
     }
 }
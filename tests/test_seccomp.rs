@@ -0,0 +1,119 @@
+#![cfg(feature = "std")]
+
+use syscalls::seccomp::{self, Action};
+use syscalls::*;
+
+/// Runs `f` in a forked child process and returns its exit code. Filters
+/// cannot be removed once installed, so they must not leak into the test
+/// harness.
+fn in_child(f: impl FnOnce() -> i32) -> i32 {
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0);
+
+        if pid == 0 {
+            libc::_exit(f());
+        }
+
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFEXITED(status));
+        libc::WEXITSTATUS(status)
+    }
+}
+
+#[test]
+fn test_install() {
+    let code = in_child(|| {
+        let rules = SysnoMap::from_iter([
+            (Sysno::getppid, Action::Errno(Errno::EPERM)),
+            (Sysno::getpgid, Action::Errno(Errno::EOPNOTSUPP)),
+        ]);
+
+        let program = seccomp::compile(&rules, Action::Allow);
+
+        if seccomp::install(&program).is_err() {
+            return 1;
+        }
+
+        if unsafe { syscall!(Sysno::getppid) } != Err(Errno::EPERM) {
+            return 2;
+        }
+
+        if unsafe { syscall!(Sysno::getpgid, 0) } != Err(Errno::EOPNOTSUPP) {
+            return 3;
+        }
+
+        if unsafe { syscall!(Sysno::getpid) }.is_err() {
+            return 4;
+        }
+
+        0
+    });
+
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn test_user_notif() {
+    use seccomp::{Listener, Response};
+    use std::os::fd::AsFd;
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel();
+
+    // Filters only apply to the thread that installs them (and its future
+    // children), so this doesn't affect the rest of the tests.
+    let target = std::thread::spawn(move || {
+        let rules = SysnoMap::from_iter([
+            (Sysno::getppid, Action::UserNotif),
+            (Sysno::getpgid, Action::UserNotif),
+        ]);
+
+        let listener =
+            Listener::install(&seccomp::compile(&rules, Action::Allow))
+                .unwrap();
+        tx.send(listener).unwrap();
+
+        unsafe {
+            (
+                syscall!(Sysno::getppid),
+                syscall!(Sysno::getpgid, 1),
+                syscall!(Sysno::getpgid, 0),
+                syscall!(Sysno::getpgid, 2),
+            )
+        }
+    });
+
+    let listener = rx.recv().unwrap();
+
+    let notif = listener.recv().unwrap();
+    assert_eq!(notif.sysno(), Some(Sysno::getppid));
+    assert!(listener.id_valid(notif.id).unwrap());
+    listener.respond(notif.id, Response::Value(42)).unwrap();
+    assert!(!listener.id_valid(notif.id).unwrap());
+
+    let notif = listener.recv().unwrap();
+    assert_eq!(notif.sysno(), Some(Sysno::getpgid));
+    assert_eq!(notif.args().arg0, 1);
+    listener
+        .respond(notif.id, Response::Errno(Errno::EPERM))
+        .unwrap();
+
+    let notif = listener.recv().unwrap();
+    assert_eq!(notif.args().arg0, 0);
+    listener.respond(notif.id, Response::Continue).unwrap();
+
+    let notif = listener.recv().unwrap();
+    assert_eq!(notif.args().arg0, 2);
+    let file = std::fs::File::open("/dev/null").unwrap();
+    let fd = listener.add_fd_and_send(notif.id, file.as_fd(), 0).unwrap();
+
+    let (ppid, pgid1, pgid0, pgid2) = target.join().unwrap();
+    assert_eq!(ppid, Ok(42));
+    assert_eq!(pgid1, Err(Errno::EPERM));
+    assert_eq!(pgid0, unsafe { syscall!(Sysno::getpgid, 0) });
+    assert_eq!(pgid2, Ok(fd as usize));
+
+    unsafe { libc::close(fd) };
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use syscalls::trace::{SyscallEvent, Tracer};
+use syscalls::{Errno, Sysno};
+
+#[test]
+fn test_spawn() {
+    let mut command = Command::new("cat");
+    command
+        .arg("/this/file/does/not/exist")
+        .stderr(Stdio::null());
+
+    let mut tracer = Tracer::spawn(&mut command).unwrap();
+    let events = (&mut tracer).collect::<Result<Vec<_>, _>>().unwrap();
+
+    // Every exit must be preceded by the matching entry.
+    let mut entered = None;
+    for event in &events {
+        match *event {
+            SyscallEvent::Enter { sysno, .. } => {
+                entered = Some(sysno);
+            }
+            SyscallEvent::Exit { sysno, .. } => {
+                assert_eq!(entered.take(), Some(sysno));
+            }
+        }
+    }
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        SyscallEvent::Exit {
+            sysno: Sysno::openat,
+            ret: Err(Errno::ENOENT),
+        }
+    )));
+
+    // `exit_group` never returns.
+    match events.last() {
+        Some(SyscallEvent::Enter {
+            sysno: Sysno::exit_group,
+            args,
+        }) => assert_eq!(args.arg0, 1),
+        event => panic!("unexpected last event: {:?}", event),
+    }
+
+    assert_eq!(
+        tracer.exit_status().and_then(|status| status.code()),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_attach() {
+    let mut child = Command::new("sleep").arg("0.2").spawn().unwrap();
+
+    let mut tracer = Tracer::attach(child.id() as i32).unwrap();
+
+    let event = tracer.next_event().unwrap();
+    assert!(event.is_some());
+
+    tracer.detach().unwrap();
+
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn test_syscall_info() {
+    let mut command = Command::new("cat");
+    command.arg("/dev/null");
+
+    let mut tracer = Tracer::spawn(&mut command).unwrap();
+
+    while let Some(event) = tracer.next_event().unwrap() {
+        let info = tracer.syscall_info().unwrap();
+
+        match event {
+            SyscallEvent::Enter { sysno, args } => {
+                assert_eq!(info.sysno(), Some(sysno));
+                assert_eq!(info.args(), Some(args));
+            }
+            SyscallEvent::Exit { ret, .. } => {
+                assert_eq!(info.ret(), Some(ret));
+            }
+        }
+    }
+
+    assert!(tracer.exit_status().unwrap().success());
+}
+
+// The tracer reaps the child instead of `Child::wait`.
+#[allow(clippy::zombie_processes)]
+#[test]
+fn test_attach_in_syscall() {
+    let mut child = Command::new("cat")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // Give `cat` time to block in `read`.
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    let mut tracer = Tracer::attach(child.id() as i32).unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(b"hello").unwrap();
+    drop(stdin);
+
+    let events = (&mut tracer).collect::<Result<Vec<_>, _>>().unwrap();
+
+    // The exit of the `read` that was in progress isn't mistaken for an
+    // entry, so every exit is preceded by the matching entry.
+    let mut entered = None;
+    for event in &events {
+        match *event {
+            SyscallEvent::Enter { sysno, .. } => {
+                assert_eq!(entered.replace(sysno), None, "{events:?}");
+            }
+            SyscallEvent::Exit { sysno, .. } => {
+                assert_eq!(entered.take(), Some(sysno), "{events:?}");
+            }
+        }
+    }
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        SyscallEvent::Exit {
+            sysno: Sysno::write,
+            ret: Ok(5),
+        }
+    )));
+
+    assert!(tracer.exit_status().unwrap().success());
+}